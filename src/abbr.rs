@@ -0,0 +1,65 @@
+use darling::FromMeta;
+use darling::ast::NestedMeta;
+use syn::{Expr, ExprLit, Lit, Meta};
+
+use crate::nested_meta::NestedMetaSliceExt;
+
+/// Abbreviation strategy to be used as an outer attribute of the
+/// [`TargetEnum`], determining how the abbreviated string representation of
+/// [`TargetVariant`]s is produced out of their full length string
+/// representation.
+///
+/// [`TargetEnum`]: crate::target::enum::TargetEnum
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AbbrStrategy {
+    /// Keeps the first `N` extended grapheme clusters (i.e. user perceived
+    /// characters) of the string representation.
+    Length(usize),
+    /// Keeps the first character of each word of the string representation
+    /// (e.g. `NorthEast` becomes `NE`).
+    Initials,
+    /// Drops any vowel following the first character of the string
+    /// representation (e.g. `Monday` becomes `Mndy`).
+    NoVowels,
+    /// Keeps the shortest prefix of the string representation that is not a
+    /// prefix of any other variant's string representation (e.g. `March` and
+    /// `May` become `Mar` and `May`, `June` and `July` become `Jun` and `Jul`).
+    UniquePrefix,
+}
+
+impl AbbrStrategy {
+    /// The list of valid [`Meta`]s for the [`AbbrStrategy`] attribute.
+    const VALID_PATHS: &'static [&'static str] =
+        &["len = ...", "initials", "no_vowels", "unique_prefix"];
+}
+
+impl Default for AbbrStrategy {
+    fn default() -> Self {
+        Self::Length(3)
+    }
+}
+
+impl FromMeta for AbbrStrategy {
+    #[rustfmt::skip]
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let nested_meta = items.get_one_exactly()?;
+
+        match nested_meta {
+            NestedMeta::Meta(meta) => match meta {
+                Meta::Path(path) if path.is_ident("initials") => Ok(Self::Initials),
+                Meta::Path(path) if path.is_ident("no_vowels") => Ok(Self::NoVowels),
+                Meta::Path(path) if path.is_ident("unique_prefix") => Ok(Self::UniquePrefix),
+                Meta::NameValue(name_value) if name_value.path.is_ident("len") => match name_value.value {
+                    Expr::Lit(ExprLit { lit: Lit::Int(ref lit), .. }) => match lit.base10_parse()? {
+                        0 => Err(darling::Error::custom("abbreviation length must be greater than zero").with_span(lit)),
+                        len => Ok(Self::Length(len)),
+                    },
+                    ref expr => Err(darling::Error::unexpected_expr_type(expr)),
+                },
+                meta => Err(darling::Error::unknown_field_path_with_alts(meta.path(), Self::VALID_PATHS)),
+            },
+            NestedMeta::Lit(lit) => Err(darling::Error::unexpected_lit_type(lit)),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use darling::FromMeta;
+use darling::ast::NestedMeta;
+use syn::{Lit, Meta};
+
+/// Alias to be used as an inner attribute of the [`TargetVariant`]s,
+/// representing an additional string representation accepted when parsing the
+/// variant, which is never produced when converting the variant to a string.
+///
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Alias {
+    /// The additional string representation of the variant.
+    value: String,
+    /// Whether the alias is deprecated, i.e. still accepted when parsing but
+    /// listed separately in error messages.
+    deprecated: bool,
+}
+
+impl Alias {
+    /// Returns the additional string representation of the variant.
+    #[inline]
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Whether the alias is deprecated.
+    #[inline]
+    pub(crate) fn is_deprecated(&self) -> bool {
+        self.deprecated
+    }
+}
+
+impl FromMeta for Alias {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(Self {
+            value: value.to_string(),
+            deprecated: false,
+        })
+    }
+
+    #[rustfmt::skip]
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        match items {
+            [NestedMeta::Lit(Lit::Str(lit))] => Self::from_string(&lit.value()),
+            [NestedMeta::Lit(Lit::Str(lit)), NestedMeta::Meta(meta)] => match meta {
+                Meta::Path(path) if path.is_ident("deprecated") => Ok(Self {
+                    value: lit.value(),
+                    deprecated: true,
+                }),
+                meta => Err(darling::Error::unknown_field_path_with_alts(meta.path(), &["deprecated"])),
+            },
+            [] => Err(darling::Error::too_few_items(1)),
+            [_, _, _, ..] => Err(darling::Error::too_many_items(2)),
+            [NestedMeta::Lit(Lit::Str(_)), NestedMeta::Lit(lit)] | [NestedMeta::Lit(lit), ..] => Err(darling::Error::unexpected_lit_type(lit)),
+            [NestedMeta::Meta(_), ..] => Err(darling::Error::unsupported_format("non-literal")),
+        }
+    }
+}
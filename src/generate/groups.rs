@@ -0,0 +1,62 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the per-group constants, predicates and iterators, for each
+/// group declared by the `#[variants(group = "...")]` variant attribute.
+#[rustfmt::skip]
+pub(crate) fn groups_impl(target_enum: &TargetEnum) -> TokenStream {
+    let enum_ident = target_enum.ident();
+    let (receiver, _) = target_enum.receiver();
+
+    let mut generated = TokenStream::new();
+
+    for group in target_enum.groups() {
+        let group_variants_idents = target_enum.group_variant_idents(&group);
+        let group_iterable_variants_idents = target_enum.group_iterable_variant_idents(&group);
+        let group_variants_count = group_iterable_variants_idents.len();
+        let group_const_ident = Ident::new(&group.to_uppercase(), Span::call_site());
+        let is_group_ident = target_enum.group_predicate_ident(&group);
+        let iter_group_ident = Ident::new(&format!("iter_{group}"), Span::call_site());
+
+        let group_const_doc = format!(
+            "The array of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants of the `{group}` group."
+        );
+
+        let is_group_doc = format!(
+            "Checks whether the [`{enum_ident}`] variant belongs to the `{group}` group, skipped ones included."
+        );
+
+        let iter_group_doc = format!(
+            "Iterates over _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants of the `{group}` group."
+        );
+
+        let generated_group_impl = quote::quote! {
+            #[automatically_derived]
+            impl #enum_ident {
+                #[doc = #group_const_doc]
+                pub const #group_const_ident: [Self; #group_variants_count] = [
+                    #(Self::#group_iterable_variants_idents,)*
+                ];
+
+                #[inline]
+                #[must_use]
+                #[doc = #is_group_doc]
+                pub const fn #is_group_ident(#receiver) -> bool {
+                    ::std::matches!(self, #(Self::#group_variants_idents)|*)
+                }
+
+                #[inline]
+                #[must_use]
+                #[doc = #iter_group_doc]
+                pub fn #iter_group_ident() -> ::std::array::IntoIter<Self, #group_variants_count> {
+                    Self::#group_const_ident.into_iter()
+                }
+            }
+        };
+
+        generated.extend(generated_group_impl);
+    }
+
+    generated
+}
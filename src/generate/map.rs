@@ -0,0 +1,241 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::generate::item_doc;
+use crate::ident::IdentExt;
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the `{Enum}Map<V>` type, if the `enum` type is marked with the
+/// `#[variants(map)]` attribute.
+#[rustfmt::skip]
+#[allow(clippy::too_many_lines)]
+pub(crate) fn map_impl(target_enum: &TargetEnum) -> Option<TokenStream> {
+    if !target_enum.implement_map() {
+        return None;
+    }
+
+    let enum_ident = target_enum.ident();
+    let enum_vis = target_enum.vis();
+    let iter_ident = Ident::new(&format!("{}Iter", enum_ident.to_unraw_string()), Span::call_site());
+
+    let map_ident = Ident::new(&format!("{}Map", enum_ident.to_unraw_string()), Span::call_site());
+    let (variant_ty, variant_arg) = if target_enum.is_copy() {
+        (quote::quote! { #enum_ident }, quote::quote! { variant })
+    } else {
+        (quote::quote! { &#enum_ident }, quote::quote! { &variant })
+    };
+
+    let map_doc = item_doc(
+        "type",
+        &format!(
+            "A map associating a value to each [`{enum_ident}`] variant, backed by an array of \
+            [`{enum_ident}::COUNT`] values."
+        ),
+        "since the enum type is marked with the `#[variants(map)]` attribute.",
+    );
+
+    let generated = quote::quote! {
+        #[doc = #map_doc]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #enum_vis struct #map_ident<V> {
+            values: [V; #enum_ident::COUNT],
+        }
+
+        #[automatically_derived]
+        impl<V> #map_ident<V> {
+            /// Returns the map associating each variant to the value returned by the
+            /// given function, called in declaration order.
+            #[must_use]
+            pub fn from_fn<F>(mut f: F) -> Self
+            where
+                F: ::std::ops::FnMut(#enum_ident) -> V,
+            {
+                Self {
+                    values: ::std::array::from_fn(|index| f(#enum_ident::from_index(index).unwrap())),
+                }
+            }
+
+            /// Returns the map associating each variant to the value at its index in
+            /// the given array.
+            #[inline]
+            #[must_use]
+            pub const fn from_array(values: [V; #enum_ident::COUNT]) -> Self {
+                Self { values }
+            }
+
+            /// Returns the array of values of the map, in declaration order of the
+            /// associated variants.
+            #[inline]
+            #[must_use]
+            pub fn into_array(self) -> [V; #enum_ident::COUNT] {
+                self.values
+            }
+
+            /// Returns a reference to the value associated to the given variant.
+            #[inline]
+            #[must_use]
+            pub fn get(&self, variant: #variant_ty) -> &V {
+                &self.values[variant.index()]
+            }
+
+            /// Returns a mutable reference to the value associated to the given variant.
+            #[inline]
+            #[must_use]
+            pub fn get_mut(&mut self, variant: #variant_ty) -> &mut V {
+                &mut self.values[variant.index()]
+            }
+
+            /// Returns an iterator over the entries of the map, in declaration order.
+            #[inline]
+            pub fn iter(&self) -> ::std::iter::Zip<#iter_ident, ::std::slice::Iter<'_, V>> {
+                #enum_ident::iter_variants().zip(self.values.iter())
+            }
+
+            /// Returns an iterator over the entries of the map, with mutable references
+            /// to the values, in declaration order.
+            #[inline]
+            pub fn iter_mut(&mut self) -> ::std::iter::Zip<#iter_ident, ::std::slice::IterMut<'_, V>> {
+                #enum_ident::iter_variants().zip(self.values.iter_mut())
+            }
+
+            /// Returns an iterator over the values of the map, in declaration order of
+            /// the associated variants.
+            #[inline]
+            pub fn values(&self) -> ::std::slice::Iter<'_, V> {
+                self.values.iter()
+            }
+
+            /// Returns an iterator over mutable references to the values of the map, in
+            /// declaration order of the associated variants.
+            #[inline]
+            pub fn values_mut(&mut self) -> ::std::slice::IterMut<'_, V> {
+                self.values.iter_mut()
+            }
+
+            /// Returns the map associating each variant to the value returned by the
+            /// given function, called with each entry in declaration order.
+            #[must_use]
+            pub fn map<U, F>(self, mut f: F) -> #map_ident<U>
+            where
+                F: ::std::ops::FnMut(#enum_ident, V) -> U,
+            {
+                let mut variants = #enum_ident::iter_variants();
+
+                #map_ident {
+                    values: self.values.map(|value| f(variants.next().unwrap(), value)),
+                }
+            }
+        }
+
+        impl<V> ::std::ops::Index<#variant_ty> for #map_ident<V> {
+            type Output = V;
+
+            fn index(&self, variant: #variant_ty) -> &V {
+                self.get(variant)
+            }
+        }
+
+        impl<V> ::std::ops::IndexMut<#variant_ty> for #map_ident<V> {
+            fn index_mut(&mut self, variant: #variant_ty) -> &mut V {
+                self.get_mut(variant)
+            }
+        }
+
+        impl<V> ::std::default::Default for #map_ident<V>
+        where
+            V: ::std::default::Default,
+        {
+            fn default() -> Self {
+                Self::from_fn(|_| V::default())
+            }
+        }
+
+        impl<V> ::std::fmt::Debug for #map_ident<V>
+        where
+            V: ::std::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_map()
+                    .entries(self.iter().map(|(variant, value)| (#enum_ident::as_str(#variant_arg), value)))
+                    .finish()
+            }
+        }
+    };
+
+    #[cfg(feature = "serde")]
+    let generated = if target_enum.implement_deserialize() {
+        quote::quote! {
+            #generated
+
+            impl<'de, V> ::serde::de::Deserialize<'de> for #map_ident<V>
+            where
+                V: ::serde::de::Deserialize<'de>,
+            {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::serde::de::Deserializer<'de>
+                {
+                    struct MapVisitor<V>(::std::marker::PhantomData<V>);
+
+                    impl<'de, V> ::serde::de::Visitor<'de> for MapVisitor<V>
+                    where
+                        V: ::serde::de::Deserialize<'de>,
+                    {
+                        type Value = #map_ident<V>;
+
+                        fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                            ::std::fmt::Formatter::write_str(f, "a map keyed by the string representations of the variants")
+                        }
+
+                        fn visit_map<A>(self, mut map: A) -> ::std::result::Result<Self::Value, A::Error>
+                        where
+                            A: ::serde::de::MapAccess<'de>,
+                        {
+                            let mut values: [::std::option::Option<V>; #enum_ident::COUNT] = ::std::array::from_fn(|_| ::std::option::Option::None);
+
+                            while let ::std::option::Option::Some(variant) = map.next_key::<#enum_ident>()? {
+                                if values[variant.index()].replace(map.next_value()?).is_some() {
+                                    return ::std::result::Result::Err(::serde::de::Error::custom(::std::format_args!("duplicate key `{}`", #enum_ident::as_str(#variant_arg))));
+                                }
+                            }
+
+                            if let ::std::option::Option::Some(index) = values.iter().position(::std::option::Option::is_none) {
+                                let variant = #enum_ident::from_index(index).unwrap();
+
+                                return ::std::result::Result::Err(::serde::de::Error::custom(::std::format_args!("missing key `{}`", #enum_ident::as_str(#variant_arg))));
+                            }
+
+                            ::std::result::Result::Ok(#map_ident::from_array(values.map(::std::option::Option::unwrap)))
+                        }
+                    }
+
+                    deserializer.deserialize_map(MapVisitor(::std::marker::PhantomData))
+                }
+            }
+        }
+    } else {
+        generated
+    };
+
+    #[cfg(feature = "serde")]
+    let generated = if target_enum.implement_serialize() {
+        quote::quote! {
+            #generated
+
+            impl<V> ::serde::ser::Serialize for #map_ident<V>
+            where
+                V: ::serde::ser::Serialize,
+            {
+                fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::ser::Serializer,
+                {
+                    serializer.collect_map(self.iter())
+                }
+            }
+        }
+    } else {
+        generated
+    };
+
+    Some(generated)
+}
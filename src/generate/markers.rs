@@ -0,0 +1,68 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::generate::item_doc;
+use crate::ident::IdentExt;
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the `{Enum}Variant` trait and the per-variant marker types, if
+/// the `enum` type is marked with the `#[variants(markers)]` attribute.
+#[rustfmt::skip]
+pub(crate) fn markers_impl(target_enum: &TargetEnum) -> Option<TokenStream> {
+    if !target_enum.implement_markers() {
+        return None;
+    }
+
+    let enum_ident = target_enum.ident();
+    let enum_vis = target_enum.vis();
+
+    let markers_mod_ident = target_enum.markers_mod_ident();
+    let variant_trait_ident = Ident::new(&format!("{}Variant", enum_ident.to_unraw_string()), Span::call_site());
+
+    let markers = target_enum.iter_variants().map(|variant| {
+        let variant_ident = variant.declared_ident();
+        let variant_name = variant_ident.to_unraw_string();
+
+        let marker_doc = format!(
+            "Type-level marker of the [`{enum_ident}::{variant_name}`](super::{enum_ident}::{variant_name}) variant."
+        );
+
+        quote::quote! {
+            #[doc = #marker_doc]
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+            pub struct #variant_ident;
+
+            impl super::#variant_trait_ident for #variant_ident {
+                const VALUE: super::#enum_ident = super::#enum_ident::#variant_ident;
+                const NAME: &'static str = super::#enum_ident::#variant_ident.as_str();
+            }
+        }
+    });
+
+    let markers_mod_doc = item_doc(
+        "module",
+        &format!("Type-level marker types of the [`{enum_ident}`](super::{enum_ident}) variants."),
+        "since the enum type is marked with the `#[variants(markers)]` attribute.",
+    );
+
+    let variant_trait_doc = format!(
+        "A type-level [`{enum_ident}`] variant, implemented by the types of [`{markers_mod_ident}`]."
+    );
+
+    let generated = quote::quote! {
+        #[doc = #variant_trait_doc]
+        #enum_vis trait #variant_trait_ident {
+            /// The variant represented by the marker type.
+            const VALUE: #enum_ident;
+
+            /// The string representation of the variant represented by the marker type.
+            const NAME: &'static str;
+        }
+
+        #[doc = #markers_mod_doc]
+        #enum_vis mod #markers_mod_ident {
+            #(#markers)*
+        }
+    };
+
+    Some(generated)
+}
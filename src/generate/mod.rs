@@ -0,0 +1,33 @@
+mod groups;
+mod map;
+mod markers;
+mod or_unknown;
+mod ord;
+mod repr;
+mod set;
+
+pub(crate) use self::groups::groups_impl;
+pub(crate) use self::map::map_impl;
+pub(crate) use self::markers::markers_impl;
+pub(crate) use self::or_unknown::or_unknown_impl;
+pub(crate) use self::ord::ord_impl;
+pub(crate) use self::repr::repr_impl;
+pub(crate) use self::set::set_impl;
+
+/// Formats the documentation of an item generated by the macro, made of the
+/// given summary followed by a `# Notes` section crediting the [`Variants`]
+/// derive macro.
+///
+/// The `kind` of the item (e.g. `"method"`, `"type"`) names the item in the
+/// `# Notes` section, whose first sentence is completed by the given notes.
+///
+/// [`Variants`]: crate::derive_enum_variants
+pub(crate) fn item_doc(kind: &str, summary: &str, notes: &str) -> String {
+    const LINKS: &str = "[`beerec-variants`]: https://docs.rs/beerec-variants\n\
+        [`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html";
+
+    format!(
+        "{summary}\n\n# Notes\n\nThis {kind} is generated by the [`Variants`] derive macro from the \
+        [`beerec-variants`] crate, {notes}\n\n{LINKS}"
+    )
+}
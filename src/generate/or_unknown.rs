@@ -0,0 +1,189 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::generate::item_doc;
+use crate::ident::IdentExt;
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the `{Enum}OrUnknown` companion type, if the `enum` type is
+/// marked with the `#[variants(or_unknown)]` attribute.
+#[rustfmt::skip]
+#[allow(clippy::too_many_lines)]
+pub(crate) fn or_unknown_impl(target_enum: &TargetEnum) -> Option<TokenStream> {
+    if !target_enum.implement_or_unknown() {
+        return None;
+    }
+
+    let enum_ident = target_enum.ident();
+    let enum_vis = target_enum.vis();
+
+    let or_unknown_ident = Ident::new(&format!("{}OrUnknown", enum_ident.to_unraw_string()), Span::call_site());
+    let variants_or_unknown_match_branches = target_enum.variants_or_unknown_match_branches(target_enum.from_str_options());
+    let (known_ty, known_variant, or_unknown_derive) = if target_enum.is_copy() {
+        (quote::quote! { #enum_ident }, quote::quote! { *variant }, Some(quote::quote! { #[derive(Clone)] }))
+    } else {
+        (quote::quote! { &#enum_ident }, quote::quote! { variant }, None)
+    };
+    let trim_value = target_enum.from_str_options().trim().then(|| quote::quote! {
+        let value = value.trim();
+    });
+
+    let or_unknown_doc = item_doc(
+        "type",
+        &format!("Either a known [`{enum_ident}`] variant or an unknown string value, preserved as is."),
+        "since the enum type is marked with the `#[variants(or_unknown)]` attribute.",
+    );
+
+    let generated = quote::quote! {
+        #[doc = #or_unknown_doc]
+        #or_unknown_derive
+        #enum_vis enum #or_unknown_ident {
+            /// A known variant.
+            Known(#enum_ident),
+            /// An unknown string value.
+            Unknown(::std::boxed::Box<str>),
+        }
+
+        #[automatically_derived]
+        impl #or_unknown_ident {
+            /// Returns the string representation of the known variant, or the unknown
+            /// string value.
+            #[inline]
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Known(variant) => variant.as_str(),
+                    Self::Unknown(value) => value,
+                }
+            }
+
+            /// Returns the known variant, if any.
+            #[inline]
+            #[must_use]
+            pub fn known(&self) -> ::std::option::Option<#known_ty> {
+                match self {
+                    Self::Known(variant) => ::std::option::Option::Some(#known_variant),
+                    Self::Unknown(_) => ::std::option::Option::None,
+                }
+            }
+
+            /// Returns the unknown string value, if any.
+            #[inline]
+            #[must_use]
+            pub fn unknown(&self) -> ::std::option::Option<&str> {
+                match self {
+                    Self::Known(_) => ::std::option::Option::None,
+                    Self::Unknown(value) => ::std::option::Option::Some(value),
+                }
+            }
+        }
+
+        impl ::std::fmt::Debug for #or_unknown_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self {
+                    Self::Known(variant) => f.debug_tuple("Known").field(&::std::format_args!("{}", variant.as_str())).finish(),
+                    Self::Unknown(value) => f.debug_tuple("Unknown").field(value).finish(),
+                }
+            }
+        }
+
+        impl ::std::cmp::PartialEq for #or_unknown_ident {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Known(variant), Self::Known(other)) => ::std::mem::discriminant(variant) == ::std::mem::discriminant(other),
+                    (Self::Unknown(value), Self::Unknown(other)) => value == other,
+                    _ => false,
+                }
+            }
+        }
+
+        impl ::std::cmp::Eq for #or_unknown_ident {}
+
+        impl ::std::hash::Hash for #or_unknown_ident {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                match self {
+                    Self::Known(variant) => ::std::hash::Hash::hash(&::std::mem::discriminant(variant), state),
+                    Self::Unknown(value) => ::std::hash::Hash::hash(value, state),
+                }
+            }
+        }
+
+        impl ::std::convert::From<#enum_ident> for #or_unknown_ident {
+            fn from(variant: #enum_ident) -> Self {
+                Self::Known(variant)
+            }
+        }
+
+        impl ::std::fmt::Display for #or_unknown_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Formatter::write_str(f, self.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for #or_unknown_ident {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+                let value = input;
+                #trim_value
+
+                ::std::result::Result::Ok(match value {
+                    #(#variants_or_unknown_match_branches,)*
+                    _ => Self::Unknown(::std::boxed::Box::from(input)),
+                })
+            }
+        }
+    };
+
+    #[cfg(feature = "serde")]
+    let generated = if target_enum.implement_deserialize() {
+        let variants_or_unknown_deserialize_match_branches = target_enum.variants_or_unknown_match_branches(target_enum.deserialize_options());
+        let trim_value = target_enum.deserialize_options().trim().then(|| quote::quote! {
+            let value = value.trim();
+        });
+
+        quote::quote! {
+            #generated
+
+            impl<'de> ::serde::de::Deserialize<'de> for #or_unknown_ident {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: ::serde::de::Deserializer<'de>
+                {
+                    let input = <::std::string::String as ::serde::de::Deserialize>::deserialize(deserializer)?;
+                    let value = input.as_str();
+                    #trim_value
+
+                    ::std::result::Result::Ok(match value {
+                        #(#variants_or_unknown_deserialize_match_branches,)*
+                        _ => Self::Unknown(::std::boxed::Box::from(input)),
+                    })
+                }
+            }
+        }
+    } else {
+        generated
+    };
+
+    #[cfg(feature = "serde")]
+    let generated = if target_enum.implement_serialize() {
+        quote::quote! {
+            #generated
+
+            impl ::serde::ser::Serialize for #or_unknown_ident {
+                fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::ser::Serializer,
+                {
+                    match self {
+                        Self::Known(variant) => ::serde::ser::Serialize::serialize(variant, serializer),
+                        Self::Unknown(value) => serializer.serialize_str(value),
+                    }
+                }
+            }
+        }
+    } else {
+        generated
+    };
+
+    Some(generated)
+}
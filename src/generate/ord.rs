@@ -0,0 +1,75 @@
+use proc_macro2::TokenStream;
+
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the [`PartialOrd`] and [`Ord`] trait implementations, along with
+/// the `MIN`/`MAX` constants and the `iter_variants_sorted` method, if the
+/// `enum` type is marked with the `#[variants(ord)]` attribute.
+#[rustfmt::skip]
+pub(crate) fn ord_impl(target_enum: &TargetEnum) -> Option<TokenStream> {
+    let ord_strategy = target_enum.ord_strategy()?;
+
+    let enum_ident = target_enum.ident();
+
+    let variants_ord_key_match_branches = target_enum.iter_variant_ord_key_match_branches(ord_strategy);
+    let sorted_variants_idents = target_enum.sorted_variant_idents(ord_strategy);
+
+    let iter_variants_sorted_doc = format!(
+        "Iterates over _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants, in the order \
+        established by the [`Ord`] trait implementation."
+    );
+
+    let min_max = sorted_variants_idents.first().zip(sorted_variants_idents.last()).map(|(min, max)| {
+        let min_doc = format!(
+            "The least _iterable_ (i.e. non-skipped) [`{enum_ident}`] variant, as ordered by [`Ord`]."
+        );
+
+        let max_doc = format!(
+            "The greatest _iterable_ (i.e. non-skipped) [`{enum_ident}`] variant, as ordered by [`Ord`]."
+        );
+
+        quote::quote! {
+            #[doc = #min_doc]
+            pub const MIN: Self = Self::#min;
+
+            #[doc = #max_doc]
+            pub const MAX: Self = Self::#max;
+        }
+    });
+
+    let generated = quote::quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            /// Returns the position of the variant in the order established by the
+            /// ordering strategy.
+            const fn ord_key(&self) -> usize {
+                match self {
+                    #(#variants_ord_key_match_branches,)*
+                }
+            }
+
+            #min_max
+
+            #[inline]
+            #[must_use]
+            #[doc = #iter_variants_sorted_doc]
+            pub fn iter_variants_sorted() -> ::std::array::IntoIter<Self, { Self::COUNT }> {
+                [#(Self::#sorted_variants_idents,)*].into_iter()
+            }
+        }
+
+        impl ::std::cmp::PartialOrd for #enum_ident {
+            fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::std::cmp::Ord for #enum_ident {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                ::std::cmp::Ord::cmp(&self.ord_key(), &other.ord_key())
+            }
+        }
+    };
+
+    Some(generated)
+}
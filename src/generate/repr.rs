@@ -0,0 +1,111 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::ident::IdentExt;
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the conversions between the `enum` type and its primitive
+/// representation, if the `enum` type is marked with the `#[variants(repr)]`
+/// attribute and an integer `#[repr(...)]` attribute.
+#[rustfmt::skip]
+#[allow(clippy::too_many_lines)]
+pub(crate) fn repr_impl(target_enum: &TargetEnum) -> Option<TokenStream> {
+    if !target_enum.implement_repr() {
+        return None;
+    }
+
+    let repr = target_enum.repr()?;
+
+    let enum_ident = target_enum.ident();
+    let enum_vis = target_enum.vis();
+    let (receiver, _) = target_enum.receiver();
+
+    let enum_name = target_enum.human_readable_name();
+    let from_repr_error_ident = Ident::new(&format!("FromRepr{}Error", enum_ident.to_unraw_string()), Span::call_site());
+    let variants_from_repr_match_branches = target_enum.iter_variant_from_repr_match_branches(&repr);
+    let to_repr_body = if target_enum.is_copy() {
+        quote::quote! { self as #repr }
+    } else {
+        let variants_to_repr_match_branches = target_enum.iter_variant_to_repr_match_branches(&repr);
+
+        quote::quote! {
+            match self {
+                #(#variants_to_repr_match_branches,)*
+            }
+        }
+    };
+    let integer_types = TargetEnum::INTEGER_TYPES.iter().map(|ty| Ident::new(ty, Span::call_site()));
+
+    let to_repr_doc = format!(
+        "Returns the discriminant of the [`{enum_ident}`] variant as a `{repr}` value."
+    );
+
+    let from_repr_doc = format!(
+        "Returns the [`{enum_ident}`] variant whose discriminant is the given `{repr}` value, if any."
+    );
+
+    let generated = quote::quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #enum_vis struct #from_repr_error_ident {
+            value: ::std::string::String,
+        }
+
+        impl #from_repr_error_ident {
+            /// Returns the value that failed to be converted.
+            #[must_use]
+            pub fn value(&self) -> &str {
+                &self.value
+            }
+        }
+
+        impl ::std::fmt::Display for #from_repr_error_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::write!(f, "unknown {} representation `{}`", #enum_name, self.value)
+            }
+        }
+
+        impl ::std::error::Error for #from_repr_error_ident {}
+
+        #[automatically_derived]
+        impl #enum_ident {
+            #[inline]
+            #[must_use]
+            #[doc = #to_repr_doc]
+            pub const fn to_repr(#receiver) -> #repr {
+                #to_repr_body
+            }
+
+            #[inline]
+            #[must_use]
+            #[doc = #from_repr_doc]
+            pub const fn from_repr(value: #repr) -> ::std::option::Option<Self> {
+                match value {
+                    #(#variants_from_repr_match_branches,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        impl ::std::convert::From<#enum_ident> for #repr {
+            fn from(variant: #enum_ident) -> Self {
+                variant.to_repr()
+            }
+        }
+
+        #(
+            impl ::std::convert::TryFrom<#integer_types> for #enum_ident {
+                type Error = #from_repr_error_ident;
+
+                fn try_from(value: #integer_types) -> ::std::result::Result<Self, Self::Error> {
+                    <#repr as ::std::convert::TryFrom<#integer_types>>::try_from(value)
+                        .ok()
+                        .and_then(Self::from_repr)
+                        .ok_or_else(|| #from_repr_error_ident {
+                            value: ::std::string::ToString::to_string(&value),
+                        })
+                }
+            }
+        )*
+    };
+
+    Some(generated)
+}
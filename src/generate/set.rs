@@ -0,0 +1,428 @@
+use proc_macro2::{Ident, Span, TokenStream};
+
+use crate::generate::item_doc;
+use crate::ident::IdentExt;
+use crate::target::r#enum::TargetEnum;
+
+/// Generates the `{Enum}Set` bitset type, if the `enum` type is marked with
+/// the `#[variants(set)]` attribute.
+#[rustfmt::skip]
+#[allow(clippy::too_many_lines)]
+pub(crate) fn set_impl(target_enum: &TargetEnum) -> Option<TokenStream> {
+    let (set_bits_ty, set_bits_count) = target_enum.set_bits_type().filter(|_| target_enum.implement_set())?;
+
+    let enum_ident = target_enum.ident();
+    let enum_vis = target_enum.vis();
+    let (receiver, _) = target_enum.receiver();
+    let variants_count = target_enum.variants_count();
+
+    let set_ident = Ident::new(&format!("{}Set", enum_ident.to_unraw_string()), Span::call_site());
+    let set_iter_ident = Ident::new(&format!("{}SetIter", enum_ident.to_unraw_string()), Span::call_site());
+    let variants_set_bit_match_branches = target_enum.iter_variant_set_bit_match_branches();
+    let set_all_bits = if variants_count == set_bits_count {
+        quote::quote! { #set_bits_ty::MAX }
+    } else {
+        quote::quote! { (1 << #variants_count) - 1 }
+    };
+    let (variant_ty, variant_arg, as_str_method) = if target_enum.is_copy() {
+        (quote::quote! { #enum_ident }, quote::quote! { variant }, quote::quote! { #enum_ident::as_str })
+    } else {
+        (quote::quote! { &#enum_ident }, quote::quote! { &variant }, quote::quote! { |variant| #enum_ident::as_str(&variant) })
+    };
+
+    let set_doc = item_doc(
+        "type",
+        &format!("A set of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants, backed by a `{set_bits_ty}` bitset."),
+        "since the enum type is marked with the `#[variants(set)]` attribute.",
+    );
+
+    let set_iter_doc = format!(
+        "An iterator over the [`{enum_ident}`] variants of a [`{set_ident}`], in declaration order."
+    );
+
+    let mut generated = quote::quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            /// Returns the bit of the variant within the generated bitset type, i.e. the
+            /// bit at its index among iterable variants, or an empty bitmask for skipped
+            /// variants.
+            const fn set_bit(#receiver) -> #set_bits_ty {
+                match self {
+                    #(#variants_set_bit_match_branches,)*
+                }
+            }
+        }
+
+        #[doc = #set_doc]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #enum_vis struct #set_ident {
+            bits: #set_bits_ty,
+        }
+
+        #[automatically_derived]
+        impl #set_ident {
+            /// The empty set.
+            pub const EMPTY: Self = Self { bits: 0 };
+
+            /// The set of all iterable variants.
+            pub const ALL: Self = Self { bits: #set_all_bits };
+
+            /// Returns the empty set.
+            #[inline]
+            #[must_use]
+            pub const fn empty() -> Self {
+                Self::EMPTY
+            }
+
+            /// Returns the set of all iterable variants.
+            #[inline]
+            #[must_use]
+            pub const fn all() -> Self {
+                Self::ALL
+            }
+
+            /// Returns the set of the given variants, ignoring skipped ones.
+            #[must_use]
+            pub const fn from_variants(variants: &[#enum_ident]) -> Self {
+                let mut bits = 0;
+                let mut index = 0;
+
+                while index < variants.len() {
+                    bits |= variants[index].set_bit();
+                    index += 1;
+                }
+
+                Self { bits }
+            }
+
+            /// Returns the set of the variants whose bits are set in the given bitmask,
+            /// ignoring bits not corresponding to any iterable variant.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits_truncate(bits: #set_bits_ty) -> Self {
+                Self { bits: bits & Self::ALL.bits }
+            }
+
+            /// Returns the bitmask of the set, i.e. the bit at the index of each variant
+            /// contained in the set.
+            #[inline]
+            #[must_use]
+            pub const fn bits(self) -> #set_bits_ty {
+                self.bits
+            }
+
+            /// Returns the number of variants contained in the set.
+            #[inline]
+            #[must_use]
+            pub const fn len(self) -> usize {
+                self.bits.count_ones() as usize
+            }
+
+            /// Checks whether the set contains no variants.
+            #[inline]
+            #[must_use]
+            pub const fn is_empty(self) -> bool {
+                self.bits == 0
+            }
+
+            /// Checks whether the set contains the given variant, which is never the case
+            /// for skipped variants.
+            #[inline]
+            #[must_use]
+            pub const fn contains(self, variant: #variant_ty) -> bool {
+                self.bits & variant.set_bit() != 0
+            }
+
+            /// Returns a copy of the set with the given variant added, unless skipped.
+            #[inline]
+            #[must_use]
+            pub const fn with(self, variant: #variant_ty) -> Self {
+                Self { bits: self.bits | variant.set_bit() }
+            }
+
+            /// Returns a copy of the set with the given variant removed.
+            #[inline]
+            #[must_use]
+            pub const fn without(self, variant: #variant_ty) -> Self {
+                Self { bits: self.bits & !variant.set_bit() }
+            }
+
+            /// Adds the given variant to the set, unless skipped, returning whether it
+            /// was newly added.
+            #[inline]
+            pub fn insert(&mut self, variant: #variant_ty) -> bool {
+                let bits = self.bits;
+                self.bits |= variant.set_bit();
+
+                self.bits != bits
+            }
+
+            /// Removes the given variant from the set, returning whether it was
+            /// contained in the set.
+            #[inline]
+            pub fn remove(&mut self, variant: #variant_ty) -> bool {
+                let bits = self.bits;
+                self.bits &= !variant.set_bit();
+
+                self.bits != bits
+            }
+
+            /// Returns the set of the variants contained in either set.
+            #[inline]
+            #[must_use]
+            pub const fn union(self, other: Self) -> Self {
+                Self { bits: self.bits | other.bits }
+            }
+
+            /// Returns the set of the variants contained in both sets.
+            #[inline]
+            #[must_use]
+            pub const fn intersection(self, other: Self) -> Self {
+                Self { bits: self.bits & other.bits }
+            }
+
+            /// Returns the set of the variants contained in this set, but not in the
+            /// other one.
+            #[inline]
+            #[must_use]
+            pub const fn difference(self, other: Self) -> Self {
+                Self { bits: self.bits & !other.bits }
+            }
+
+            /// Returns the set of the variants contained in exactly one of the sets.
+            #[inline]
+            #[must_use]
+            pub const fn symmetric_difference(self, other: Self) -> Self {
+                Self { bits: self.bits ^ other.bits }
+            }
+
+            /// Returns the set of the iterable variants not contained in this set.
+            #[inline]
+            #[must_use]
+            pub const fn complement(self) -> Self {
+                Self { bits: !self.bits & Self::ALL.bits }
+            }
+
+            /// Checks whether every variant contained in this set is also contained in
+            /// the other one.
+            #[inline]
+            #[must_use]
+            pub const fn is_subset(self, other: Self) -> bool {
+                self.bits & !other.bits == 0
+            }
+
+            /// Checks whether every variant contained in the other set is also contained
+            /// in this one.
+            #[inline]
+            #[must_use]
+            pub const fn is_superset(self, other: Self) -> bool {
+                other.is_subset(self)
+            }
+
+            /// Checks whether the sets have no variants in common.
+            #[inline]
+            #[must_use]
+            pub const fn is_disjoint(self, other: Self) -> bool {
+                self.bits & other.bits == 0
+            }
+
+            /// Returns an iterator over the variants contained in the set, in
+            /// declaration order.
+            #[inline]
+            pub const fn iter(self) -> #set_iter_ident {
+                #set_iter_ident { bits: self.bits }
+            }
+        }
+
+        impl ::std::ops::BitOr for #set_ident {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(other)
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for #set_ident {
+            fn bitor_assign(&mut self, other: Self) {
+                *self = self.union(other);
+            }
+        }
+
+        impl ::std::ops::BitAnd for #set_ident {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                self.intersection(other)
+            }
+        }
+
+        impl ::std::ops::BitAndAssign for #set_ident {
+            fn bitand_assign(&mut self, other: Self) {
+                *self = self.intersection(other);
+            }
+        }
+
+        impl ::std::ops::Sub for #set_ident {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.difference(other)
+            }
+        }
+
+        impl ::std::ops::SubAssign for #set_ident {
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.difference(other);
+            }
+        }
+
+        impl ::std::ops::BitXor for #set_ident {
+            type Output = Self;
+
+            fn bitxor(self, other: Self) -> Self {
+                self.symmetric_difference(other)
+            }
+        }
+
+        impl ::std::ops::BitXorAssign for #set_ident {
+            fn bitxor_assign(&mut self, other: Self) {
+                *self = self.symmetric_difference(other);
+            }
+        }
+
+        impl ::std::ops::Not for #set_ident {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.complement()
+            }
+        }
+
+        impl ::std::convert::From<#enum_ident> for #set_ident {
+            fn from(variant: #enum_ident) -> Self {
+                Self::EMPTY.with(#variant_arg)
+            }
+        }
+
+        impl ::std::iter::FromIterator<#enum_ident> for #set_ident {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: ::std::iter::IntoIterator<Item = #enum_ident>,
+            {
+                let mut set = Self::EMPTY;
+                ::std::iter::Extend::extend(&mut set, iter);
+
+                set
+            }
+        }
+
+        impl ::std::iter::Extend<#enum_ident> for #set_ident {
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: ::std::iter::IntoIterator<Item = #enum_ident>,
+            {
+                for variant in iter {
+                    self.insert(#variant_arg);
+                }
+            }
+        }
+
+        impl ::std::iter::IntoIterator for #set_ident {
+            type Item = #enum_ident;
+            type IntoIter = #set_iter_ident;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl ::std::fmt::Debug for #set_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_set().entries(self.iter().map(#as_str_method)).finish()
+            }
+        }
+
+        impl ::std::fmt::Display for #set_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                for (index, name) in self.iter().map(#as_str_method).enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    f.write_str(name)?;
+                }
+
+                ::std::result::Result::Ok(())
+            }
+        }
+
+        #[doc = #set_iter_doc]
+        #[derive(Debug, Clone)]
+        #enum_vis struct #set_iter_ident {
+            bits: #set_bits_ty,
+        }
+
+        impl ::std::iter::Iterator for #set_iter_ident {
+            type Item = #enum_ident;
+
+            fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                if self.bits == 0 {
+                    return ::std::option::Option::None;
+                }
+
+                let index = self.bits.trailing_zeros() as usize;
+                self.bits &= self.bits - 1;
+
+                #enum_ident::from_index(index)
+            }
+
+            fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                let len = self.bits.count_ones() as usize;
+
+                (len, ::std::option::Option::Some(len))
+            }
+        }
+
+        impl ::std::iter::DoubleEndedIterator for #set_iter_ident {
+            fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+                if self.bits == 0 {
+                    return ::std::option::Option::None;
+                }
+
+                let index = (#set_bits_ty::BITS - 1 - self.bits.leading_zeros()) as usize;
+                self.bits &= !(1 << index);
+
+                #enum_ident::from_index(index)
+            }
+        }
+
+        impl ::std::iter::ExactSizeIterator for #set_iter_ident {}
+
+        impl ::std::iter::FusedIterator for #set_iter_ident {}
+    };
+
+    if target_enum.implement_from_str() {
+        generated.extend(quote::quote! {
+            impl ::std::str::FromStr for #set_ident {
+                type Err = <#enum_ident as ::std::str::FromStr>::Err;
+
+                fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+                    let mut set = Self::EMPTY;
+
+                    if value.trim().is_empty() {
+                        return ::std::result::Result::Ok(set);
+                    }
+
+                    for name in value.split(',') {
+                        let variant = <#enum_ident as ::std::str::FromStr>::from_str(name.trim())?;
+                        set.insert(#variant_arg);
+                    }
+
+                    ::std::result::Result::Ok(set)
+                }
+            }
+        });
+    }
+
+    Some(generated)
+}
@@ -1,51 +1,34 @@
 use syn::Ident;
+use syn::ext::IdentExt as _;
 
+use crate::rename::case::RenameCase;
 use crate::string::StringExt;
 
 /// Extension trait providing string conversion methods for [`syn::Ident`].
 ///
 /// This trait extends the `syn::Ident` type with methods converting identifiers
-/// to various string formats, including case transformation and abbreviations.
+/// to various string formats, including case transformations.
+///
+/// Raw identifiers are stripped of their `r#` prefix (e.g. `r#Type` is
+/// converted to `Type`).
 pub(crate) trait IdentExt {
-    /// Converts the identifier to an uppercase string.
-    fn to_uppercase_string(&self) -> String;
-
-    /// Converts the identifier to a lowercase string.
-    fn to_lowercase_string(&self) -> String;
-
-    /// Converts the identifier to an abbreviated string.
-    fn to_string_abbr(&self) -> String;
-
-    /// Converts the identifier to an uppercase string abbreviation.
-    fn to_uppercase_string_abbr(&self) -> String;
+    /// Converts the identifier to a string, stripping the `r#` prefix of raw
+    /// identifiers.
+    fn to_unraw_string(&self) -> String;
 
-    /// Converts the identifier to an lowercase string abbreviation.
-    fn to_lowercase_string_abbr(&self) -> String;
+    /// Converts the identifier to a string following the given case
+    /// convention, stripping the `r#` prefix of raw identifiers.
+    fn to_case_string(&self, case: RenameCase) -> String;
 }
 
 impl IdentExt for Ident {
     #[inline]
-    fn to_uppercase_string(&self) -> String {
-        self.to_string().to_uppercase_in_place()
-    }
-
-    #[inline]
-    fn to_lowercase_string(&self) -> String {
-        self.to_string().to_lowercase()
-    }
-
-    #[inline]
-    fn to_string_abbr(&self) -> String {
-        self.to_string().to_abbr_in_place()
-    }
-
-    #[inline]
-    fn to_uppercase_string_abbr(&self) -> String {
-        self.to_string_abbr().to_uppercase_in_place()
+    fn to_unraw_string(&self) -> String {
+        self.unraw().to_string()
     }
 
     #[inline]
-    fn to_lowercase_string_abbr(&self) -> String {
-        self.to_string_abbr().to_lowercase_in_place()
+    fn to_case_string(&self, case: RenameCase) -> String {
+        self.to_unraw_string().to_case_in_place(case)
     }
 }
@@ -1,6 +1,12 @@
+mod abbr;
+mod alias;
+mod generate;
 mod ident;
 mod nested_meta;
+mod ord;
+mod parse;
 mod rename;
+mod skip;
 mod string;
 mod target;
 
@@ -9,6 +15,9 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use syn::DeriveInput;
 
+use self::generate::item_doc;
+use self::ident::IdentExt;
+use self::parse::ParseForms;
 use self::target::r#enum::TargetEnum;
 
 /// The actual derive macro implementation.
@@ -27,6 +36,13 @@ fn derive_enum_variants_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
     let target_enum = TargetEnum::from_derive_input(input)?;
 
     let enum_ident = target_enum.ident();
+    let enum_vis = target_enum.vis();
+
+    let (receiver, receiver_ty) = target_enum.receiver();
+
+    let iter_ident = Ident::new(&format!("{}Iter", enum_ident.to_unraw_string()), Span::call_site());
+    let as_str_iter_ident = Ident::new(&format!("{}AsStrIter", enum_ident.to_unraw_string()), Span::call_site());
+    let as_str_abbr_iter_ident = Ident::new(&format!("{}AsStrAbbrIter", enum_ident.to_unraw_string()), Span::call_site());
 
     let variants_count = target_enum.variants_count();
     let variants_idents = target_enum.iter_variant_idents();
@@ -37,6 +53,9 @@ fn derive_enum_variants_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
     let variants_list_string = target_enum.variants_list_string();
     let variants_list_string_abbr = target_enum.variants_list_string_abbr();
 
+    let variants_index_match_branches = target_enum.iter_variant_index_match_branches();
+    let variants_from_index_match_branches = target_enum.iter_variant_from_index_match_branches();
+
     let iterable_variants_doc = format!(
         "The array of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants."
     );
@@ -45,13 +64,200 @@ fn derive_enum_variants_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
         "The number of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants."
     );
 
-    let as_str_doc = format!(
-        r"Returns a string representation of the [`{enum_ident}`] variant.
+    let count_doc = format!(
+        "The number of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants."
+    );
+
+    let all_doc = format!(
+        "The array of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants, in declaration order."
+    );
+
+    let first_doc = format!(
+        "The first _iterable_ (i.e. non-skipped) [`{enum_ident}`] variant, in declaration order."
+    );
+
+    let last_doc = format!(
+        "The last _iterable_ (i.e. non-skipped) [`{enum_ident}`] variant, in declaration order."
+    );
+
+    let has_non_iterable_variants = target_enum.has_non_iterable_variants();
+
+    let skipped_doc = |subject: &str| if has_non_iterable_variants {
+        format!("\n\nReturns `None` if {subject} excluded from iteration, as it has no index.")
+    } else {
+        String::new()
+    };
+
+    let index_skipped_doc = skipped_doc("the variant is");
+    let navigation_skipped_doc = skipped_doc("the variant is");
+    let distance_to_skipped_doc = skipped_doc("either variant is");
+    let range_skipped_doc = if has_non_iterable_variants {
+        "\n\nRanges with any bound excluded from iteration yield no variants."
+    } else {
+        ""
+    };
+
+    let index_doc = format!(
+        "Returns the position of the [`{enum_ident}`] variant in [`{enum_ident}::ALL`].{index_skipped_doc}"
+    );
+
+    let from_index_doc = format!(
+        "Returns the [`{enum_ident}`] variant at the given position in [`{enum_ident}::ALL`], if any."
+    );
+
+    let next_doc = format!(
+        "Returns the [`{enum_ident}`] variant following this one in [`{enum_ident}::ALL`], \
+        wrapping around to [`{enum_ident}::FIRST`].{navigation_skipped_doc}"
+    );
 
-# Notes
+    let prev_doc = format!(
+        "Returns the [`{enum_ident}`] variant preceding this one in [`{enum_ident}::ALL`], \
+        wrapping around to [`{enum_ident}::LAST`].{navigation_skipped_doc}"
+    );
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`]
-crate, it applies rename strategies following a priority-based fallback approach:
+    let saturating_next_doc = format!(
+        "Returns the [`{enum_ident}`] variant following this one in [`{enum_ident}::ALL`], \
+        stopping at [`{enum_ident}::LAST`].{navigation_skipped_doc}"
+    );
+
+    let saturating_prev_doc = format!(
+        "Returns the [`{enum_ident}`] variant preceding this one in [`{enum_ident}::ALL`], \
+        stopping at [`{enum_ident}::FIRST`].{navigation_skipped_doc}"
+    );
+
+    let offset_doc = format!(
+        "Returns the [`{enum_ident}`] variant `n` positions away from this one in \
+        [`{enum_ident}::ALL`] (backwards if `n` is negative), wrapping around in both \
+        directions.{navigation_skipped_doc}"
+    );
+
+    let distance_to_doc = format!(
+        "Returns the signed number of positions from this [`{enum_ident}`] variant to `other` \
+        in [`{enum_ident}::ALL`], such that `self.offset(self.distance_to(other))` equals \
+        `other`.{distance_to_skipped_doc}"
+    );
+
+    let (index_ty, navigation_ty, distance_ty) = if has_non_iterable_variants {
+        (
+            quote::quote! { ::std::option::Option<usize> },
+            quote::quote! { ::std::option::Option<Self> },
+            quote::quote! { ::std::option::Option<isize> },
+        )
+    } else {
+        (quote::quote! { usize }, quote::quote! { Self }, quote::quote! { isize })
+    };
+
+    // Binds the index of `self` (returning `None` early for variants excluded
+    // from iteration), and wraps the resulting value accordingly.
+    let (let_index, let_indexes, some) = if has_non_iterable_variants {
+        (
+            quote::quote! {
+                let ::std::option::Option::Some(index) = self.index() else {
+                    return ::std::option::Option::None;
+                };
+            },
+            quote::quote! {
+                let (::std::option::Option::Some(index), ::std::option::Option::Some(other_index)) = (self.index(), other.index()) else {
+                    return ::std::option::Option::None;
+                };
+            },
+            quote::quote! { ::std::option::Option::Some },
+        )
+    } else {
+        (
+            quote::quote! { let index = self.index(); },
+            quote::quote! { let (index, other_index) = (self.index(), other.index()); },
+            quote::quote! {},
+        )
+    };
+
+    let first_last = target_enum.first_last_variant_idents().map(|(first, last)| quote::quote! {
+        #[doc = #first_doc]
+        pub const FIRST: Self = Self::#first;
+
+        #[doc = #last_doc]
+        pub const LAST: Self = Self::#last;
+
+        #[inline]
+        #[must_use]
+        #[doc = #next_doc]
+        pub const fn next(#receiver) -> #navigation_ty {
+            #let_index
+
+            #some(match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            })
+        }
+
+        #[inline]
+        #[must_use]
+        #[doc = #prev_doc]
+        pub const fn prev(#receiver) -> #navigation_ty {
+            #let_index
+
+            #some(match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            })
+        }
+
+        #[inline]
+        #[must_use]
+        #[doc = #saturating_next_doc]
+        pub const fn saturating_next(#receiver) -> #navigation_ty {
+            #let_index
+
+            #some(match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            })
+        }
+
+        #[inline]
+        #[must_use]
+        #[doc = #saturating_prev_doc]
+        pub const fn saturating_prev(#receiver) -> #navigation_ty {
+            #let_index
+
+            #some(match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            })
+        }
+
+        #[inline]
+        #[must_use]
+        #[doc = #offset_doc]
+        pub const fn offset(#receiver, n: isize) -> #navigation_ty {
+            #let_index
+
+            // Navigation methods are only generated if at least one variant is iterable,
+            // so `COUNT` is never zero here, and any offset of the only variant is itself.
+            if Self::COUNT == 1 {
+                return #some(Self::FIRST);
+            }
+
+            let count = Self::COUNT as isize;
+            let index = (index as isize + n % count).rem_euclid(count);
+
+            #some(Self::from_index(index as usize).unwrap())
+        }
+
+        #[inline]
+        #[must_use]
+        #[doc = #distance_to_doc]
+        pub const fn distance_to(#receiver, other: #receiver_ty) -> #distance_ty {
+            #let_indexes
+
+            #some(other_index as isize - index as isize)
+        }
+    });
+
+    let as_str_doc = item_doc(
+        "method",
+        &format!(r"Returns a string representation of the [`{enum_ident}`] variant."),
+        r"it applies rename strategies following a priority-based fallback approach:
 
 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
    produced by the rename strategy from the `#[variants(rename(...))]`
@@ -61,19 +267,13 @@ crate, it applies rename strategies following a priority-based fallback approach
    been specified for the type;
 1. **No renaming** (_default_) - converts the variant identifier to a string
    if neither the type-level nor the variant-level rename attribute has been
-   specified.
-
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
+   specified.",
     );
 
-    let as_str_abbr_doc = format!(
-        r"Returns an abbreviated string representation of the [`{enum_ident}`] variant.
-
-# Notes
-
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`]
-crate, it applies rename strategies on the string representation of the
+    let as_str_abbr_doc = item_doc(
+        "method",
+        &format!(r"Returns an abbreviated string representation of the [`{enum_ident}`] variant."),
+        r"it applies rename strategies on the string representation of the
 variant, following a priority-based fallback approach:
 
 1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
@@ -97,92 +297,123 @@ abbreviation:
    been specified for the type;
 1. **No renaming** (_default_) - converts the variant identifier to a string
    if neither the type-level nor the variant-level rename attribute has been
-   specified.
-
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
+   specified.",
     );
 
-    let iter_variants_doc = format!(
-        r"Iterates over _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants.
+    let iteration_note = target_enum.excluded_variants_note(|variant| !variant.is_iterable(), "iteration");
+    let listing_note = target_enum.excluded_variants_note(|variant| !variant.is_listed(), "the listing");
 
-# Notes
+    let iter_variants_doc = item_doc(
+        "method",
+        &format!(r"Iterates over _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants."),
+        &iteration_note,
+    );
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from iteration.
+    let range_doc = format!(
+        "Iterates over the [`{enum_ident}`] variants of [`{enum_ident}::ALL`] within the given \
+        range, yielding no variants if its start follows its end.{range_skipped_doc}"
+    );
 
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
+    let range_wrapping_doc = format!(
+        "Iterates over the [`{enum_ident}`] variants of [`{enum_ident}::ALL`] within the given \
+        range, wrapping around to [`{enum_ident}::FIRST`] if its start follows its end.{range_skipped_doc}"
     );
 
-    let iter_variants_as_str_doc = format!(
-        r"Iterates over _iterable_ (i.e. non-skipped) string representations of [`{enum_ident}`]
+    let iter_variants_as_str_doc = item_doc(
+        "method",
+        &format!(r"Iterates over _iterable_ (i.e. non-skipped) string representations of [`{enum_ident}`]
 variants.
 
-See [`{enum_ident}::as_str`] for further details about yielded values.
-
-# Notes
-
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from iteration.
-
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
+See [`{enum_ident}::as_str`] for further details about yielded values."),
+        &iteration_note,
     );
 
-    let iter_variants_as_str_abbr_doc = format!(
-        r"Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+    let iter_variants_as_str_abbr_doc = item_doc(
+        "method",
+        &format!(r"Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
 [`{enum_ident}`] variants.
 
-See [`{enum_ident}::as_str_abbr`] for further details about yielded values.
-
-# Notes
-
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from iteration.
-
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
+See [`{enum_ident}::as_str_abbr`] for further details about yielded values."),
+        &iteration_note,
     );
 
-    let variants_list_str_doc = format!(
-        r"Returns a list of quoted (double-quotes) and comma separated string
-representations of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants.
+    let iter_doc = format!(
+        "An iterator over _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants, returned by \
+        [`{enum_ident}::iter_variants`]."
+    );
 
-See [`{enum_ident}::as_str`] for further details about the string representations.
+    let as_str_iter_doc = format!(
+        "An iterator over string representations of _iterable_ (i.e. non-skipped) \
+        [`{enum_ident}`] variants, returned by [`{enum_ident}::iter_variants_as_str`]."
+    );
 
-# Notes
+    let as_str_abbr_iter_doc = format!(
+        "An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) \
+        [`{enum_ident}`] variants, returned by [`{enum_ident}::iter_variants_as_str_abbr`]."
+    );
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from the listing.
+    let variants_list_str_doc = item_doc(
+        "method",
+        &format!(r"Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`{enum_ident}`]
+variants.
 
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
+See [`{enum_ident}::as_str`] for further details about the string representations."),
+        &listing_note,
     );
 
-    let variants_list_str_abbr_doc = format!(
-        r"Returns a list of quoted (double-quotes) and comma separated abbreviated string
-representations of _iterable_ (i.e. non-skipped) [`{enum_ident}`] variants.
-
-See [`{enum_ident}::as_str_abbr`] for further details about the abbreviated string representations.
+    let variants_list_str_abbr_doc = item_doc(
+        "method",
+        &format!(r"Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`{enum_ident}`]
+variants.
 
-# Notes
+See [`{enum_ident}::as_str_abbr`] for further details about the abbreviated string representations."),
+        &listing_note,
+    );
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from the listing.
+    let (range_indexes_ty, bound_index, let_range_indexes) = if has_non_iterable_variants {
+        (
+            quote::quote! { ::std::option::Option<(usize, usize)> },
+            quote::quote! { variant.index()? },
+            quote::quote! {
+                let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range) else {
+                    return #iter_ident { front: 0, back: 0 };
+                };
+            },
+        )
+    } else {
+        (
+            quote::quote! { (usize, usize) },
+            quote::quote! { variant.index() },
+            quote::quote! { let (front, back) = Self::range_indexes(&range); },
+        )
+    };
 
-[`beerec-variants`]: https://docs.rs/beerec-variants
-[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html"
-    );
+    let range_indexes_doc = if has_non_iterable_variants {
+        "Returns the indexes of the first variant within the given range and of the variant\n\
+        following the last one, or `None` if any bound of the range has no index."
+    } else {
+        "Returns the indexes of the first variant within the given range and of the variant\n\
+        following the last one."
+    };
 
-    let mut generated = quote::quote! {
+    let copy_impl = target_enum.implement_copy().then(|| quote::quote! {
         impl ::std::marker::Copy for #enum_ident {}
+    });
 
+    let clone_impl = target_enum.implement_clone().then(|| quote::quote! {
         impl ::std::clone::Clone for #enum_ident {
             fn clone(&self) -> Self {
                 *self
             }
         }
+    });
+
+    let mut generated = quote::quote! {
+        #copy_impl
+
+        #clone_impl
 
         #[automatically_derived]
         impl #enum_ident {
@@ -194,10 +425,37 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
             #[doc = #iterable_variants_count_doc]
             const ITERABLE_VARIANTS_COUNT: usize = #variants_count;
 
+            #[doc = #count_doc]
+            pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+
+            #[doc = #all_doc]
+            pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+
+            #first_last
+
+            #[inline]
+            #[must_use]
+            #[doc = #index_doc]
+            pub const fn index(#receiver) -> #index_ty {
+                match self {
+                    #(#variants_index_match_branches,)*
+                }
+            }
+
+            #[inline]
+            #[must_use]
+            #[doc = #from_index_doc]
+            pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+                match index {
+                    #(#variants_from_index_match_branches,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
             #[inline]
             #[must_use]
             #[doc = #as_str_doc]
-            pub const fn as_str(self) -> &'static str {
+            pub const fn as_str(#receiver) -> &'static str {
                 match self {
                     #(#variants_as_str_match_branches,)*
                 }
@@ -206,25 +464,81 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
             #[inline]
             #[must_use]
             #[doc = #as_str_abbr_doc]
-            pub const fn as_str_abbr(self) -> &'static str {
+            pub const fn as_str_abbr(#receiver) -> &'static str {
                 match self {
                     #(#variants_as_str_abbr_match_branches,)*
                 }
             }
 
             #[doc = #iter_variants_doc]
-            pub fn iter_variants() -> impl ::std::iter::Iterator<Item = Self> {
-                Self::ITERABLE_VARIANTS.into_iter()
+            pub const fn iter_variants() -> #iter_ident {
+                #iter_ident {
+                    front: 0,
+                    back: Self::COUNT,
+                }
+            }
+
+            #[doc = #range_doc]
+            pub fn range<R>(range: R) -> #iter_ident
+            where
+                R: ::std::ops::RangeBounds<Self>,
+            {
+                #let_range_indexes
+
+                #iter_ident {
+                    front,
+                    back: ::std::cmp::max(front, back),
+                }
+            }
+
+            #[doc = #range_wrapping_doc]
+            pub fn range_wrapping<R>(range: R) -> #iter_ident
+            where
+                R: ::std::ops::RangeBounds<Self>,
+            {
+                #let_range_indexes
+
+                // An inclusive range ending on the variant preceding its start covers the
+                // whole cycle, rather than being empty.
+                let wraps = back < front
+                    || (back == front
+                        && ::std::matches!(range.start_bound(), ::std::ops::Bound::Included(_))
+                        && ::std::matches!(range.end_bound(), ::std::ops::Bound::Included(_)));
+
+                #iter_ident {
+                    front,
+                    back: if wraps { back + Self::COUNT } else { back },
+                }
+            }
+
+            #[doc = #range_indexes_doc]
+            fn range_indexes<R>(range: &R) -> #range_indexes_ty
+            where
+                R: ::std::ops::RangeBounds<Self>,
+            {
+                let front = match range.start_bound() {
+                    ::std::ops::Bound::Included(variant) => #bound_index,
+                    ::std::ops::Bound::Excluded(variant) => #bound_index + 1,
+                    ::std::ops::Bound::Unbounded => 0,
+                };
+
+                let back = match range.end_bound() {
+                    ::std::ops::Bound::Included(variant) => #bound_index + 1,
+                    ::std::ops::Bound::Excluded(variant) => #bound_index,
+                    ::std::ops::Bound::Unbounded => Self::COUNT,
+                };
+
+                #some((front, back))
             }
 
             #[doc = #iter_variants_as_str_doc]
-            pub fn iter_variants_as_str() -> impl ::std::iter::Iterator<Item = &'static str> {
-                Self::iter_variants().map(Self::as_str)
+            pub const fn iter_variants_as_str() -> #as_str_iter_ident {
+                #as_str_iter_ident(Self::iter_variants())
             }
 
             #[doc = #iter_variants_as_str_abbr_doc]
-            pub fn iter_variants_as_str_abbr() -> impl ::std::iter::Iterator<Item = &'static str> {
-                Self::iter_variants().map(Self::as_str_abbr)
+            pub const fn iter_variants_as_str_abbr() -> #as_str_abbr_iter_ident {
+                #as_str_abbr_iter_ident(Self::iter_variants())
             }
 
             #[doc = #variants_list_str_doc]
@@ -239,6 +553,198 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
         }
     };
 
+    let predicates = target_enum.iter_variant_predicates().map(|(predicate_ident, variant)| {
+        let variant_ident = variant.declared_ident();
+        let variant_name = variant_ident.to_unraw_string();
+
+        let predicate_doc = format!(
+            "Checks whether the [`{enum_ident}`] value is the [`{enum_ident}::{variant_name}`] variant."
+        );
+
+        quote::quote! {
+            #[inline]
+            #[must_use]
+            #[doc = #predicate_doc]
+            pub const fn #predicate_ident(#receiver) -> bool {
+                ::std::matches!(self, Self::#variant_ident)
+            }
+        }
+    });
+
+    let variants_eq_match_patterns = target_enum.iter_variant_eq_match_patterns();
+
+    let const_eq_doc = format!(
+        "Checks whether two [`{enum_ident}`] values are the same variant, in `const` contexts too."
+    );
+
+    generated.extend(quote::quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            #(#predicates)*
+
+            #[inline]
+            #[must_use]
+            #[doc = #const_eq_doc]
+            pub const fn const_eq(#receiver, other: #receiver_ty) -> bool {
+                ::std::matches!((self, other), #(#variants_eq_match_patterns)|*)
+            }
+        }
+    });
+
+    let generated_iter_impl = quote::quote! {
+        #[derive(Debug, Clone)]
+        #[doc = #iter_doc]
+        #enum_vis struct #iter_ident {
+            front: usize,
+            back: usize,
+        }
+
+        impl ::std::iter::Iterator for #iter_ident {
+            type Item = #enum_ident;
+
+            fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                if self.front == self.back {
+                    return ::std::option::Option::None;
+                }
+
+                // Enums without iterable variants have no index to wrap around to.
+                if #enum_ident::COUNT == 0 {
+                    return ::std::option::Option::None;
+                }
+
+                let index = self.front;
+                self.front += 1;
+
+                // Wrapping ranges span at most two cycles of iterable variants.
+                #enum_ident::from_index(if index < #enum_ident::COUNT { index } else { index - #enum_ident::COUNT })
+            }
+
+            fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                let len = self.back - self.front;
+                (len, ::std::option::Option::Some(len))
+            }
+
+            fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+                self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+                self.next()
+            }
+        }
+
+        impl ::std::iter::DoubleEndedIterator for #iter_ident {
+            fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+                if self.front == self.back {
+                    return ::std::option::Option::None;
+                }
+
+                if #enum_ident::COUNT == 0 {
+                    return ::std::option::Option::None;
+                }
+
+                self.back -= 1;
+
+                #enum_ident::from_index(if self.back < #enum_ident::COUNT { self.back } else { self.back - #enum_ident::COUNT })
+            }
+
+            fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+                self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+                self.next_back()
+            }
+        }
+
+        impl ::std::iter::ExactSizeIterator for #iter_ident {}
+
+        impl ::std::iter::FusedIterator for #iter_ident {}
+    };
+
+    generated.extend(generated_iter_impl);
+
+    for (adapter_ident, adapter_method, adapter_doc) in [
+        (&as_str_iter_ident, quote::quote! { as_str }, &as_str_iter_doc),
+        (&as_str_abbr_iter_ident, quote::quote! { as_str_abbr }, &as_str_abbr_iter_doc),
+    ] {
+        let adapter_method = if target_enum.is_copy() {
+            quote::quote! { #enum_ident::#adapter_method }
+        } else {
+            quote::quote! { |variant| #enum_ident::#adapter_method(&variant) }
+        };
+
+        let generated_adapter_impl = quote::quote! {
+            #[derive(Debug, Clone)]
+            #[doc = #adapter_doc]
+            #enum_vis struct #adapter_ident(#iter_ident);
+
+            impl ::std::iter::Iterator for #adapter_ident {
+                type Item = &'static str;
+
+                fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                    self.0.next().map(#adapter_method)
+                }
+
+                fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                    self.0.size_hint()
+                }
+
+                fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+                    self.0.nth(n).map(#adapter_method)
+                }
+            }
+
+            impl ::std::iter::DoubleEndedIterator for #adapter_ident {
+                fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+                    self.0.next_back().map(#adapter_method)
+                }
+
+                fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+                    self.0.nth_back(n).map(#adapter_method)
+                }
+            }
+
+            impl ::std::iter::ExactSizeIterator for #adapter_ident {}
+
+            impl ::std::iter::FusedIterator for #adapter_ident {}
+        };
+
+        generated.extend(generated_adapter_impl);
+    }
+
+    for derive in target_enum.iter_derives() {
+        let generated_derive_impl = if derive.is_ident("Debug") {
+            let variants_debug_match_branches = target_enum.iter_variant_debug_match_branches();
+
+            quote::quote! {
+                impl ::std::fmt::Debug for #enum_ident {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        ::std::fmt::Formatter::write_str(f, match self {
+                            #(#variants_debug_match_branches,)*
+                        })
+                    }
+                }
+            }
+        } else if derive.is_ident("PartialEq") {
+            quote::quote! {
+                impl ::std::cmp::PartialEq for #enum_ident {
+                    fn eq(&self, other: &Self) -> bool {
+                        ::std::mem::discriminant(self) == ::std::mem::discriminant(other)
+                    }
+                }
+            }
+        } else if derive.is_ident("Eq") {
+            quote::quote! {
+                impl ::std::cmp::Eq for #enum_ident {}
+            }
+        } else {
+            quote::quote! {
+                impl ::std::hash::Hash for #enum_ident {
+                    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                        ::std::hash::Hash::hash(&::std::mem::discriminant(self), state);
+                    }
+                }
+            }
+        };
+
+        generated.extend(generated_derive_impl);
+    }
+
     if target_enum.implement_display() {
         let generated_display_impl = quote::quote! {
             impl ::std::fmt::Display for #enum_ident {
@@ -251,35 +757,252 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
         generated.extend(generated_display_impl);
     }
 
+    generated.extend(generate::ord_impl(&target_enum));
+
+    generated.extend(generate::groups_impl(&target_enum));
+
+    if target_enum.implement_parse() {
+        let parse_candidates_consts = target_enum.parse_forms().into_iter().map(|forms| {
+            let parse_candidates_ident = forms.candidates_ident();
+            let parse_candidates = target_enum.iter_parse_candidates(forms).collect::<Vec<_>>();
+            let parse_candidates_count = parse_candidates.len();
+
+            quote::quote! {
+                /// The accepted string and abbreviated string representations and the
+                /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+                const #parse_candidates_ident: [(&'static str, Self); #parse_candidates_count] = [
+                    #(#parse_candidates,)*
+                ];
+            }
+        });
+
+        let from_str_fuzzy_doc = format!(
+            "Parses a [`{enum_ident}`] variant out of the string or abbreviated string \
+            representation (or non-deprecated alias) most similar to the given value, if their \
+            similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to \
+            `1.0` (i.e. strings equal regardless of their ASCII case)."
+        );
+
+        let from_str_parse_candidates_ident = target_enum.from_str_options().forms().candidates_ident();
+        let from_str_fuzzy = target_enum.implement_from_str().then(|| quote::quote! {
+            #[must_use]
+            #[doc = #from_str_fuzzy_doc]
+            pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+                Self::#from_str_parse_candidates_ident
+                    .into_iter()
+                    .map(|(candidate, variant)| {
+                        let distance = Self::parse_edit_distance(value, candidate);
+                        let len = ::std::cmp::max(value.chars().count(), candidate.chars().count());
+
+                        let similarity = match len {
+                            0 => 1.0,
+                            len => 1.0 - distance as f64 / len as f64,
+                        };
+
+                        (variant, similarity)
+                    })
+                    .filter(|&(_, similarity)| similarity >= threshold)
+                    .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+                    .map(|(variant, _)| variant)
+            }
+        });
+
+        let generated_parse_impl = quote::quote! {
+            #[automatically_derived]
+            impl #enum_ident {
+                #(#parse_candidates_consts)*
+
+                /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+                /// regardless of their ASCII case.
+                fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+                    let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+                    let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+                    let mut current = ::std::vec![0; candidate.len() + 1];
+
+                    for (i, value_char) in value.chars().enumerate() {
+                        current[0] = i + 1;
+
+                        for (j, candidate_char) in candidate.iter().enumerate() {
+                            let substitution = previous[j] + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+                        }
+
+                        ::std::mem::swap(&mut previous, &mut current);
+                    }
+
+                    previous[candidate.len()]
+                }
+
+                /// Returns the closest of the given parse candidates to the given value,
+                /// along with its edit distance from the value.
+                fn parse_closest_candidate(
+                    value: &str,
+                    candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+                ) -> ::std::option::Option<(&'static str, Self, usize)> {
+                    candidates
+                        .into_iter()
+                        .map(|(candidate, variant)| (candidate, variant, Self::parse_edit_distance(value, candidate)))
+                        .min_by_key(|&(_, _, distance)| distance)
+                }
+
+                /// Returns the closest of the given parse candidates to the given value, if
+                /// it's close enough to be suggested (i.e. its edit distance from the value
+                /// is at most a third of the value length).
+                fn parse_suggestion(
+                    value: &str,
+                    candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+                ) -> ::std::option::Option<&'static str> {
+                    let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+
+                    Self::parse_closest_candidate(value, candidates)
+                        .filter(|&(_, _, distance)| distance <= max_distance)
+                        .map(|(candidate, _, _)| candidate)
+                }
+
+                #from_str_fuzzy
+            }
+        };
+
+        generated.extend(generated_parse_impl);
+    }
+
     if target_enum.implement_from_str() {
-        let parse_error_ident = Ident::new(&format!("Parse{enum_ident}Error"), Span::call_site());
-        let variants_from_str_match_branches = target_enum.variants_from_str_match_branches();
+        let enum_name = target_enum.human_readable_name();
+        let parse_error_ident = target_enum.parse_error_ident().cloned().unwrap_or_else(|| {
+            Ident::new(&format!("Parse{}Error", enum_ident.to_unraw_string()), Span::call_site())
+        });
+        let parse_error_vis = target_enum.parse_error_vis();
+        let parse_error_derives = target_enum.parse_error_derives();
+        let parse_error_input = if let Some(input_limit) = target_enum.parse_error_input_limit() {
+            quote::quote! {
+                match value.char_indices().nth(#input_limit) {
+                    ::std::option::Option::Some((end, _)) => ::std::format!("{}…", &value[..end]),
+                    ::std::option::Option::None => ::std::string::ToString::to_string(value),
+                }
+            }
+        } else {
+            quote::quote! {
+                ::std::string::ToString::to_string(value)
+            }
+        };
+        let (from_str_error_type, from_str_error) = match target_enum.parse_error_type() {
+            Some(error_type) => (
+                quote::quote! { #error_type },
+                quote::quote! { <#error_type as ::std::convert::From<#parse_error_ident>>::from(error) },
+            ),
+            None => (quote::quote! { #parse_error_ident }, quote::quote! { error }),
+        };
+        let trim_value = target_enum.from_str_options().trim().then(|| quote::quote! {
+            let value = value.trim();
+        });
+        let from_str_body = |forms: ParseForms| {
+            let variants_from_str_match_branches = target_enum.variants_from_str_match_branches(forms);
+            let parse_candidates_ident = forms.candidates_ident();
+            let expected = target_enum.expected_list_string(forms);
+
+            let unknown_value_branch = if let Some(other_variant_ident) = target_enum.other_variant_ident() {
+                quote::quote! {
+                    _ => ::std::result::Result::Ok(Self::#other_variant_ident)
+                }
+            } else {
+                quote::quote! {
+                    _ => {
+                        let error = #parse_error_ident {
+                            input: #parse_error_input,
+                            suggestion: Self::parse_suggestion(value, Self::#parse_candidates_ident),
+                            expected: #expected,
+                        };
+
+                        ::std::result::Result::Err(#from_str_error)
+                    }
+                }
+            };
+
+            quote::quote! {
+                #trim_value
+
+                match value {
+                    #(#variants_from_str_match_branches,)*
+                    #unknown_value_branch
+                }
+            }
+        };
+        let from_str_impl_body = from_str_body(target_enum.from_str_options().forms());
+        let from_str_full_body = from_str_body(ParseForms::Full);
+        let from_str_abbr_body = from_str_body(ParseForms::Abbr);
+
+        let from_str_full_doc = format!(
+            "Parses a [`{enum_ident}`] variant out of its string representation (or alias) only, \
+            with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation."
+        );
+
+        let from_str_abbr_doc = format!(
+            "Parses a [`{enum_ident}`] variant out of its abbreviated string representation (or \
+            alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) \
+            implementation."
+        );
+
+        let from_str_doc = format!(
+            "Parses a [`{enum_ident}`] variant out of its string representations (or aliases), {}",
+            target_enum.excluded_variants_note(|variant| !variant.is_parseable() && !variant.is_other(), "parsing"),
+        );
 
         let generated_from_str_impl = quote::quote! {
-            #[derive(Debug, PartialEq, Eq)]
-            pub struct #parse_error_ident;
+            #[derive(Debug, PartialEq, Eq, #(#parse_error_derives),*)]
+            #parse_error_vis struct #parse_error_ident {
+                input: ::std::string::String,
+                suggestion: ::std::option::Option<&'static str>,
+                expected: &'static str,
+            }
+
+            impl #parse_error_ident {
+                /// Returns the input that failed to be parsed, possibly truncated.
+                #[must_use]
+                pub fn input(&self) -> &str {
+                    &self.input
+                }
+
+                /// Returns the closest string or abbreviated string representation (or
+                /// alias) to the input that failed to be parsed, if any is close enough.
+                #[must_use]
+                pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+                    self.suggestion
+                }
+            }
 
             impl ::std::fmt::Display for #parse_error_ident {
                 fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    ::std::fmt::Formatter::write_str(f, "Expected one of ")?;
-                    ::std::fmt::Formatter::write_str(f, #enum_ident::variants_list_str())?;
-                    ::std::fmt::Formatter::write_str(f, " or one of ")?;
-                    ::std::fmt::Formatter::write_str(f, #enum_ident::variants_list_str_abbr())?;
+                    ::std::write!(f, "unknown {} `{}`", #enum_name, self.input)?;
 
-                    Ok(())
+                    if let ::std::option::Option::Some(suggestion) = self.suggestion {
+                        return ::std::write!(f, ", did you mean `{suggestion}`?");
+                    }
+
+                    ::std::write!(f, ", expected {}", self.expected)
                 }
             }
 
             impl ::std::error::Error for #parse_error_ident {}
 
+            #[automatically_derived]
+            impl #enum_ident {
+                #[doc = #from_str_full_doc]
+                pub fn from_str_full(value: &str) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+                    #from_str_full_body
+                }
+
+                #[doc = #from_str_abbr_doc]
+                pub fn from_str_abbr(value: &str) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+                    #from_str_abbr_body
+                }
+            }
+
             impl ::std::str::FromStr for #enum_ident {
-                type Err = #parse_error_ident;
+                type Err = #from_str_error_type;
 
+                #[doc = #from_str_doc]
                 fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
-                    match value {
-                        #(#variants_from_str_match_branches,)*
-                        _ => ::std::result::Result::Err(#parse_error_ident),
-                    }
+                    #from_str_impl_body
                 }
             }
         };
@@ -287,13 +1010,78 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
         generated.extend(generated_from_str_impl);
     }
 
+    generated.extend(generate::repr_impl(&target_enum));
+
+    if let Some(default_variant_ident) = target_enum.default_variant_ident() {
+        let default_doc = format!(
+            "The default [`{enum_ident}`] variant, i.e. [`{enum_ident}::{default_variant_ident}`]."
+        );
+
+        let generated_default_impl = quote::quote! {
+            #[automatically_derived]
+            impl #enum_ident {
+                #[doc = #default_doc]
+                pub const DEFAULT: Self = Self::#default_variant_ident;
+            }
+
+            impl ::std::default::Default for #enum_ident {
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+        };
+
+        generated.extend(generated_default_impl);
+    }
+
     #[cfg(feature = "serde")]
     if target_enum.implement_deserialize() {
-        let visitor_ident = Ident::new(&format!("{enum_ident}Visitor"), Span::call_site());
+        let enum_name = target_enum.human_readable_name();
+        let visitor_ident = Ident::new(&format!("{}Visitor", enum_ident.to_unraw_string()), Span::call_site());
         let variants_deserialize_match_branches = target_enum.variants_deserialize_match_branches();
+        let parse_candidates_ident = target_enum.deserialize_options().forms().candidates_ident();
+        let expected = target_enum.expected_list_string(target_enum.deserialize_options().forms());
+        let trim_value = target_enum.deserialize_options().trim().then(|| quote::quote! {
+            let value = value.trim();
+        });
+
+        let unknown_value_branch = if let Some(other_variant_ident) = target_enum.other_variant_ident() {
+            quote::quote! {
+                _ => ::std::result::Result::Ok(#enum_ident::#other_variant_ident)
+            }
+        } else if target_enum.deserialize_fallback() {
+            quote::quote! {
+                _ => ::std::result::Result::Ok(#enum_ident::DEFAULT)
+            }
+        } else {
+            quote::quote! {
+                _ => match #enum_ident::parse_suggestion(value, #enum_ident::#parse_candidates_ident) {
+                    ::std::option::Option::Some(suggestion) => {
+                        let error = ::serde::de::Error::custom(::std::format_args!(
+                            "unknown {} `{}`, did you mean `{}`?",
+                            #enum_name,
+                            value,
+                            suggestion,
+                        ));
+                        ::std::result::Result::Err(error)
+                    },
+                    ::std::option::Option::None => {
+                        let unexp = ::serde::de::Unexpected::Str(value);
+                        let error = ::serde::de::Error::invalid_value(unexp, &self);
+                        ::std::result::Result::Err(error)
+                    },
+                }
+            }
+        };
+
+        let deserialize_doc = format!(
+            "Deserializes a [`{enum_ident}`] variant out of its string representations (or aliases), {}",
+            target_enum.excluded_variants_note(|variant| !variant.is_parseable() && !variant.is_other(), "deserialization"),
+        );
 
         let generated_deserialize_impl = quote::quote! {
             impl<'de> ::serde::de::Deserialize<'de> for #enum_ident {
+                #[doc = #deserialize_doc]
                 fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
                 where
                     D: ::serde::de::Deserializer<'de>
@@ -304,25 +1092,18 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
                         type Value = #enum_ident;
 
                         fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                            ::std::fmt::Formatter::write_str(f, "one of ")?;
-                            ::std::fmt::Formatter::write_str(f, #enum_ident::variants_list_str())?;
-                            ::std::fmt::Formatter::write_str(f, " or one of ")?;
-                            ::std::fmt::Formatter::write_str(f, #enum_ident::variants_list_str_abbr())?;
-
-                            Ok(())
+                            ::std::fmt::Formatter::write_str(f, #expected)
                         }
 
                         fn visit_str<E>(self, value: &str) -> ::std::result::Result<Self::Value, E>
                         where
                             E: ::serde::de::Error,
                         {
+                            #trim_value
+
                             match value {
                                 #(#variants_deserialize_match_branches,)*
-                                _ => {
-                                    let unexp = ::serde::de::Unexpected::Str(value);
-                                    let error = ::serde::de::Error::invalid_value(unexp, &self);
-                                    ::std::result::Result::Err(error)
-                                },
+                                #unknown_value_branch,
                             }
                         }
                     }
@@ -337,13 +1118,36 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 
     #[cfg(feature = "serde")]
     if target_enum.implement_serialize() {
+        let serialize_body = if let Some(variants_serialize_match_branches) = target_enum.variants_serialize_match_branches() {
+            quote::quote! {
+                match self {
+                    #(#variants_serialize_match_branches,)*
+                }
+            }
+        } else {
+            quote::quote! {
+                serializer.serialize_str(self.as_str())
+            }
+        };
+
+        let serialize_doc = format!(
+            "Serializes the [`{enum_ident}`] variant as its string representation, {}{}",
+            target_enum.excluded_variants_note(|variant| !variant.is_serializable(), "serialization"),
+            if target_enum.iter_variants().any(|variant| !variant.is_serializable()) {
+                "\n\n# Errors\n\nReturns an error for the variants excluded from serialization."
+            } else {
+                ""
+            },
+        );
+
         let generated_serialize_impl = quote::quote! {
             impl ::serde::ser::Serialize for #enum_ident {
+                #[doc = #serialize_doc]
                 fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
                 where
                     S: ::serde::ser::Serializer,
                 {
-                    serializer.serialize_str(self.as_str())
+                    #serialize_body
                 }
             }
         };
@@ -351,6 +1155,14 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
         generated.extend(generated_serialize_impl);
     }
 
+    generated.extend(generate::set_impl(&target_enum));
+
+    generated.extend(generate::map_impl(&target_enum));
+
+    generated.extend(generate::markers_impl(&target_enum));
+
+    generated.extend(generate::or_unknown_impl(&target_enum));
+
     Ok(generated)
 }
 
@@ -362,15 +1174,171 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// - `as_str_abbr` - returns an abbreviated string representation of the target
 ///   `enum` variant;
 /// - `iter_variants` - returns an iterator over target `enum` variants (owned
-///   values);
+///   values), of type `{Enum}Iter`;
+/// - `range` and `range_wrapping` - return an iterator over target `enum`
+///   variants within the given range (wrapping around if its start follows its
+///   end, for the latter), of type `{Enum}Iter`;
 /// - `iter_variants_as_str` - returns an iterator over string representations
-///   of the target `enum` variants (`&'static str` values);
+///   of the target `enum` variants (`&'static str` values), of type
+///   `{Enum}AsStrIter`;
 /// - `iter_variants_as_str_abbr` - returns an iterator over abbreviated string
-///   representations of the `enum` variants (`&'static str` values);
+///   representations of the `enum` variants (`&'static str` values), of type
+///   `{Enum}AsStrAbbrIter`;
 /// - `variants_list_str` - returns a list of quoted (double-quotes) and comma
 ///   separated string representations of the `enum` variants;
 /// - `variants_list_str_abbr` - returns a list of of quoted (double-quotes) and
-///   comma separated abbreviated string representation of the `enum` variants.
+///   comma separated abbreviated string representation of the `enum` variants;
+/// - `index` - returns the position of the target `enum` variant among the
+///   `enum` variants;
+/// - `from_index` - returns the `enum` variant at the given position, if any;
+/// - `next` and `prev` - return the following and preceding `enum` variants,
+///   wrapping around;
+/// - `saturating_next` and `saturating_prev` - return the following and
+///   preceding `enum` variants, stopping at the last and first variants;
+/// - `offset` - returns the `enum` variant the given (signed) number of positions
+///   away, wrapping around;
+/// - `distance_to` - returns the signed number of positions to the given `enum`
+///   variant;
+/// - `is_{variant}` (e.g. `is_monday`) - checks whether the target `enum` value is
+///   the given variant, one for each and every variant (skipped ones included),
+///   named after the snake case variant identifier;
+/// - `const_eq` - checks whether two `enum` values are the same variant, usable in
+///   `const` contexts unlike the [`PartialEq`] trait implementation.
+///
+/// Along with the following associated constants:
+///
+/// - `COUNT` - the number of `enum` variants;
+/// - `ALL` - the array of `enum` variants, in declaration order;
+/// - `FIRST` and `LAST` - the first and last `enum` variants.
+///
+/// The `FIRST` and `LAST` constants and the navigation methods (i.e. `next`,
+/// `prev`, `saturating_next`, `saturating_prev`, `offset` and `distance_to`) are
+/// generated only if at least one variant is not skipped.
+///
+/// If any variant is marked with the `#[variants(skip)]` or `#[variants(skip(iter))]`
+/// attribute, the `index` and navigation methods return [`Option`]s instead, being
+/// `None` for skipped variants, and ranges bounded by skipped variants are empty.
+///
+/// Variants marked with the `#[variants(skip)]` attribute are excluded from
+/// iteration, listing, counting and indexing.
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// enum Tab {
+///     Home,
+///     Search,
+///     #[variants(skip)]
+///     Debug,
+///     Settings,
+/// }
+///
+/// # fn main() {
+/// let badges: [u32; Tab::COUNT] = [0; Tab::COUNT];
+///
+/// assert_eq!(3, badges.len());
+/// assert_eq!([Tab::Home, Tab::Search, Tab::Settings], Tab::ALL);
+/// assert_eq!(Tab::Home, Tab::FIRST);
+/// assert_eq!(Tab::Settings, Tab::LAST);
+///
+/// assert_eq!(Some(2), Tab::Settings.index());
+/// assert_eq!(None, Tab::Debug.index());
+/// assert_eq!(Some(Tab::Home), Tab::Settings.next());
+/// assert_eq!(None, Tab::Debug.next());
+/// assert_eq!(Some(Tab::Settings), Tab::from_index(2));
+/// assert_eq!(None, Tab::from_index(3));
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// enum Weekday {
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+///     Sunday,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Weekday::Monday, Weekday::Sunday.next());
+/// assert_eq!(Weekday::Sunday, Weekday::Monday.prev());
+/// assert_eq!(Weekday::Sunday, Weekday::Sunday.saturating_next());
+/// assert_eq!(Weekday::Monday, Weekday::Monday.saturating_prev());
+///
+/// assert_eq!(Weekday::Wednesday, Weekday::Friday.offset(5));
+/// assert_eq!(Weekday::Saturday, Weekday::Monday.offset(-9));
+///
+/// assert_eq!(4, Weekday::Monday.distance_to(Weekday::Friday));
+/// assert_eq!(-4, Weekday::Friday.distance_to(Weekday::Monday));
+/// # }
+/// ```
+///
+/// The generated iterator types share the visibility of the `enum` type and
+/// implement the [`DoubleEndedIterator`], [`ExactSizeIterator`],
+/// [`FusedIterator`] and [`Clone`] traits, so they can be stored, reversed and
+/// measured.
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// enum Weekday {
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+///     Sunday,
+/// }
+///
+/// struct Calendar {
+///     days: WeekdayIter,
+/// }
+///
+/// # fn main() {
+/// let mut calendar = Calendar { days: Weekday::iter_variants() };
+///
+/// assert_eq!(7, calendar.days.len());
+/// assert_eq!(Some(Weekday::Sunday), calendar.days.next_back());
+/// assert_eq!(Some(Weekday::Thursday), calendar.days.nth(3));
+/// assert_eq!(2, calendar.days.len());
+///
+/// let mut names = Weekday::iter_variants_as_str_abbr().rev();
+///
+/// assert_eq!(Some("Sun"), names.next());
+/// assert_eq!(vec!["Sat", "Fri"], names.clone().take(2).collect::<Vec<_>>());
+/// assert_eq!(6, names.len());
+///
+/// let business_days = Weekday::range(Weekday::Monday..=Weekday::Friday);
+/// let weekend = Weekday::range(Weekday::Saturday..);
+///
+/// assert_eq!(5, business_days.len());
+/// assert_eq!(vec![Weekday::Saturday, Weekday::Sunday], weekend.collect::<Vec<_>>());
+/// assert_eq!(0, Weekday::range(Weekday::Friday..=Weekday::Monday).len());
+///
+/// assert_eq!(
+///     vec![Weekday::Friday, Weekday::Saturday, Weekday::Sunday, Weekday::Monday],
+///     Weekday::range_wrapping(Weekday::Friday..=Weekday::Monday).collect::<Vec<_>>(),
+/// );
+///
+/// let from_tuesday = Weekday::range_wrapping(Weekday::Tuesday..=Weekday::Monday);
+///
+/// assert_eq!(7, from_tuesday.len());
+/// assert_eq!(
+///     vec![Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday, Weekday::Friday],
+///     from_tuesday.clone().take(4).collect::<Vec<_>>(),
+/// );
+/// assert_eq!(Some(Weekday::Monday), from_tuesday.last());
+/// assert_eq!(0, Weekday::range_wrapping(Weekday::Tuesday..Weekday::Tuesday).len());
+/// # }
+/// ```
 ///
 /// # Enum level attributes
 ///
@@ -380,16 +1348,156 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// - `rename` - customizes the string representation of each variant;
 /// - `rename_abbr` - customizes the abbreviated string representation of each
 ///   variant;
+/// - `abbr` - customizes the algorithm producing the abbreviated string
+///   representation of each variant out of its full length string
+///   representation;
 /// - `display` - generates a [`Display`] trait implementation based on the
 ///   string representation provided by the generated `as_str` method;
 /// - `from_str` - generates a [`FromStr`] trait implementation based on the
 ///   string or abbreviated string representation provided by the generated
-///   `as_str` and `as_str_abbr` methods respectively.
+///   `as_str` and `as_str_abbr` methods respectively (see below for the
+///   supported parsing options);
+/// - `allow_collisions` - allows distinct variants to share the same string or
+///   abbreviated string representation, the first declared variant taking
+///   precedence when parsing;
+/// - `or_unknown` - generates the `{Enum}OrUnknown` companion type, wrapping
+///   either a known variant or an unknown string value, along with its `as_str`,
+///   `known` and `unknown` methods and its [`Display`] and [`FromStr`] trait
+///   implementations (and the `Deserialize` and `Serialize` ones, if the `serde`
+///   feature is enabled and the `deserialize` and `serialize` attributes are
+///   specified, respectively). Unknown values are preserved as is, so that they
+///   can be converted back to strings byte-for-byte;
+/// - `ord` - generates the [`PartialOrd`] and [`Ord`] trait implementations
+///   (requiring the [`PartialEq`] and [`Eq`] traits to be implemented), along
+///   with the `iter_variants_sorted` method and the `MIN` and `MAX` associated
+///   constants, following the given ordering strategy (see below);
+/// - `no_copy` - skips the [`Copy`] and [`Clone`] trait implementations (see the
+///   notes below);
+/// - `derive(...)` - additionally implements any of the [`Debug`],
+///   [`PartialEq`], [`Eq`] and [`Hash`] traits, unless already derived;
+/// - `derived(...)` - declares which of the [`Clone`], [`Copy`], [`Debug`],
+///   [`PartialEq`], [`Eq`] and [`Hash`] traits are already derived by means the
+///   macro cannot detect (see the notes below), so that their implementations
+///   are skipped;
+/// - `predicate_prefix = "..."` - customizes the prefix of the generated
+///   `is_{variant}` and `is_{group}` predicate methods (e.g.
+///   `#[variants(predicate_prefix = "is_state_")]` generates `is_state_idle`),
+///   defaulting to `is_`, so that they don't collide with hand-written methods;
+/// - `set` - generates the `{Enum}Set` bitset type, backed by the smallest
+///   unsigned integer type fitting the iterable variants (up to 128), along with
+///   its `const` constructors and set operations (e.g. `from_variants`, `contains`,
+///   `union` and `difference`), the `|`, `&`, `-`, `^` and `!` operators, its
+///   iterator type `{Enum}SetIter` (yielding variants in declaration order) and
+///   its [`Display`] trait implementation (comma separated string
+///   representations). If the `from_str` attribute is also specified, the set
+///   implements the [`FromStr`] trait as well, parsing comma separated string
+///   representations. Skipped variants are never contained in the set;
+/// - `map` - generates the `{Enum}Map<V>` type, associating a value to each
+///   variant and backed by an array of `COUNT` values, along with its `from_fn`,
+///   `get`, `iter`, `values` and `map` methods and its [`Index`] and
+///   [`IndexMut`] trait implementations. If the `serde` feature is enabled, the
+///   map also implements the `Deserialize` and `Serialize` traits (if the
+///   `deserialize` and `serialize` attributes are specified, respectively), as a
+///   map keyed by the variants, going through their own `Deserialize` and
+///   `Serialize` trait implementations;
+/// - `repr` - generates the conversions between the `enum` type and the
+///   primitive integer type of its `#[repr(...)]` attribute (see the primitive
+///   representation section);
+/// - `markers` - generates a zero-sized marker type for each and every variant
+///   (skipped ones included), within a module named after the snake case `enum`
+///   identifier (e.g. `weekday::Monday`, or `crate_markers::A` for the `crate`,
+///   `self` and `super` keywords), along with the `{Enum}Variant` trait
+///   they implement, carrying the `VALUE` and `NAME` associated constants (i.e.
+///   the variant and its string representation). Since the marker types refer to
+///   the `enum` type through `super`, the `enum` type must be declared at module
+///   level, rather than within a function body or any other block (see the notes
+///   below).
 ///
 /// Valid `rename` and `rename_abbr` customization strategies are:
 ///
 /// - `uppercase` - makes the (abbreviated) string representation uppercase;
-/// - `lowercase` - makes the (abbreviated) string representation lowercase.
+/// - `lowercase` - makes the (abbreviated) string representation lowercase;
+/// - `snake_case` - converts the (abbreviated) string representation to
+///   `snake_case`;
+/// - `kebab_case` - converts the (abbreviated) string representation to
+///   `kebab-case`;
+/// - `camel_case` - converts the (abbreviated) string representation to
+///   `camelCase`;
+/// - `screaming_snake_case` - converts the (abbreviated) string representation
+///   to `SCREAMING_SNAKE_CASE`;
+/// - `title_case` - converts the (abbreviated) string representation to
+///   `Title Case`;
+/// - `train_case` - converts the (abbreviated) string representation to
+///   `Train-Case`;
+/// - `dot_case` - converts the (abbreviated) string representation to
+///   `dot.case`.
+///
+/// Case conventions other than `uppercase` and `lowercase` split the variant
+/// identifier into words on case boundaries: acronyms are kept together (e.g.
+/// `HTTPServer` becomes `http_server` in `snake_case`) and so are digits with the
+/// word they're attached to (e.g. `Utf8Encoding` becomes `utf8_encoding` in
+/// `snake_case`). Custom string overrides are split on non-alphanumeric
+/// characters as well (e.g. `"plain-text"`).
+///
+/// All string transformations are Unicode aware: case conventions follow the
+/// Unicode case mapping rules (e.g. `Straße` becomes `STRASSE` in `uppercase`) and
+/// abbreviations never split a user perceived character (i.e. an extended grapheme
+/// cluster, such as `ì` or `🇮🇹`). Raw identifiers are stripped of their `r#`
+/// prefix (e.g. `r#Type` becomes `Type`).
+///
+/// Valid `abbr` strategies are:
+///
+/// - `len = N` - keeps the first `N` user perceived characters of the string
+///   representation (`len = 3` is the default strategy);
+/// - `initials` - keeps the first character of each word of the string
+///   representation (e.g. `NorthEast` becomes `NE`);
+/// - `no_vowels` - drops any vowel following the first character of the string
+///   representation (e.g. `Monday` becomes `Mndy`);
+/// - `unique_prefix` - keeps the shortest prefix of the string representation
+///   that is not a prefix of any other variant's string representation (e.g.
+///   `March` and `May` become `Mar` and `May`).
+///
+/// Variant-level `rename_abbr` string literals always take precedence over the
+/// `abbr` strategy, whereas variant-level `rename_abbr` case conventions are
+/// applied to the abbreviation produced by the `abbr` strategy.
+///
+/// Valid `ord` strategies are:
+///
+/// - `declaration` - orders variants by their declaration order (the bare
+///   `#[variants(ord)]` attribute is equivalent to `#[variants(ord(declaration))]`);
+/// - `name` - orders variants lexicographically by their string representation;
+/// - `rank` - orders variants by their explicit rank, requiring each variant to
+///   be marked with a distinct `#[variants(rank = N)]` attribute.
+///
+/// Valid `from_str` parsing options (e.g. `#[variants(from_str(trim))]`) are:
+///
+/// - `case_insensitive` - matches the input regardless of its ASCII case,
+///   without allocating (e.g. `monday`, `MON` and `Mon` are all accepted);
+/// - `trim` - trims leading and trailing whitespace off the input before
+///   matching it;
+/// - `full` - accepts the string representation only (and aliases);
+/// - `abbr` - accepts the abbreviated string representation only (and aliases);
+/// - `both` - accepts both the string and abbreviated string representations
+///   (and aliases), which is the default;
+/// - `error = "..."` - renames the generated parsing error type (defaults to
+///   `Parse{Enum}Error`);
+/// - `error_vis = "..."` - sets the visibility of the generated parsing error type
+///   (defaults to `pub`);
+/// - `error_derive(...)` - additionally derives `Clone` and/or `Hash` on the
+///   generated parsing error type;
+/// - `error_type = "..."` - uses the given type as the `FromStr::Err` associated
+///   type, converting the generated parsing error type into it by means of its
+///   `From` trait implementation;
+/// - `input_limit = N` - keeps at most the first `N` characters of the rejected
+///   input in the generated parsing error type, followed by `…` if truncated.
+///
+/// String representations differing only by their ASCII case are considered
+/// colliding when the `case_insensitive` parsing option is specified.
+///
+/// Regardless of the accepted forms, the `from_str` attribute also generates the
+/// `from_str_full` and `from_str_abbr` methods, accepting the string or the
+/// abbreviated string representation (and aliases) only, respectively, with the
+/// same parsing options. Parsing error messages only list the accepted forms.
 ///
 /// ## Examples
 ///
@@ -430,13 +1538,310 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// }
 ///
 /// # fn main() {
-/// assert_eq!("active", State::Active.as_str());
-/// assert_eq!("inactive", State::Inactive.as_str());
-/// assert_eq!("disabled", State::Disabled.as_str());
+/// assert_eq!("active", State::Active.as_str());
+/// assert_eq!("inactive", State::Inactive.as_str());
+/// assert_eq!("disabled", State::Disabled.as_str());
+///
+/// assert_eq!("ACT", State::Active.as_str_abbr());
+/// assert_eq!("INA", State::Inactive.as_str_abbr());
+/// assert_eq!("DIS", State::Disabled.as_str_abbr());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Variants)]
+/// #[variants(rename(kebab_case), rename_abbr(screaming_snake_case))]
+/// enum Resource {
+///     HTTPServer,
+///     Utf8Stream,
+///     #[variants(rename(title_case))]
+///     PlainText,
+/// }
+///
+/// # fn main() {
+/// assert_eq!("http-server", Resource::HTTPServer.as_str());
+/// assert_eq!("utf8-stream", Resource::Utf8Stream.as_str());
+/// assert_eq!("Plain Text", Resource::PlainText.as_str());
+///
+/// assert_eq!("HTT", Resource::HTTPServer.as_str_abbr());
+/// assert_eq!("UTF", Resource::Utf8Stream.as_str_abbr());
+/// assert_eq!("PLA", Resource::PlainText.as_str_abbr());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Variants)]
+/// #[variants(abbr(unique_prefix))]
+/// enum Month {
+///     January,
+///     February,
+///     March,
+///     April,
+///     May,
+///     June,
+///     July,
+///     August,
+///     September,
+///     October,
+///     November,
+///     December,
+/// }
+///
+/// #[derive(Variants)]
+/// #[variants(abbr(initials))]
+/// enum Region {
+///     NorthEast,
+///     NorthWest,
+///     SouthEast,
+///     SouthWest,
+///     #[variants(rename_abbr = "C")]
+///     Central,
+/// }
+///
+/// # fn main() {
+/// assert_eq!("Ja", Month::January.as_str_abbr());
+/// assert_eq!("F", Month::February.as_str_abbr());
+/// assert_eq!("Mar", Month::March.as_str_abbr());
+/// assert_eq!("Ap", Month::April.as_str_abbr());
+/// assert_eq!("May", Month::May.as_str_abbr());
+/// assert_eq!("Jun", Month::June.as_str_abbr());
+/// assert_eq!("Jul", Month::July.as_str_abbr());
+/// assert_eq!("Au", Month::August.as_str_abbr());
+///
+/// assert_eq!("NE", Region::NorthEast.as_str_abbr());
+/// assert_eq!("NW", Region::NorthWest.as_str_abbr());
+/// assert_eq!("SE", Region::SouthEast.as_str_abbr());
+/// assert_eq!("SW", Region::SouthWest.as_str_abbr());
+/// assert_eq!("C", Region::Central.as_str_abbr());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Variants)]
+/// #[variants(rename(uppercase), abbr(len = 4))]
+/// enum Word {
+///     Straße,
+///     #[variants(rename = "Lunedì", rename_abbr(lowercase))]
+///     Monday,
+///     #[variants(rename = "Cafe\u{301}")]
+///     Cafe,
+///     r#Type,
+/// }
+///
+/// # fn main() {
+/// assert_eq!("STRASSE", Word::Straße.as_str());
+/// assert_eq!("Lunedì", Word::Monday.as_str());
+/// assert_eq!("Cafe\u{301}", Word::Cafe.as_str());
+/// assert_eq!("TYPE", Word::r#Type.as_str());
+///
+/// assert_eq!("STRA", Word::Straße.as_str_abbr());
+/// assert_eq!("lune", Word::Monday.as_str_abbr());
+/// assert_eq!("Cafe\u{301}", Word::Cafe.as_str_abbr());
+/// assert_eq!("TYPE", Word::r#Type.as_str_abbr());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(allow_collisions, from_str)]
+/// enum Planet {
+///     Mars,
+///     Mercury,
+///     #[variants(rename_abbr = "Mars")]
+///     Marsupial,
+/// }
+///
+/// # fn main() {
+/// assert_eq!("Mars", Planet::Mars.as_str());
+/// assert_eq!("Mars", Planet::Marsupial.as_str_abbr());
+///
+/// // The first declared variant takes precedence.
+/// assert_eq!(Ok(Planet::Mars), "Mars".parse::<Planet>());
+/// assert_eq!(Ok(Planet::Marsupial), "Marsupial".parse::<Planet>());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(ord(rank))]
+/// enum Severity {
+///     #[variants(rank = 3)]
+///     Info,
+///     #[variants(rank = 2)]
+///     Warning,
+///     #[variants(rank = 1)]
+///     Error,
+///     #[variants(rank = 0)]
+///     Critical,
+/// }
+///
+/// # fn main() {
+/// assert!(Severity::Critical < Severity::Info);
+/// assert_eq!(Severity::Critical, Severity::MIN);
+/// assert_eq!(Severity::Info, Severity::MAX);
+///
+/// assert_eq!(
+///     vec![Severity::Critical, Severity::Error, Severity::Warning, Severity::Info],
+///     Severity::iter_variants_sorted().collect::<Vec<_>>(),
+/// );
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Clone, Variants)]
+/// #[variants(no_copy, derive(Debug, PartialEq, Eq))]
+/// enum Token {
+///     Open,
+///     Close,
+/// }
+///
+/// # fn main() {
+/// let token = Token::Open;
+///
+/// // Methods take `self` by reference, so the variant is not moved.
+/// assert_eq!("Open", token.as_str());
+/// assert_eq!(Token::Close, token.next());
+/// assert_eq!(Token::Open, token.clone());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Variants)]
+/// #[variants(derived(Clone, Copy, Debug, PartialEq, Eq))]
+/// enum Direction {
+///     Up,
+///     Down,
+/// }
+///
+/// # fn main() {
+/// // Methods take `self` by value, since `Copy` is declared as derived.
+/// assert_eq!(Direction::Down, Direction::Up.next());
+/// assert_eq!(Direction::Up, Direction::Down.next());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Variants)]
+/// #[variants(predicate_prefix = "is_state_")]
+/// enum State {
+///     Idle,
+///     Running,
+/// }
+///
+/// const IDLE: bool = State::Idle.is_state_idle();
+/// const SAME: bool = State::Running.const_eq(State::Idle);
+///
+/// # fn main() {
+/// assert!(IDLE);
+/// assert!(!SAME);
+/// assert!(State::Running.is_state_running());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(set, from_str)]
+/// enum Permission {
+///     Read,
+///     Write,
+///     Execute,
+/// }
+///
+/// const READ_WRITE: PermissionSet = PermissionSet::from_variants(&[Permission::Read, Permission::Write]);
+///
+/// # fn main() {
+/// assert!(READ_WRITE.contains(Permission::Write));
+/// assert_eq!(PermissionSet::from(Permission::Execute), !READ_WRITE);
+/// assert_eq!(PermissionSet::ALL, READ_WRITE | Permission::Execute.into());
+///
+/// assert_eq!("Read, Write", READ_WRITE.to_string());
+/// assert_eq!(Ok(READ_WRITE), "Write, Read".parse());
+/// assert_eq!(
+///     vec![Permission::Read, Permission::Write],
+///     READ_WRITE.iter().collect::<Vec<_>>(),
+/// );
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(map)]
+/// enum Weekday {
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+/// }
+///
+/// # fn main() {
+/// let mut hours = WeekdayMap::from_fn(|weekday| if weekday == Weekday::Monday { 4 } else { 8 });
+/// hours[Weekday::Wednesday] += 2;
+///
+/// assert_eq!(22, hours.values().sum::<u32>());
+/// assert_eq!(
+///     vec![(Weekday::Monday, &4), (Weekday::Tuesday, &8), (Weekday::Wednesday, &10)],
+///     hours.iter().collect::<Vec<_>>(),
+/// );
+///
+/// let overtime = hours.map(|_, hours| hours > 8);
+///
+/// assert!(overtime[Weekday::Wednesday]);
+/// # }
+/// ```
+///
+/// ```rust
+/// # use std::marker::PhantomData;
+/// #
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(markers)]
+/// enum DoorState {
+///     Open,
+///     Closed,
+/// }
+///
+/// struct Door<S: DoorStateVariant> {
+///     state: PhantomData<S>,
+/// }
+///
+/// impl Door<door_state::Closed> {
+///     fn open(self) -> Door<door_state::Open> {
+///         Door { state: PhantomData }
+///     }
+/// }
+///
+/// impl<S: DoorStateVariant> Door<S> {
+///     fn state(&self) -> DoorState {
+///         S::VALUE
+///     }
+/// }
+///
+/// # fn main() {
+/// let door = Door::<door_state::Closed> { state: PhantomData };
 ///
-/// assert_eq!("ACT", State::Active.as_str_abbr());
-/// assert_eq!("INA", State::Inactive.as_str_abbr());
-/// assert_eq!("DIS", State::Disabled.as_str_abbr());
+/// assert_eq!(DoorState::Closed, door.state());
+/// assert_eq!(DoorState::Open, door.open().state());
+/// assert_eq!("Open", <door_state::Open as DoorStateVariant>::NAME);
 /// # }
 /// ```
 ///
@@ -490,7 +1895,162 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// assert_eq!(Ok(Priority::High), <Priority as FromStr>::from_str("Hig"));
 /// assert_eq!(Ok(Priority::Critical), <Priority as FromStr>::from_str("Cri"));
 ///
-/// assert_eq!(Err(ParsePriorityError), <Priority as FromStr>::from_str("invalid"));
+/// assert!(<Priority as FromStr>::from_str("invalid").is_err());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str(case_insensitive, trim))]
+/// enum Weekday {
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+///     Sunday,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ok(Weekday::Monday), "monday".parse());
+/// assert_eq!(Ok(Weekday::Monday), "MON".parse());
+/// assert_eq!(Ok(Weekday::Monday), "Mon ".parse());
+/// assert_eq!(Ok(Weekday::Friday), "  fRiDaY\n".parse());
+///
+/// assert!("Mo".parse::<Weekday>().is_err());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str(full))]
+/// enum Format {
+///     Json,
+///     Yaml,
+///     Toml,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ok(Format::Json), "Json".parse());
+/// assert_eq!(Ok(Format::Yaml), Format::from_str_abbr("Yam"));
+///
+/// assert_eq!(
+///     r#"unknown format `Xml`, expected one of "Json", "Yaml", "Toml""#,
+///     "Xml".parse::<Format>().unwrap_err().to_string(),
+/// );
+/// assert_eq!(
+///     r#"unknown format `Xml`, expected one of "Jso", "Yam", "Tom""#,
+///     Format::from_str_abbr("Xml").unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+///
+/// When parsing fails, the generated error keeps the rejected input and suggests
+/// the closest string or abbreviated string representation (or non-deprecated
+/// alias) by edit distance, if any is close enough. The `from_str` attribute
+/// also generates a `from_str_fuzzy` method, parsing the variant whose string or
+/// abbreviated string representation (or non-deprecated alias) is the most
+/// similar to the given value, as long as their similarity (ranging from `0.0`
+/// to `1.0`) is at least the given threshold.
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str)]
+/// enum Weekday {
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+///     Sunday,
+/// }
+///
+/// # fn main() {
+/// let error = "Wendesday".parse::<Weekday>().unwrap_err();
+///
+/// assert_eq!("Wendesday", error.input());
+/// assert_eq!(Some("Wednesday"), error.suggestion());
+/// assert_eq!(
+///     "unknown weekday `Wendesday`, did you mean `Wednesday`?",
+///     error.to_string(),
+/// );
+///
+/// assert_eq!(Some(Weekday::Wednesday), Weekday::from_str_fuzzy("Wendesday", 0.6));
+/// assert_eq!(Some(Weekday::Thursday), Weekday::from_str_fuzzy("thurs", 0.6));
+/// assert_eq!(None, Weekday::from_str_fuzzy("Holiday", 0.6));
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str(error = "UnknownCurrency", error_derive(Clone, Hash), input_limit = 8))]
+/// enum Currency {
+///     Euro,
+///     Dollar,
+///     Pound,
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct BadRequest(String);
+///
+/// impl From<ParseUnitError> for BadRequest {
+///     fn from(error: ParseUnitError) -> Self {
+///         Self(error.to_string())
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str(error_type = "BadRequest", error_vis = "pub(crate)"))]
+/// enum Unit {
+///     Meter,
+///     Foot,
+/// }
+///
+/// # fn main() {
+/// let error = "Bitcoin and friends".parse::<Currency>().unwrap_err();
+///
+/// assert_eq!("Bitcoin …", error.input());
+/// assert_eq!(error.clone(), error);
+///
+/// assert_eq!(
+///     Err(BadRequest(String::from(r#"unknown unit `Mile`, expected one of "Meter", "Foot" or one of "Met", "Foo""#))),
+///     "Mile".parse::<Unit>(),
+/// );
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(or_unknown, from_str(case_insensitive))]
+/// enum Channel {
+///     Stable,
+///     Beta,
+///     Nightly,
+/// }
+///
+/// # fn main() {
+/// let known = "beta".parse::<ChannelOrUnknown>().unwrap();
+/// assert_eq!(ChannelOrUnknown::Known(Channel::Beta), known);
+/// assert_eq!(Some(Channel::Beta), known.known());
+/// assert_eq!("Beta", known.as_str());
+///
+/// // Unknown values are preserved as is.
+/// let unknown = "Canary-2".parse::<ChannelOrUnknown>().unwrap();
+/// assert_eq!(ChannelOrUnknown::Unknown(Box::from("Canary-2")), unknown);
+/// assert_eq!(Some("Canary-2"), unknown.unknown());
+/// assert_eq!("Canary-2", unknown.to_string());
 /// # }
 /// ```
 ///
@@ -503,7 +2063,10 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// 
 /// - `deserialize` - generates a [`Deserialize`] trait implementation based on
 ///   the string or abbreviated string representation provided by the generated
-///   `as_str` and `as_str_abbr` respectively;
+///   `as_str` and `as_str_abbr` respectively, supporting the same parsing
+///   options as the `from_str` attribute (e.g.
+///   `#[variants(deserialize(case_insensitive))]`), along with the `fallback`
+///   option deserializing unknown values as the variant marked as `default`;
 /// - `serialize` - generates a [`Serialize`] trait implementation based on the
 ///   string representation provided by the generated `as_str` method.
 /// 
@@ -562,6 +2125,91 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 ///     Ok(Config { theme: Theme::Light }),
 ///     toml::from_str::<'_, Config>("theme = \"Lig\"\n"),
 /// );
+///
+/// // Misspelled values are rejected suggesting the closest string representation.
+/// assert!(
+///     toml::from_str::<'_, Config>("theme = \"Autto\"\n")
+///         .unwrap_err()
+///         .to_string()
+///         .contains("unknown theme `Autto`, did you mean `Auto`?"),
+/// );
+/// # }
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, Variants, PartialEq, Eq)]
+/// # #[cfg(feature = "serde")]
+/// #[variants(deserialize(fallback))]
+/// enum Level {
+///     Low,
+///     #[variants(default)]
+///     Medium,
+///     High,
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// # #[cfg(feature = "serde")]
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     #[serde(default)]
+///     level: Level,
+/// }
+///
+/// # fn main() {
+/// # #[cfg(feature = "serde")]
+/// # {
+/// assert_eq!(
+///     Ok(Config { level: Level::High }),
+///     toml::from_str::<'_, Config>("level = \"High\"\n"),
+/// );
+///
+/// // Unknown values fall back to the default variant.
+/// assert_eq!(
+///     Ok(Config { level: Level::Medium }),
+///     toml::from_str::<'_, Config>("level = \"Extreme\"\n"),
+/// );
+///
+/// // Missing values fall back to the default variant by means of `#[serde(default)]`.
+/// assert_eq!(
+///     Ok(Config { level: Level::Medium }),
+///     toml::from_str::<'_, Config>(""),
+/// );
+/// # }
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, Variants, PartialEq, Eq)]
+/// # #[cfg(feature = "serde")]
+/// #[variants(or_unknown, deserialize, serialize)]
+/// enum Region {
+///     Europe,
+///     America,
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// # #[cfg(feature = "serde")]
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     region: RegionOrUnknown,
+/// }
+///
+/// # fn main() {
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let record = toml::from_str::<'_, Record>("region = \"Oceania\"\n").unwrap();
+/// assert_eq!(RegionOrUnknown::Unknown(Box::from("Oceania")), record.region);
+///
+/// // Unknown values round-trip unchanged.
+/// assert_eq!(
+///     Ok(String::from("region = \"Oceania\"\n")),
+///     toml::to_string(&record),
+/// );
 /// # }
 /// # }
 /// ```
@@ -574,6 +2222,7 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// #[variants(serialize)]
 /// enum Codec {
 ///     H264,
+///     #[variants(rename_abbr = "HEVC")]
 ///     H265,
 ///     AV1,
 /// }
@@ -610,17 +2259,50 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 ///
 /// The macro exposes the following variant attributes:
 ///
-/// - `skip` - excludes the marked variant from iteration and listing;
+/// - `skip` - excludes the marked variant from iteration and listing (see below
+///   for the supported skip modes);
 /// - `rename` - customizes the string representation of the marked variant;
 /// - `rename_abbr` - customizes the abbreviated string representation of the
-///   marked variant.
+///   marked variant;
+/// - `alias` - adds an extra string representation accepted when parsing (i.e.
+///   by the generated [`FromStr`] and `Deserialize` trait implementations) the
+///   marked variant, which is never produced by the generated methods nor by
+///   the generated [`Display`] and `Serialize` trait implementations. The
+///   attribute can be repeated to add multiple aliases, and
+///   `#[variants(alias("...", deprecated))]` marks the alias as deprecated,
+///   listing it separately in parsing error messages;
+/// - `default` - marks the variant as the default one, generating the `DEFAULT`
+///   associated constant and a [`Default`] trait implementation returning it. At
+///   most one variant can be marked as `default`, and it must not be skipped;
+/// - `other` - marks the variant as the catch-all one, parsing any unknown value
+///   as the marked variant (i.e. by the generated [`FromStr`] and `Deserialize`
+///   trait implementations) rather than failing. At most one variant can be
+///   marked as `other`, and its string representations (and aliases) are neither
+///   matched nor listed, be it by the generated `variants_list_str` and
+///   `variants_list_str_abbr` methods or in parsing error messages and
+///   suggestions;
+/// - `rank = N` - sets the (signed integer) rank of the variant, determining its
+///   order if the `enum` is marked with the `#[variants(ord(rank))]` attribute;
+/// - `group = "..."` - adds the marked variant to the named group, generating the
+///   `is_{group}` method (prefixed according to the `predicate_prefix`
+///   type-level attribute), checking whether a variant belongs to the group, the
+///   `{GROUP}` associated constant, an array of the group variants in declaration
+///   order, and the `iter_{group}` method, returning an iterator over them. The
+///   attribute can be repeated to add the variant to multiple groups, and the
+///   group name must be a lowercase identifier (e.g. `weekend`). Skipped variants
+///   are excluded from the `{GROUP}` constant and the `iter_{group}` iterator, but
+///   still belong to the group.
 ///
 /// Valid `rename` and `rename_abbr` customization strategies are:
 ///
 /// - `"..."` (string literal) - overrides the string representation with a
 ///   custom string;
 /// - `uppercase` - makes the (abbreviated) string representation uppercase;
-/// - `lowercase` - makes the (abbreviated) string representation lowercase.
+/// - `lowercase` - makes the (abbreviated) string representation lowercase;
+/// - `snake_case`, `kebab_case`, `camel_case`, `screaming_snake_case`,
+///   `title_case`, `train_case` or `dot_case` - converts the (abbreviated)
+///   string representation to the corresponding case convention (see the type
+///   level attributes for further details).
 ///
 /// For custom string overrides:
 ///
@@ -631,6 +2313,22 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 ///
 /// both are valid, supported formats.
 ///
+/// Valid `skip` modes (e.g. `#[variants(skip(parse, list))]`) are:
+///
+/// - `iter` - excludes the variant from iteration, counting and indexing (e.g.
+///   `iter_variants`, `COUNT` and `index`);
+/// - `list` - excludes the variant from listings (i.e. `variants_list_str` and
+///   `variants_list_str_abbr`), parsing error messages and parsing suggestions,
+///   while still accepting it when parsing;
+/// - `parse` - rejects the variant string representations (and aliases) when
+///   parsing, also excluding it from parsing error messages and suggestions;
+/// - `serialize` - makes the generated `Serialize` trait implementation fail
+///   with an error when serializing the variant, rather than silently producing
+///   its string representation.
+///
+/// The bare `#[variants(skip)]` attribute is equivalent to
+/// `#[variants(skip(iter, list))]`.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -655,6 +2353,131 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// # }
 /// ```
 ///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str)]
+/// enum Environment {
+///     #[variants(alias = "dev", alias("develop", deprecated))]
+///     Development,
+///     #[variants(alias = "prod")]
+///     Production,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ok(Environment::Development), "Development".parse());
+/// assert_eq!(Ok(Environment::Development), "dev".parse());
+/// assert_eq!(Ok(Environment::Development), "develop".parse());
+/// assert_eq!(Ok(Environment::Production), "prod".parse());
+///
+/// // Aliases are never produced by the generated methods.
+/// assert_eq!("Development", Environment::Development.as_str());
+/// assert_eq!("\"Development\", \"Production\"", Environment::variants_list_str());
+///
+/// assert_eq!(
+///     "unknown environment `staging`, expected one of \"Development\", \"Production\" \
+///      or one of \"Dev\", \"Pro\" (aliases: \"dev\", \"prod\"; deprecated aliases: \"develop\")",
+///     "staging".parse::<Environment>().unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str)]
+/// enum Command {
+///     Start,
+///     Stop,
+///     #[variants(skip(list))]
+///     Debug,
+///     #[variants(skip(parse))]
+///     Internal,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ok(Command::Debug), "Debug".parse());
+/// assert!("Internal".parse::<Command>().is_err());
+///
+/// assert_eq!(4, Command::COUNT);
+/// assert_eq!("\"Start\", \"Stop\", \"Internal\"", Command::variants_list_str());
+/// assert_eq!(
+///     "unknown command `Pause`, expected one of \"Start\", \"Stop\" or one of \"Sta\", \"Sto\"",
+///     "Pause".parse::<Command>().unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// enum Priority {
+///     Low,
+///     #[variants(default)]
+///     Medium,
+///     High,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Priority::Medium, Priority::DEFAULT);
+/// assert_eq!(Priority::Medium, Priority::default());
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(from_str)]
+/// enum Event {
+///     Created,
+///     Deleted,
+///     #[variants(other, skip)]
+///     Unknown,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ok(Event::Created), "Created".parse());
+/// assert_eq!(Ok(Event::Unknown), "Archived".parse());
+/// assert_eq!(Ok(Event::Unknown), "Unknown".parse());
+///
+/// assert_eq!(2, Event::COUNT);
+/// # }
+/// ```
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// enum Weekday {
+///     #[variants(group = "workday")]
+///     Monday,
+///     #[variants(group = "workday", group = "midweek")]
+///     Wednesday,
+///     #[variants(group = "workday")]
+///     Friday,
+///     #[variants(group = "weekend")]
+///     Saturday,
+///     #[variants(group = "weekend", skip)]
+///     Sunday,
+/// }
+///
+/// # fn main() {
+/// assert!(Weekday::Wednesday.is_midweek());
+/// assert!(Weekday::Sunday.is_weekend());
+/// assert!(!Weekday::Friday.is_weekend());
+///
+/// assert_eq!([Weekday::Saturday], Weekday::WEEKEND);
+/// assert_eq!(
+///     vec![Weekday::Monday, Weekday::Wednesday, Weekday::Friday],
+///     Weekday::iter_workday().collect::<Vec<_>>(),
+/// );
+/// # }
+/// ```
+///
 /// # String representation renaming priority
 ///
 /// To produce _string representations_ of enum variants, renaming can be
@@ -704,6 +2527,49 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 ///    if neither the type-level nor the variant-level rename attribute has been
 ///    specified.
 ///
+/// # Primitive representation
+///
+/// If the `enum` type the macro is being derived on is marked with the `repr`
+/// type-level attribute, along with a primitive integer `#[repr(...)]` attribute
+/// (e.g. `#[repr(u8)]`), the macro also generates:
+///
+/// - `to_repr` - returns the discriminant of the target `enum` variant, as a
+///   value of the `repr` type;
+/// - `from_repr` - returns the `enum` variant whose discriminant is the given
+///   value of the `repr` type, if any;
+/// - a [`From`] trait implementation converting the `enum` type into the `repr`
+///   type;
+/// - a [`TryFrom`] trait implementation for each primitive integer type, failing
+///   with a `FromRepr{Enum}Error` error that keeps the rejected value.
+///
+/// Explicit discriminants are honored, and skipped variants are converted as well.
+///
+/// ```rust
+/// # use beerec_variants::Variants;
+/// #
+/// #[derive(Debug, PartialEq, Eq, Variants)]
+/// #[variants(repr)]
+/// #[repr(u8)]
+/// enum Status {
+///     Pending = 1,
+///     Active,
+///     Closed = 10,
+/// }
+///
+/// # fn main() {
+/// assert_eq!(2, Status::Active.to_repr());
+/// assert_eq!(10, u8::from(Status::Closed));
+/// assert_eq!(Some(Status::Pending), Status::from_repr(1));
+/// assert_eq!(None, Status::from_repr(3));
+///
+/// assert_eq!(Ok(Status::Closed), Status::try_from(10_i64));
+/// assert_eq!(
+///     "unknown status representation `300`",
+///     Status::try_from(300_u16).unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+///
 /// # Errors
 ///
 /// The macro will produce a compile error if:
@@ -714,20 +2580,86 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// - derived on `enum` types with any unnamed field (i.e. tuple) variants;
 /// - derived on `enum` types with any newtype variants;
 /// - the `rename` variant-level attribute is passed any other value than a
-///   string literal, `uppercase` or `lowercase`;
+///   string literal or a valid case convention;
 /// - the `rename_abbr` variant-level attribute is passed any other value than a
-///   string literal, `uppercase` or `lowercase`;
-/// - the `rename` type-level attribute is passed any other value than
-///   `uppercase` or `lowercase`;
-/// - the `rename_abbr` type-level attribute is passed any other value than
-///   `uppercase` or `lowercase`.
+///   string literal or a valid case convention;
+/// - the `alias` variant-level attribute is passed any other value than a
+///   string literal, optionally followed by `deprecated`;
+/// - the `rename` type-level attribute is passed any other value than a valid
+///   case convention;
+/// - the `rename_abbr` type-level attribute is passed any other value than a
+///   valid case convention;
+/// - the `abbr` type-level attribute is passed any other value than a valid
+///   abbreviation strategy, or `len = 0`;
+/// - the `from_str` or `deserialize` type-level attributes are passed any other
+///   option than a valid parsing option;
+/// - the `error_derive` parsing option is passed any other trait than `Clone` or
+///   `Hash`;
+/// - more than one of the `full`, `abbr` and `both` parsing options are specified;
+/// - more than one variant is marked with the `default` variant-level attribute;
+/// - the variant marked with the `default` variant-level attribute is also marked
+///   with the `skip` variant-level attribute;
+/// - more than one variant is marked with the `other` variant-level attribute;
+/// - the `group` variant-level attribute is passed any other value than a
+///   lowercase identifier, a name clashing with other generated items (e.g.
+///   `first`), or the same group more than once;
+/// - the `ord` type-level attribute is passed any other value than a valid
+///   ordering strategy;
+/// - the `derive` type-level attribute is passed any other trait than `Debug`,
+///   `PartialEq`, `Eq` or `Hash`;
+/// - the `derived` type-level attribute is passed any other trait than `Clone`,
+///   `Copy`, `Debug`, `PartialEq`, `Eq` or `Hash`;
+/// - the `predicate_prefix` type-level attribute is passed any other value than a
+///   lowercase identifier, or the predicate methods of two variants (e.g.
+///   `HttpServer` and `HTTPServer`), or of a variant and a group, share the same
+///   name;
+/// - the `set` type-level attribute is specified on an `enum` type with more than
+///   128 iterable variants;
+/// - the `map` type-level attribute is specified on an `enum` type with any
+///   variant excluded from iteration (i.e. marked with `skip` or `skip(iter)`),
+///   or along with the `deserialize` or `serialize` type-level attribute on an
+///   `enum` type with any variant marked with `skip(parse)` or `skip(serialize)`,
+///   respectively;
+/// - the `repr` type-level attribute is specified on an `enum` type that is not
+///   marked with a primitive integer `#[repr(...)]` attribute;
+/// - the `rank` variant-level attribute is specified, unless the `enum` is
+///   ordered by rank, in which case any variant is missing it or shares its
+///   value with another variant;
+/// - the `fallback` deserialization option is specified, but no variant is marked
+///   with the `default` variant-level attribute, or a variant is marked with the
+///   `other` variant-level attribute;
+/// - the string or abbreviated string representation, or any alias of any
+///   variant is empty (e.g. `#[variants(rename = "")]`);
+/// - a parsing implementation is generated (i.e. `from_str`, `or_unknown` or
+///   `deserialize`) and the string or abbreviated string representation, or any
+///   alias of any variant it accepts equals the one of another variant it accepts
+///   (e.g. `March` and `Mars` both abbreviated to `Mar`), unless the
+///   `allow_collisions` type-level attribute is specified.
 ///
 /// # Notes
 ///
 /// Deriving [`Variants`] on type automatically implements [`Clone`] and
-/// [`Copy`] for such type. This means that deriving either trait on a type that
-/// also derives [`Variants`] will result in a "conflicting implementations"
-/// compilation error.
+/// [`Copy`] for such type, unless the `no_copy` type-level attribute is specified.
+/// Either implementation is also skipped if the trait is derived by a
+/// `#[derive(...)]` attribute following the one deriving [`Variants`] (e.g.
+/// `#[derive(Variants)] #[derive(Clone, Copy)]`), whereas deriving either trait
+/// in the same or a preceding `#[derive(...)]` attribute results in a
+/// "conflicting implementations" compilation error, since the compiler hides such
+/// attributes from the macro, unless the trait is also listed in the `derived(...)`
+/// type-level attribute (e.g. `#[derive(Clone, Copy, Variants)]
+/// #[variants(derived(Clone, Copy))]`). The same applies to the traits listed in
+/// the `derive(...)` type-level attribute.
+///
+/// If the type doesn't implement [`Copy`] (i.e. `#[variants(no_copy)]`), the
+/// generated methods take `self` by reference rather than by value.
+/// 
+/// The marker types generated by the `markers` type-level attribute refer to the
+/// `enum` type and the `{Enum}Variant` trait through `super`, which doesn't name
+/// the enclosing block of an item declared within a function body (or any other
+/// block), but the enclosing module. Since the macro cannot detect where the
+/// `enum` type is declared, specifying the attribute on such a type results in
+/// "cannot find ... in `super`" compilation errors, rather than in a dedicated
+/// error message.
 ///
 /// # Examples
 ///
@@ -850,7 +2782,7 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 /// assert_eq!(Ok(Weekday::Saturday), <Weekday as FromStr>::from_str("Sat"));
 /// assert_eq!(Ok(Weekday::Sunday), <Weekday as FromStr>::from_str("Sun"));
 ///
-/// assert_eq!(Err(ParseWeekdayError), <Weekday as FromStr>::from_str("invalid"));
+/// assert!(<Weekday as FromStr>::from_str("invalid").is_err());
 ///
 /// // The enum has been marked as `deserialize`, so `serde::Deserialize` implementation is available.
 /// #[cfg(feature = "serde")]
@@ -972,8 +2904,22 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
 ///
 /// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
 /// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+/// [`Debug`]: https://doc.rust-lang.org/std/fmt/trait.Debug.html
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
 /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// [`DoubleEndedIterator`]: https://doc.rust-lang.org/std/iter/trait.DoubleEndedIterator.html
+/// [`Eq`]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+/// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
 /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`FusedIterator`]: https://doc.rust-lang.org/std/iter/trait.FusedIterator.html
+/// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+/// [`IndexMut`]: https://doc.rust-lang.org/std/ops/trait.IndexMut.html
+/// [`Index`]: https://doc.rust-lang.org/std/ops/trait.Index.html
+/// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+/// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+/// [`PartialOrd`]: https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html
+/// [`TryFrom`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
 /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
 /// [`Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
 #[proc_macro_derive(Variants, attributes(variants))]
@@ -997,9 +2943,29 @@ mod test {
         macrotest::expand_args("tests/expand/serde/*.rs", &["--features", "serde"]);
     }
 
+    #[test]
+    fn pass() {
+        let test = trybuild::TestCases::new();
+        test.pass("tests/pass/*.rs");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn pass_serde() {
+        let test = trybuild::TestCases::new();
+        test.pass("tests/pass/serde/*.rs");
+    }
+
     #[test]
     fn error() {
         let test = trybuild::TestCases::new();
         test.compile_fail("tests/fail/*.rs");
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn error_serde() {
+        let test = trybuild::TestCases::new();
+        test.compile_fail("tests/fail/serde/*.rs");
+    }
 }
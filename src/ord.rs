@@ -0,0 +1,49 @@
+use darling::FromMeta;
+use darling::ast::NestedMeta;
+use syn::Meta;
+
+use crate::nested_meta::NestedMetaSliceExt;
+
+/// Ordering strategy to be used as an outer attribute of the [`TargetEnum`],
+/// determining the order of [`TargetVariant`]s established by the generated
+/// [`PartialOrd`] and [`Ord`] trait implementations.
+///
+/// [`TargetEnum`]: crate::target::enum::TargetEnum
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum OrdStrategy {
+    /// Orders variants by their declaration order.
+    #[default]
+    Declaration,
+    /// Orders variants lexicographically by their string representation,
+    /// falling back to the declaration order on equal string representations.
+    Name,
+    /// Orders variants by their explicit `#[variants(rank = N)]` rank.
+    Rank,
+}
+
+impl OrdStrategy {
+    /// The list of valid [`Meta`]s for the [`OrdStrategy`] attribute.
+    const VALID_PATHS: &'static [&'static str] = &["declaration", "name", "rank"];
+}
+
+impl FromMeta for OrdStrategy {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    #[rustfmt::skip]
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let nested_meta = items.get_one_exactly()?;
+
+        match nested_meta {
+            NestedMeta::Meta(meta) => match meta {
+                Meta::Path(path) if path.is_ident("declaration") => Ok(Self::Declaration),
+                Meta::Path(path) if path.is_ident("name") => Ok(Self::Name),
+                Meta::Path(path) if path.is_ident("rank") => Ok(Self::Rank),
+                meta => Err(darling::Error::unknown_field_path_with_alts(meta.path(), Self::VALID_PATHS)),
+            },
+            NestedMeta::Lit(lit) => Err(darling::Error::unexpected_lit_type(lit)),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use darling::FromMeta;
+use darling::util::PathList;
+use proc_macro2::Span;
+use syn::{Ident, Path, Visibility};
+
+/// The forms of string representations of [`TargetVariant`]s accepted by the
+/// generated parsing implementations, in addition to aliases.
+///
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum ParseForms {
+    /// Accepts the final string representation only.
+    Full,
+    /// Accepts the abbreviated string representation only.
+    Abbr,
+    /// Accepts both the final and abbreviated string representations.
+    #[default]
+    Both,
+}
+
+impl ParseForms {
+    /// Whether the final string representation is accepted.
+    #[inline]
+    pub(crate) fn accepts_full(self) -> bool {
+        matches!(self, Self::Full | Self::Both)
+    }
+
+    /// Whether the abbreviated string representation is accepted.
+    #[inline]
+    pub(crate) fn accepts_abbr(self) -> bool {
+        matches!(self, Self::Abbr | Self::Both)
+    }
+
+    /// Returns the identifier of the generated associated constant listing the
+    /// "_parse candidates_" of the accepted forms.
+    pub(crate) fn candidates_ident(self) -> Ident {
+        let ident = match self {
+            Self::Full => "PARSE_CANDIDATES_FULL",
+            Self::Abbr => "PARSE_CANDIDATES_ABBR",
+            Self::Both => "PARSE_CANDIDATES",
+        };
+
+        Ident::new(ident, Span::call_site())
+    }
+}
+
+/// Parsing options to be used as an outer attribute of the [`TargetEnum`],
+/// customizing how the generated parsing implementations (i.e. [`FromStr`]
+/// and `Deserialize`) match the input against the (abbreviated) string
+/// representations of [`TargetVariant`]s.
+///
+/// The options can be omitted altogether (e.g. `#[variants(from_str)]`), in
+/// which case the input is matched exactly against both the final and
+/// abbreviated string representations.
+///
+/// [`FromStr`]: ::std::str::FromStr
+/// [`TargetEnum`]: crate::target::enum::TargetEnum
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+#[darling(default, from_word = || Ok(Self::default()), and_then = Self::validate)]
+pub(crate) struct ParseOptions {
+    /// Whether to match the input regardless of its ASCII case.
+    ///
+    /// This field represents the `case_insensitive` option.
+    case_insensitive: bool,
+    /// Whether to trim leading and trailing whitespace off the input before
+    /// matching it.
+    ///
+    /// This field represents the `trim` option.
+    trim: bool,
+    /// Whether to accept the final string representation only.
+    ///
+    /// This field represents the `full` option.
+    full: bool,
+    /// Whether to accept the abbreviated string representation only.
+    ///
+    /// This field represents the `abbr` option.
+    abbr: bool,
+    /// Whether to accept both the final and abbreviated string representations.
+    ///
+    /// This field represents the `both` option.
+    both: bool,
+}
+
+impl ParseOptions {
+    /// Validates that at most one of the `full`, `abbr` and `both` options is
+    /// specified.
+    fn validate(self) -> darling::Result<Self> {
+        if [self.full, self.abbr, self.both]
+            .into_iter()
+            .filter(|&flag| flag)
+            .count()
+            > 1
+        {
+            return Err(darling::Error::custom(
+                "at most one of `full`, `abbr` and `both` options can be specified",
+            ));
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the forms of string representations accepted when parsing.
+    #[inline]
+    pub(crate) fn forms(self) -> ParseForms {
+        match (self.full, self.abbr) {
+            (true, _) => ParseForms::Full,
+            (_, true) => ParseForms::Abbr,
+            _ => ParseForms::Both,
+        }
+    }
+
+    /// Whether to match the input regardless of its ASCII case.
+    #[inline]
+    pub(crate) fn case_insensitive(self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Whether to trim leading and trailing whitespace off the input before
+    /// matching it.
+    #[inline]
+    pub(crate) fn trim(self) -> bool {
+        self.trim
+    }
+}
+
+/// [`FromStr`] options to be used as an outer attribute of the [`TargetEnum`],
+/// extending [`ParseOptions`] with the configuration of the generated parsing
+/// error type.
+///
+/// [`FromStr`]: ::std::str::FromStr
+/// [`TargetEnum`]: crate::target::enum::TargetEnum
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(default, from_word = || Ok(Self::default()), and_then = Self::validate)]
+pub(crate) struct FromStrOptions {
+    /// The parsing options shared with the other generated parsing
+    /// implementations.
+    #[darling(flatten)]
+    parse: ParseOptions,
+    /// The identifier of the generated parsing error type, defaulting to
+    /// `Parse{Enum}Error`.
+    ///
+    /// This field represents the `error = "..."` option.
+    error: Option<Ident>,
+    /// The visibility of the generated parsing error type, defaulting to
+    /// `pub`.
+    ///
+    /// This field represents the `error_vis = "..."` option.
+    error_vis: Option<Visibility>,
+    /// The additional traits to be derived on the generated parsing error type.
+    ///
+    /// This field represents the `error_derive(...)` option.
+    error_derive: PathList,
+    /// The user-supplied error type to be used as the [`FromStr::Err`]
+    /// associated type, converted from the generated parsing error type by
+    /// means of its [`From`] trait implementation.
+    ///
+    /// This field represents the `error_type = "..."` option.
+    ///
+    /// [`FromStr::Err`]: ::std::str::FromStr::Err
+    error_type: Option<Path>,
+    /// The maximum number of characters of the rejected input to be kept by the
+    /// generated parsing error type.
+    ///
+    /// This field represents the `input_limit = N` option.
+    input_limit: Option<usize>,
+}
+
+impl FromStrOptions {
+    /// The list of valid traits to be derived on the generated parsing error
+    /// type.
+    const VALID_ERROR_DERIVES: &'static [&'static str] = &["Clone", "Hash"];
+
+    /// Validates the traits to be derived on the generated parsing error type.
+    fn validate(self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+
+        for path in self.error_derive.iter() {
+            if !Self::VALID_ERROR_DERIVES
+                .iter()
+                .any(|valid| path.is_ident(valid))
+            {
+                errors.push(
+                    darling::Error::unknown_field_path_with_alts(path, Self::VALID_ERROR_DERIVES)
+                        .with_span(path),
+                );
+            }
+        }
+
+        errors.finish_with(self)
+    }
+
+    /// Returns the parsing options shared with the other generated parsing
+    /// implementations.
+    #[inline]
+    pub(crate) fn parse(&self) -> ParseOptions {
+        self.parse
+    }
+
+    /// Returns the identifier of the generated parsing error type, if
+    /// customized.
+    #[inline]
+    pub(crate) fn error(&self) -> Option<&Ident> {
+        self.error.as_ref()
+    }
+
+    /// Returns the visibility of the generated parsing error type, if
+    /// customized.
+    #[inline]
+    pub(crate) fn error_vis(&self) -> Option<&Visibility> {
+        self.error_vis.as_ref()
+    }
+
+    /// Returns the additional traits to be derived on the generated parsing
+    /// error type.
+    #[inline]
+    pub(crate) fn error_derive(&self) -> &[Path] {
+        &self.error_derive
+    }
+
+    /// Returns the user-supplied error type to be used as the [`FromStr::Err`]
+    /// associated type, if any.
+    ///
+    /// [`FromStr::Err`]: ::std::str::FromStr::Err
+    #[inline]
+    pub(crate) fn error_type(&self) -> Option<&Path> {
+        self.error_type.as_ref()
+    }
+
+    /// Returns the maximum number of characters of the rejected input to be
+    /// kept by the generated parsing error type, if bounded.
+    #[inline]
+    pub(crate) fn input_limit(&self) -> Option<usize> {
+        self.input_limit
+    }
+}
+
+/// `Deserialize` options to be used as an outer attribute of the
+/// [`TargetEnum`], extending [`ParseOptions`] with the fallback behavior on
+/// unknown values.
+///
+/// [`TargetEnum`]: crate::target::enum::TargetEnum
+#[derive(Debug, Clone, Copy, Default, FromMeta)]
+#[darling(default, from_word = || Ok(Self::default()))]
+#[cfg(feature = "serde")]
+pub(crate) struct DeserializeOptions {
+    /// The parsing options shared with the other generated parsing
+    /// implementations.
+    #[darling(flatten)]
+    parse: ParseOptions,
+    /// Whether to deserialize unknown values as the default variant (i.e. the
+    /// variant marked with the `#[variants(default)]` attribute), rather than
+    /// failing.
+    ///
+    /// This field represents the `fallback` option.
+    fallback: bool,
+}
+
+#[cfg(feature = "serde")]
+impl DeserializeOptions {
+    /// Returns the parsing options shared with the other generated parsing
+    /// implementations.
+    #[inline]
+    pub(crate) fn parse(self) -> ParseOptions {
+        self.parse
+    }
+
+    /// Whether to deserialize unknown values as the default variant.
+    #[inline]
+    pub(crate) fn fallback(self) -> bool {
+        self.fallback
+    }
+}
@@ -0,0 +1,69 @@
+use syn::Path;
+
+/// Case convention to be applied by rename strategies to the string
+/// representation of [`TargetVariant`]s.
+///
+/// Apart from [`RenameCase::Uppercase`] and [`RenameCase::Lowercase`], which
+/// act on the string as a whole, case conventions split the string into words
+/// (see [`StrExt::split_words`]) and join them back together according to the
+/// convention.
+///
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+/// [`StrExt::split_words`]: crate::string::StrExt::split_words
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameCase {
+    /// Converts the string representation to uppercase (e.g. `NORTHEAST`).
+    Uppercase,
+    /// Converts the string representation to lowercase (e.g. `northeast`).
+    Lowercase,
+    /// Converts the string representation to snake case (e.g. `north_east`).
+    SnakeCase,
+    /// Converts the string representation to kebab case (e.g. `north-east`).
+    KebabCase,
+    /// Converts the string representation to camel case (e.g. `northEast`).
+    CamelCase,
+    /// Converts the string representation to screaming snake case (e.g.
+    /// `NORTH_EAST`).
+    ScreamingSnakeCase,
+    /// Converts the string representation to title case (e.g. `North East`).
+    TitleCase,
+    /// Converts the string representation to train case (e.g. `North-East`).
+    TrainCase,
+    /// Converts the string representation to dot case (e.g. `north.east`).
+    DotCase,
+}
+
+impl RenameCase {
+    /// The list of valid [`Meta::Path`]s for the [`RenameCase`].
+    ///
+    /// [`Meta::Path`]: ::syn::Meta::Path
+    pub(crate) const VALID_PATHS: &'static [&'static str] = &[
+        "uppercase",
+        "lowercase",
+        "snake_case",
+        "kebab_case",
+        "camel_case",
+        "screaming_snake_case",
+        "title_case",
+        "train_case",
+        "dot_case",
+    ];
+
+    /// Returns the [`RenameCase`] matching the given [`Path`], if any.
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        let ident = path.get_ident()?;
+
+        match ident.to_string().as_str() {
+            "uppercase" => Some(Self::Uppercase),
+            "lowercase" => Some(Self::Lowercase),
+            "snake_case" => Some(Self::SnakeCase),
+            "kebab_case" => Some(Self::KebabCase),
+            "camel_case" => Some(Self::CamelCase),
+            "screaming_snake_case" => Some(Self::ScreamingSnakeCase),
+            "title_case" => Some(Self::TitleCase),
+            "train_case" => Some(Self::TrainCase),
+            "dot_case" => Some(Self::DotCase),
+            _ => None,
+        }
+    }
+}
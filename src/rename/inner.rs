@@ -3,22 +3,32 @@ use darling::ast::NestedMeta;
 use syn::{Lit, Meta};
 
 use crate::nested_meta::NestedMetaSliceExt;
+use crate::rename::case::RenameCase;
 
 /// Rename strategy to be used as an inner attribute of the [`TargetVariant`]s.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum InnerRenameStrategy {
     /// Replaces variant string representation with given string literal.
     Literal(String),
-    /// Converts variant string representation to uppercase.
-    Uppercase,
-    /// Converts variant string representation to lowercase.
-    Lowercase,
+    /// Converts variant string representation to the given case convention.
+    Case(RenameCase),
 }
 
 impl InnerRenameStrategy {
     /// The list of valid [`Meta::Path`]s for the [`InnerRenameStrategy`]
     /// attribute.
-    const VALID_PATHS: &'static [&'static str] = &["uppercase", "lowercase", "..."];
+    const VALID_PATHS: &'static [&'static str] = &[
+        "uppercase",
+        "lowercase",
+        "snake_case",
+        "kebab_case",
+        "camel_case",
+        "screaming_snake_case",
+        "title_case",
+        "train_case",
+        "dot_case",
+        "...",
+    ];
 }
 
 impl FromMeta for InnerRenameStrategy {
@@ -32,9 +42,9 @@ impl FromMeta for InnerRenameStrategy {
 
         match nested_meta {
             NestedMeta::Meta(meta) => match meta {
-                Meta::Path(path) if path.is_ident("uppercase") => Ok(Self::Uppercase),
-                Meta::Path(path) if path.is_ident("lowercase") => Ok(Self::Lowercase),
-                Meta::Path(path) => Err(darling::Error::unknown_field_path_with_alts(path, Self::VALID_PATHS)),
+                Meta::Path(path) => RenameCase::from_path(path)
+                    .map(Self::Case)
+                    .ok_or_else(|| darling::Error::unknown_field_path_with_alts(path, Self::VALID_PATHS)),
                 _ => Err(darling::Error::unsupported_format("non-path")),
             },
             NestedMeta::Lit(literal) => match literal {
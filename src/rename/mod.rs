@@ -1,2 +1,3 @@
+pub mod case;
 pub mod inner;
 pub mod outer;
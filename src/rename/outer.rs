@@ -3,20 +3,19 @@ use darling::ast::NestedMeta;
 use syn::Meta;
 
 use crate::nested_meta::NestedMetaSliceExt;
+use crate::rename::case::RenameCase;
 
 /// Rename strategy to be used as an outer attribute of the [`TargetEnum`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum OuterRenameStrategy {
-    /// Converts variant string representation to uppercase.
-    Uppercase,
-    /// Converts variant string representation to lowercase.
-    Lowercase,
-}
+pub(crate) struct OuterRenameStrategy(RenameCase);
 
 impl OuterRenameStrategy {
-    /// The list of valid [`Meta::Path`]s for the [`OuterRenameStrategy`]
-    /// attribute.
-    const VALID_PATHS: &'static [&'static str] = &["uppercase", "lowercase"];
+    /// Returns the case convention the variant string representation is
+    /// converted to.
+    #[inline]
+    pub(crate) fn case(self) -> RenameCase {
+        self.0
+    }
 }
 
 impl FromMeta for OuterRenameStrategy {
@@ -25,9 +24,9 @@ impl FromMeta for OuterRenameStrategy {
         let nested_meta = items.get_one_exactly()?;
 
         match nested_meta {
-            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("uppercase") => Ok(Self::Uppercase),
-            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lowercase") => Ok(Self::Lowercase),
-            NestedMeta::Meta(Meta::Path(path)) => Err(darling::Error::unknown_field_path_with_alts(path, Self::VALID_PATHS)),
+            NestedMeta::Meta(Meta::Path(path)) => RenameCase::from_path(path)
+                .map(Self)
+                .ok_or_else(|| darling::Error::unknown_field_path_with_alts(path, RenameCase::VALID_PATHS)),
             _ => Err(darling::Error::unsupported_format("non-path")),
         }
     }
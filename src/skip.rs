@@ -0,0 +1,68 @@
+use darling::FromMeta;
+
+/// Skip modes to be used as an inner attribute of the [`TargetVariant`]s,
+/// excluding the variant from some of the generated implementations.
+///
+/// The bare `#[variants(skip)]` attribute excludes the variant from iteration
+/// and listings, equivalently to `#[variants(skip(iter, list))]`.
+///
+/// [`TargetVariant`]: crate::target::variant::TargetVariant
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+#[allow(clippy::struct_excessive_bools)]
+#[darling(default, from_word = Self::from_word)]
+pub(crate) struct Skip {
+    /// Whether to exclude the variant from iteration (and indexing).
+    ///
+    /// This field represents the `iter` mode.
+    iter: bool,
+    /// Whether to reject the variant string representations when parsing.
+    ///
+    /// This field represents the `parse` mode.
+    parse: bool,
+    /// Whether to fail when serializing the variant.
+    ///
+    /// This field represents the `serialize` mode.
+    serialize: bool,
+    /// Whether to exclude the variant from listings (i.e. the generated
+    /// listing methods, parsing error messages and parsing suggestions).
+    ///
+    /// This field represents the `list` mode.
+    list: bool,
+}
+
+impl Skip {
+    /// Returns the skip modes of the bare `#[variants(skip)]` attribute.
+    #[allow(clippy::unnecessary_wraps)]
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self {
+            iter: true,
+            list: true,
+            ..Self::default()
+        })
+    }
+
+    /// Whether the variant is excluded from iteration (and indexing).
+    #[inline]
+    pub(crate) fn skips_iter(self) -> bool {
+        self.iter
+    }
+
+    /// Whether the variant string representations are rejected when parsing.
+    #[inline]
+    pub(crate) fn skips_parse(self) -> bool {
+        self.parse
+    }
+
+    /// Whether serializing the variant fails.
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub(crate) fn skips_serialize(self) -> bool {
+        self.serialize
+    }
+
+    /// Whether the variant is excluded from listings.
+    #[inline]
+    pub(crate) fn skips_list(self) -> bool {
+        self.list
+    }
+}
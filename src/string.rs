@@ -1,39 +1,247 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::abbr::AbbrStrategy;
+use crate::rename::case::RenameCase;
+
 /// Extension trait providing in-place transformation methods for the [`String`]
 /// type.
 ///
 /// This trait extends the [`String`] type provided by the standard library with
 /// methods that act by taking ownership of the value, transforming it in-place
 /// and returning it afterwards, allowing method chaining.
+///
+/// All transformations are Unicode aware: case conversions follow the Unicode
+/// case mapping rules (see [`str::to_uppercase`] and [`str::to_lowercase`]) and
+/// the value is never split within an extended grapheme cluster (i.e. a user
+/// perceived character, such as `ì` or `🇮🇹`).
 pub(crate) trait StringExt {
     /// Returns an uppercase conversion of the [`String`] by taking
-    /// ownership of the value and transforming it in-place.
+    /// ownership of the value.
+    ///
+    /// The conversion is performed in-place only if the value is ASCII, since
+    /// the uppercase conversion of non-ASCII characters may change its length.
     fn to_uppercase_in_place(self) -> Self;
 
     /// Returns an lowercase conversion of the [`String`] by taking
-    /// ownership of the value and transforming it in-place.
+    /// ownership of the value.
+    ///
+    /// The conversion is performed in-place only if the value is ASCII, since
+    /// the lowercase conversion of non-ASCII characters may change its length.
     fn to_lowercase_in_place(self) -> Self;
 
-    /// Returns an abbreviation of the [`String`] by taking ownership
-    /// of the value and transforming it in-place.
-    fn to_abbr_in_place(self) -> Self;
+    /// Returns an abbreviation of the [`String`] following the given
+    /// [`AbbrStrategy`] by taking ownership of the value and transforming it
+    /// in-place.
+    ///
+    /// The [`AbbrStrategy::UniquePrefix`] strategy depends on the string
+    /// representations of all the variants, hence it's expected to be resolved
+    /// into an [`AbbrStrategy::Length`] beforehand (see
+    /// [`TargetEnum::abbr_strategy`]) and leaves the value untouched otherwise.
+    ///
+    /// [`TargetEnum::abbr_strategy`]: crate::target::enum::TargetEnum::abbr_strategy
+    fn to_abbr_in_place(self, strategy: AbbrStrategy) -> Self;
+
+    /// Returns a conversion of the [`String`] to the given [`RenameCase`] by
+    /// taking ownership of the value.
+    ///
+    /// Case conventions other than [`RenameCase::Uppercase`] and
+    /// [`RenameCase::Lowercase`] split the value into words (see
+    /// [`StrExt::split_words`]) before joining them back together, hence
+    /// the transformation is not always performed in-place.
+    fn to_case_in_place(self, case: RenameCase) -> Self;
+
+    /// Returns a conversion of the [`String`] to the given [`RenameCase`], if
+    /// any, abbreviated following the given [`AbbrStrategy`] by taking
+    /// ownership of the value.
+    ///
+    /// The [`AbbrStrategy::Initials`] strategy takes the word boundaries from
+    /// the value before the case conversion, since converting it to the
+    /// [`RenameCase::Uppercase`] and [`RenameCase::Lowercase`] case conventions
+    /// loses them (e.g. `NorthEast` is abbreviated to `NE`, not `N`).
+    fn to_case_abbr_in_place(self, case: Option<RenameCase>, strategy: AbbrStrategy) -> Self;
 }
 
 impl StringExt for String {
-    #[inline]
     fn to_uppercase_in_place(mut self) -> Self {
-        self.make_ascii_uppercase();
-        self
+        match self.is_ascii() {
+            true => {
+                self.make_ascii_uppercase();
+                self
+            }
+            false => self.to_uppercase(),
+        }
     }
 
-    #[inline]
     fn to_lowercase_in_place(mut self) -> Self {
-        self.make_ascii_lowercase();
-        self
+        match self.is_ascii() {
+            true => {
+                self.make_ascii_lowercase();
+                self
+            }
+            false => self.to_lowercase(),
+        }
+    }
+
+    fn to_abbr_in_place(mut self, strategy: AbbrStrategy) -> Self {
+        match strategy {
+            AbbrStrategy::Length(len) => {
+                if let Some((offset, _)) = self.grapheme_indices(true).nth(len) {
+                    self.truncate(offset);
+                }
+
+                self
+            }
+            AbbrStrategy::Initials => self
+                .split_words()
+                .into_iter()
+                .filter_map(|word| word.graphemes(true).next())
+                .collect(),
+            AbbrStrategy::NoVowels => {
+                let mut graphemes = self.graphemes(true);
+                let first = graphemes.next();
+
+                first
+                    .into_iter()
+                    .chain(graphemes.filter(|grapheme| !is_vowel(grapheme)))
+                    .collect()
+            }
+            AbbrStrategy::UniquePrefix => self,
+        }
+    }
+
+    #[rustfmt::skip]
+    fn to_case_in_place(self, case: RenameCase) -> Self {
+        match case {
+            RenameCase::Uppercase => self.to_uppercase_in_place(),
+            RenameCase::Lowercase => self.to_lowercase_in_place(),
+            RenameCase::SnakeCase => join_words(&self, "_", str::to_lowercase, str::to_lowercase),
+            RenameCase::KebabCase => join_words(&self, "-", str::to_lowercase, str::to_lowercase),
+            RenameCase::CamelCase => join_words(&self, "", str::to_lowercase, capitalize),
+            RenameCase::ScreamingSnakeCase => join_words(&self, "_", str::to_uppercase, str::to_uppercase),
+            RenameCase::TitleCase => join_words(&self, " ", capitalize, capitalize),
+            RenameCase::TrainCase => join_words(&self, "-", capitalize, capitalize),
+            RenameCase::DotCase => join_words(&self, ".", str::to_lowercase, str::to_lowercase),
+        }
+    }
+
+    fn to_case_abbr_in_place(self, case: Option<RenameCase>, strategy: AbbrStrategy) -> Self {
+        match (case, strategy) {
+            (None, _) => self.to_abbr_in_place(strategy),
+            (
+                Some(case @ (RenameCase::Uppercase | RenameCase::Lowercase)),
+                AbbrStrategy::Initials,
+            ) => self.to_abbr_in_place(strategy).to_case_in_place(case),
+            (Some(case), _) => self.to_case_in_place(case).to_abbr_in_place(strategy),
+        }
+    }
+}
+
+/// Extension trait providing inspection methods for the [`str`] type.
+pub(crate) trait StrExt {
+    /// Splits the string slice into words.
+    ///
+    /// The string slice is inspected one extended grapheme cluster at a time,
+    /// classifying each cluster by its base (i.e. first) character, so that
+    /// combining marks are never split from the character they're attached
+    /// to. Words are delimited by any non-alphanumeric character (e.g. `_`,
+    /// `-`, `.` or whitespace) and by case boundaries, that are:
+    ///
+    /// - a lowercase letter or a digit followed by an uppercase letter (e.g.
+    ///   `NorthEast` and `Utf8Encoding` split into `North`, `East` and `Utf8`,
+    ///   `Encoding` respectively);
+    /// - the last uppercase letter of an acronym followed by a lowercase
+    ///   letter (e.g. `HTTPServer` splits into `HTTP` and `Server`).
+    ///
+    /// Digits are never split from the word they're attached to (e.g. `Utf8`
+    /// and `H264` are both single words).
+    fn split_words(&self) -> Vec<&str>;
+}
+
+impl StrExt for str {
+    fn split_words(&self) -> Vec<&str> {
+        let graphemes = self
+            .grapheme_indices(true)
+            .filter_map(|(offset, grapheme)| Some((offset, grapheme.chars().next()?)))
+            .collect::<Vec<_>>();
+
+        let mut words = Vec::new();
+        let mut start = None;
+
+        for (index, &(offset, current)) in graphemes.iter().enumerate() {
+            match start {
+                _ if !current.is_alphanumeric() => {
+                    if let Some(start) = start.take() {
+                        words.push(&self[start..offset]);
+                    }
+                }
+                None => start = Some(offset),
+                Some(word_start) => {
+                    let previous = graphemes[index - 1].1;
+                    let next = graphemes.get(index + 1).map(|&(_, next)| next);
+
+                    let is_word_start = current.is_uppercase()
+                        && (previous.is_lowercase()
+                            || previous.is_numeric()
+                            || (previous.is_uppercase() && next.is_some_and(char::is_lowercase)));
+
+                    if is_word_start {
+                        words.push(&self[word_start..offset]);
+                        start = Some(offset);
+                    }
+                }
+            }
+        }
+
+        if let Some(start) = start {
+            words.push(&self[start..]);
+        }
+
+        words
     }
+}
+
+/// Splits the given value into words (see [`StrExt::split_words`]) and joins
+/// them back together using `separator`, after transforming the first word with
+/// `first_word` and any other word with `other_words`.
+fn join_words(
+    value: &str,
+    separator: &str,
+    first_word: fn(&str) -> String,
+    other_words: fn(&str) -> String,
+) -> String {
+    let mut words = value.split_words().into_iter();
+    let mut joined = words.next().map(first_word).unwrap_or_default();
 
-    #[inline]
-    fn to_abbr_in_place(mut self) -> Self {
-        self.truncate(3);
-        self
+    for word in words {
+        joined.push_str(separator);
+        joined.push_str(&other_words(word));
     }
+
+    joined
+}
+
+/// Capitalizes the given word, i.e. makes its first extended grapheme cluster
+/// uppercase and the remaining ones lowercase.
+fn capitalize(word: &str) -> String {
+    let Some(first) = word.graphemes(true).next() else {
+        return String::new();
+    };
+
+    let mut capitalized = first.to_uppercase();
+    capitalized.push_str(&word[first.len()..].to_lowercase());
+    capitalized
+}
+
+/// Checks whether the base (i.e. first) character of the given extended
+/// grapheme cluster is a vowel of the latin alphabet, regardless of its case
+/// and diacritics (e.g. `a`, `À` and `ì` are all vowels).
+#[rustfmt::skip]
+fn is_vowel(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(|base| base.to_lowercase().all(|c| matches!(c,
+        'a' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' |
+        'e' | 'è' | 'é' | 'ê' | 'ë' |
+        'i' | 'ì' | 'í' | 'î' | 'ï' |
+        'o' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' |
+        'u' | 'ù' | 'ú' | 'û' | 'ü'
+    )))
 }
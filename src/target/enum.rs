@@ -0,0 +1,1486 @@
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet};
+
+use darling::FromDeriveInput;
+use darling::ast::Data;
+use darling::util::PathList;
+use itertools::Itertools;
+use proc_macro2::{Span, TokenStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, LitStr, Meta, Path, Token, Visibility};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::abbr::AbbrStrategy;
+use crate::ident::IdentExt;
+use crate::ord::OrdStrategy;
+#[cfg(feature = "serde")]
+use crate::parse::DeserializeOptions;
+use crate::parse::{FromStrOptions, ParseForms, ParseOptions};
+use crate::rename::case::RenameCase;
+use crate::rename::outer::OuterRenameStrategy;
+use crate::target::variant::{TargetVariant, is_lowercase_ident};
+
+/// The type representing the `enum` type the macro is being derived on.
+///
+/// This type is constructed while the input [`TokenStream`] is being parsed,
+/// and is populated with information about the `enum` identifier and its
+/// variants's and outer attributes.
+///
+/// [`TokenStream`]: ::proc_macro2::TokenStream
+#[derive(Debug, Clone, FromDeriveInput)]
+#[allow(clippy::struct_excessive_bools)]
+#[darling(
+    supports(enum_unit),
+    attributes(variants),
+    forward_attrs,
+    and_then = Self::validate
+)]
+pub(crate) struct TargetEnum {
+    /// The identifier of the `enum` type the macro is being derived on.
+    ident: Ident,
+    /// The visibility of the `enum` type the macro is being derived on.
+    vis: Visibility,
+    /// The outer attributes of the `enum` type the macro is being derived on,
+    /// such as `#[repr(...)]` and `#[derive(...)]`.
+    ///
+    /// Only the `#[derive(...)]` attributes following the one invoking the
+    /// macro are available, as the compiler strips the others.
+    attrs: Vec<Attribute>,
+    /// The body of the `enum` type the macro is being derived on.
+    ///
+    /// This field represents the `enum`'s variants and allows iteration over
+    /// them and their (abbreviated) string representations.
+    data: Data<TargetVariant, ()>,
+    /// The rename strategy for the string representation of `enum` variants
+    /// that the macro is being derived on.
+    ///
+    /// This field represents the `#[variants(rename(...))]` outer attribute.
+    #[darling(default)]
+    rename: Option<OuterRenameStrategy>,
+    /// The rename strategy for the abbreviated string representation of `enum`
+    /// variants that the macro is being derived on.
+    ///
+    /// This field represents the `#[variants(rename_abbr(...))]` outer
+    /// attribute.
+    #[darling(default)]
+    rename_abbr: Option<OuterRenameStrategy>,
+    /// The abbreviation strategy for the abbreviated string representation of
+    /// `enum` variants that the macro is being derived on.
+    ///
+    /// This field represents the `#[variants(abbr(...))]` outer attribute.
+    #[darling(default)]
+    abbr: Option<AbbrStrategy>,
+    /// Whether to generate a [`Display`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string
+    /// representation.
+    ///
+    /// This field represents the `#[variants(display)]` outer attribute.
+    ///
+    /// [`Display`]: ::std::fmt::Display
+    #[darling(default)]
+    display: bool,
+    /// Wether to generate a [`FromStr`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string or
+    /// abbreviated string representations.
+    ///
+    /// This field represents the `#[variants(from_str)]` and
+    /// `#[variants(from_str(...))]` outer attributes, the latter customizing
+    /// the parsing options and the generated parsing error type.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[darling(default)]
+    from_str: Option<FromStrOptions>,
+    /// Whether to allow distinct variants of the `enum` type the macro is being
+    /// derived on to share the same (abbreviated) string representation.
+    ///
+    /// When collisions are allowed, the first declared variant takes
+    /// precedence over the following ones when parsing.
+    ///
+    /// This field represents the `#[variants(allow_collisions)]` outer
+    /// attribute.
+    #[darling(default)]
+    allow_collisions: bool,
+    /// Whether to generate the `{Enum}OrUnknown` companion type, wrapping
+    /// either a variant of the `enum` type the macro is being derived on or
+    /// an unknown string value.
+    ///
+    /// This field represents the `#[variants(or_unknown)]` outer attribute.
+    #[darling(default)]
+    or_unknown: bool,
+    /// Whether to generate the `{Enum}Set` bitset type, representing a set of
+    /// _iterable_ (i.e. non-skipped) variants of the `enum` type the macro is
+    /// being derived on.
+    ///
+    /// This field represents the `#[variants(set)]` outer attribute.
+    #[darling(default)]
+    set: bool,
+    /// Whether to generate the `{Enum}Map<V>` type, associating a value to
+    /// each variant of the `enum` type the macro is being derived on, all of
+    /// which must be _iterable_ (i.e. non-skipped).
+    ///
+    /// This field represents the `#[variants(map)]` outer attribute.
+    #[darling(default)]
+    map: bool,
+    /// Whether to generate the conversions between the `enum` type the macro
+    /// is being derived on and the primitive integer type specified by its
+    /// `#[repr(...)]` attribute.
+    ///
+    /// This field represents the `#[variants(repr)]` outer attribute.
+    #[darling(default)]
+    repr: bool,
+    /// Whether to generate a zero-sized marker type for each variant of the
+    /// `enum` type the macro is being derived on, implementing the generated
+    /// `{Enum}Variant` trait.
+    ///
+    /// The marker types refer to the `enum` type through `super`, hence the
+    /// attribute is only supported on `enum` types declared at module level.
+    ///
+    /// This field represents the `#[variants(markers)]` outer attribute.
+    #[darling(default)]
+    markers: bool,
+    /// The ordering strategy of the [`PartialOrd`] and [`Ord`] trait
+    /// implementations to be generated for the `enum` type the macro is being
+    /// derived on, if any.
+    ///
+    /// This field represents the `#[variants(ord)]` and
+    /// `#[variants(ord(...))]` outer attributes.
+    #[darling(default)]
+    ord: Option<OrdStrategy>,
+    /// Whether to skip the generation of the [`Copy`] and [`Clone`] trait
+    /// implementations for the `enum` type the macro is being derived on.
+    ///
+    /// This field represents the `#[variants(no_copy)]` outer attribute.
+    #[darling(default)]
+    no_copy: bool,
+    /// The additional standard traits to be implemented for the `enum` type
+    /// the macro is being derived on.
+    ///
+    /// This field represents the `#[variants(derive(...))]` outer attribute.
+    #[darling(default)]
+    derive: PathList,
+    /// The standard traits already implemented for the `enum` type the macro
+    /// is being derived on by means the macro cannot detect, such as the
+    /// `#[derive(...)]` attribute invoking the macro itself.
+    ///
+    /// This field represents the `#[variants(derived(...))]` outer attribute.
+    #[darling(default)]
+    derived: PathList,
+    /// The prefix of the generated predicate methods checking whether a value
+    /// of the `enum` type the macro is being derived on is a given variant,
+    /// defaulting to `is_`.
+    ///
+    /// This field represents the `#[variants(predicate_prefix = "...")]` outer
+    /// attribute.
+    #[darling(default)]
+    predicate_prefix: Option<LitStr>,
+    /// Wether to generate a [`Deserialize`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string
+    /// representation.
+    ///
+    /// This field represents the `#[variants(deserialize)]` and
+    /// `#[variants(deserialize(...))]` outer attributes, the latter
+    /// customizing the parsing options and the fallback on unknown values.
+    ///
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    #[darling(default)]
+    #[cfg(feature = "serde")]
+    deserialize: Option<DeserializeOptions>,
+    /// Wether to generate a [`Serialize`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string
+    /// representation.
+    ///
+    /// [`Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
+    #[darling(default)]
+    #[cfg(feature = "serde")]
+    serialize: bool,
+}
+
+impl TargetEnum {
+    /// The list of primitive integer types, i.e. valid `#[repr(...)]` types
+    /// of the `enum` type the macro is being derived on, and the types the
+    /// `TryFrom` trait is implemented for.
+    pub(crate) const INTEGER_TYPES: &'static [&'static str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    /// The list of unsigned integer types backing the generated `{Enum}Set`
+    /// bitset type, along with their number of bits, in ascending size order.
+    const SET_BITS_TYPES: &'static [(&'static str, usize)] = &[
+        ("u8", 8),
+        ("u16", 16),
+        ("u32", 32),
+        ("u64", 64),
+        ("u128", 128),
+    ];
+
+    /// The list of valid traits to be implemented by means of the
+    /// `#[variants(derive(...))]` outer attribute.
+    const VALID_DERIVES: &'static [&'static str] = &["Debug", "PartialEq", "Eq", "Hash"];
+
+    /// The list of valid traits to be declared as already implemented by means
+    /// of the `#[variants(derived(...))]` outer attribute.
+    const VALID_DERIVED: &'static [&'static str] =
+        &["Clone", "Copy", "Debug", "PartialEq", "Eq", "Hash"];
+
+    /// The list of keywords that cannot be raw identifiers, hence cannot name
+    /// the generated module containing the marker types.
+    const PATH_KEYWORDS: &'static [&'static str] = &["crate", "self", "super"];
+
+    /// Validates the final (abbreviated) string representations of the `enum`
+    /// type the macro is being derived on, accumulating an error for each
+    /// variant failing the validation (see [`TargetVariant::validate`]) and
+    /// for each variant colliding with a previously declared one (unless
+    /// collisions are explicitly allowed), as well as the variants marked as
+    /// `default` and `other`, the variants ranks, the traits to be derived and
+    /// the generated predicate methods.
+    fn validate(self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+
+        for variant in self.iter_variants() {
+            errors.handle(variant.validate(
+                self.rename,
+                self.rename_abbr,
+                self.abbr_strategy(variant),
+            ));
+        }
+
+        if !self.allow_collisions {
+            for error in self.collision_errors() {
+                errors.push(error);
+            }
+        }
+
+        for variant in self
+            .iter_variants()
+            .filter(|variant| variant.is_default())
+            .skip(1)
+        {
+            errors.push(variant.duplicate_default_error());
+        }
+
+        for variant in self
+            .iter_variants()
+            .filter(|variant| variant.is_other())
+            .skip(1)
+        {
+            errors.push(variant.duplicate_other_error());
+        }
+
+        for error in self.rank_errors() {
+            errors.push(error);
+        }
+
+        for path in self.derive.iter() {
+            if !Self::VALID_DERIVES.iter().any(|valid| path.is_ident(valid)) {
+                errors.push(
+                    darling::Error::unknown_field_path_with_alts(path, Self::VALID_DERIVES)
+                        .with_span(path),
+                );
+            }
+        }
+
+        for path in self.derived.iter() {
+            if !Self::VALID_DERIVED.iter().any(|valid| path.is_ident(valid)) {
+                errors.push(
+                    darling::Error::unknown_field_path_with_alts(path, Self::VALID_DERIVED)
+                        .with_span(path),
+                );
+            }
+        }
+
+        if let Some(prefix) = &self.predicate_prefix
+            && !is_lowercase_ident(&prefix.value())
+        {
+            errors.push(
+                darling::Error::custom(
+                    "predicate prefix must be a lowercase identifier (e.g. `is_`)",
+                )
+                .with_span(prefix),
+            );
+        }
+
+        for error in self.predicate_collision_errors() {
+            errors.push(error);
+        }
+
+        if self.set && self.set_bits_type().is_none() {
+            errors.push(darling::Error::custom(
+                "`set` supports at most 128 iterable variants",
+            ));
+        }
+
+        if self.map {
+            for variant in self
+                .iter_variants()
+                .filter(|variant| !variant.is_iterable())
+            {
+                errors.push(variant.map_skipped_error());
+            }
+
+            #[cfg(feature = "serde")]
+            for variant in self.iter_variants().filter(|variant| variant.is_iterable()) {
+                if self.implement_deserialize() && !variant.is_parseable() && !variant.is_other() {
+                    errors.push(variant.map_serde_skipped_error("deserialize", "skip(parse)"));
+                }
+
+                if self.implement_serialize() && !variant.is_serializable() {
+                    errors.push(variant.map_serde_skipped_error("serialize", "skip(serialize)"));
+                }
+            }
+        }
+
+        if self.repr && self.repr().is_none() {
+            errors.push(darling::Error::custom(
+                "`repr` requires a primitive integer `#[repr(...)]` attribute (e.g. `#[repr(u8)]`)",
+            ));
+        }
+
+        #[cfg(feature = "serde")]
+        if self.deserialize_fallback() && self.default_variant_ident().is_none() {
+            errors.push(darling::Error::custom(
+                "`deserialize(fallback)` requires a variant marked as `default`",
+            ));
+        }
+
+        #[cfg(feature = "serde")]
+        if self.deserialize_fallback() && self.other_variant_ident().is_some() {
+            errors.push(darling::Error::custom(
+                "`deserialize(fallback)` conflicts with the variant marked as `other`",
+            ));
+        }
+
+        errors.finish_with(self)
+    }
+
+    /// Returns the parsing options and the accepted forms of string
+    /// representations of each parsing implementation generated for the `enum`
+    /// type the macro is being derived on, i.e. the [`FromStr`] trait
+    /// implementation along with the `from_str_full` and `from_str_abbr`
+    /// methods, the `{Enum}OrUnknown` companion type and the `Deserialize`
+    /// trait implementation.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    fn parse_configurations(&self) -> Vec<(ParseOptions, ParseForms)> {
+        let mut configurations = Vec::new();
+
+        if self.implement_from_str() {
+            let options = self.from_str_options();
+            configurations.extend([
+                (options, options.forms()),
+                (options, ParseForms::Full),
+                (options, ParseForms::Abbr),
+            ]);
+        }
+
+        if self.implement_or_unknown() {
+            let options = self.from_str_options();
+            configurations.push((options, options.forms()));
+        }
+
+        #[cfg(feature = "serde")]
+        if self.implement_deserialize() {
+            let options = self.deserialize_options();
+            configurations.push((options, options.forms()));
+        }
+
+        configurations
+    }
+
+    /// Returns an error for each variant of the `enum` type the macro is being
+    /// derived on whose (abbreviated) string representation collides with the
+    /// (abbreviated) string representation of a previously declared variant,
+    /// within the forms accepted by any generated parsing implementation (see
+    /// [`TargetEnum::parse_configurations`]).
+    ///
+    /// At most one error is reported for each pair of colliding variants.
+    /// Variants marked as `skip(parse)` are never checked, and string
+    /// representations are compared regardless of their ASCII case if the
+    /// parsing implementation is case insensitive.
+    fn collision_errors(&self) -> Vec<darling::Error> {
+        let mut errors = Vec::new();
+        let mut reported = HashSet::new();
+
+        for (options, forms) in self.parse_configurations() {
+            let representations = self
+                .iter_variants()
+                .enumerate()
+                .filter(|(_, variant)| variant.is_parseable())
+                .map(|(index, variant)| {
+                    let name = forms
+                        .accepts_full()
+                        .then(|| ("string", variant.as_str(self.rename)));
+                    let name_abbr = forms.accepts_abbr().then(|| {
+                        let name_abbr = variant.as_str_abbr(
+                            self.rename,
+                            self.rename_abbr,
+                            self.abbr_strategy(variant),
+                        );
+
+                        ("abbreviated string", name_abbr)
+                    });
+
+                    let names = [name, name_abbr]
+                        .into_iter()
+                        .flatten()
+                        .chain(
+                            variant
+                                .aliases()
+                                .iter()
+                                .map(|alias| ("alias", Cow::Borrowed(alias.value()))),
+                        )
+                        .collect::<Vec<_>>();
+
+                    (index, variant, names)
+                })
+                .collect::<Vec<_>>();
+
+            for (position, (index, variant, names)) in representations.iter().enumerate() {
+                for (other_index, other, other_names) in &representations[..position] {
+                    let collision = names.iter().cartesian_product(other_names).find(
+                        |((_, name), (_, other_name))| match options.case_insensitive() {
+                            true => name.eq_ignore_ascii_case(other_name),
+                            false => name == other_name,
+                        },
+                    );
+
+                    if let Some(((kind, name), (other_kind, _))) = collision
+                        && reported.insert((*index, *other_index))
+                    {
+                        errors.push(variant.collision_error(kind, name, other, other_kind));
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+
+    /// Returns an error for each ranked variant if the `enum` type the macro is
+    /// being derived on is not ordered by rank, otherwise an error for each
+    /// unranked variant and for each variant whose rank equals the rank of a
+    /// previously declared variant.
+    fn rank_errors(&self) -> Vec<darling::Error> {
+        if self.ord != Some(OrdStrategy::Rank) {
+            return self
+                .iter_variants()
+                .filter(|variant| variant.rank().is_some())
+                .map(TargetVariant::unexpected_rank_error)
+                .collect();
+        }
+
+        let mut errors = Vec::new();
+
+        for (index, variant) in self.variants().iter().enumerate() {
+            let Some(rank) = variant.rank() else {
+                errors.push(variant.missing_rank_error());
+                continue;
+            };
+
+            if let Some(other) = self.variants()[..index]
+                .iter()
+                .find(|other| other.rank() == Some(rank))
+            {
+                errors.push(variant.rank_collision_error(other));
+            }
+        }
+
+        errors
+    }
+
+    /// Returns an error for each variant of the `enum` type the macro is being
+    /// derived on whose generated predicate method shares its identifier with
+    /// the predicate method of a previously declared variant or of a group, or
+    /// with the generated `const_eq` method.
+    fn predicate_collision_errors(&self) -> Vec<darling::Error> {
+        let predicates = self.iter_variant_predicates().collect::<Vec<_>>();
+        let groups = self.groups();
+
+        let mut errors = Vec::new();
+
+        for (index, (predicate, variant)) in predicates.iter().enumerate() {
+            if let Some((_, other)) = predicates[..index]
+                .iter()
+                .find(|(other_predicate, _)| other_predicate == predicate)
+            {
+                let other = format!(
+                    "the predicate of variant `{}`",
+                    other.declared_ident().to_unraw_string()
+                );
+                errors.push(variant.predicate_collision_error(predicate, &other));
+            } else if let Some(group) = groups
+                .iter()
+                .find(|group| *predicate == self.group_predicate_ident(group))
+            {
+                let other = format!("the predicate of group `{group}`");
+                errors.push(variant.predicate_collision_error(predicate, &other));
+            } else if predicate == "const_eq" {
+                errors.push(variant.predicate_collision_error(predicate, "the `const_eq` method"));
+            }
+        }
+
+        errors
+    }
+
+    /// Returns the identifier of the `enum` type the macro is being derived on.
+    #[inline]
+    pub(crate) fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the visibility of the `enum` type the macro is being derived on.
+    #[inline]
+    pub(crate) fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+
+    /// Returns the primitive integer type specified by the `#[repr(...)]`
+    /// attribute of the `enum` type the macro is being derived on, if any.
+    ///
+    /// Malformed `#[repr(...)]` attributes are ignored, as they're reported by
+    /// the compiler anyway.
+    pub(crate) fn repr(&self) -> Option<Ident> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("repr"))
+            .filter_map(|attr| {
+                attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .find_map(|meta| match meta {
+                Meta::Path(path) if Self::INTEGER_TYPES.iter().any(|ty| path.is_ident(ty)) => {
+                    path.get_ident().cloned()
+                }
+                _ => None,
+            })
+    }
+
+    /// Checks whether the trait with the given identifier is derived on the
+    /// `enum` type the macro is being derived on, either by a `#[derive(...)]`
+    /// attribute following the one invoking the macro or as declared by the
+    /// `#[variants(derived(...))]` outer attribute.
+    ///
+    /// Traits are matched by the last segment of their path (e.g. both `Clone`
+    /// and `std::clone::Clone` match `Clone`).
+    pub(crate) fn is_derived(&self, name: &str) -> bool {
+        self.derived.iter().any(|path| path.is_ident(name))
+            || self
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("derive"))
+                .filter_map(|attr| {
+                    attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                        .ok()
+                })
+                .flatten()
+                .any(|path| {
+                    path.segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == name)
+                })
+    }
+
+    /// Whether to generate a [`Copy`] trait implementation for the `enum` type
+    /// the macro is being derived on, i.e. unless opted out or already
+    /// derived.
+    #[inline]
+    pub(crate) fn implement_copy(&self) -> bool {
+        !self.no_copy && !self.is_derived("Copy")
+    }
+
+    /// Whether to generate a [`Clone`] trait implementation for the `enum`
+    /// type the macro is being derived on, i.e. unless opted out or already
+    /// derived.
+    #[inline]
+    pub(crate) fn implement_clone(&self) -> bool {
+        !self.no_copy && !self.is_derived("Clone")
+    }
+
+    /// Whether the `enum` type the macro is being derived on implements the
+    /// [`Copy`] trait, either generated or derived.
+    ///
+    /// The generated methods take `self` by value if the `enum` type is
+    /// [`Copy`], by reference otherwise.
+    #[inline]
+    pub(crate) fn is_copy(&self) -> bool {
+        !self.no_copy || self.is_derived("Copy")
+    }
+
+    /// Returns the receiver of the generated methods and its type, i.e.
+    /// `self` and `Self` if the `enum` type is [`Copy`], `&self` and `&Self`
+    /// otherwise.
+    pub(crate) fn receiver(&self) -> (TokenStream, TokenStream) {
+        if self.is_copy() {
+            (quote::quote! { self }, quote::quote! { Self })
+        } else {
+            (quote::quote! { &self }, quote::quote! { &Self })
+        }
+    }
+
+    /// Returns an iterator over the additional standard traits to be
+    /// implemented for the `enum` type the macro is being derived on, skipping
+    /// the ones already derived.
+    pub(crate) fn iter_derives(&self) -> impl Iterator<Item = &Path> {
+        self.derive.iter().filter(|path| {
+            path.get_ident()
+                .is_none_or(|ident| !self.is_derived(&ident.to_string()))
+        })
+    }
+
+    /// Returns a sentence naming the variants of the `enum` type the macro is
+    /// being derived on that are `excluded` from the given operation (e.g.
+    /// "with the \[`Weekday::Monday`\] variant excluded from iteration."), to
+    /// be used in the notes of the generated documentation.
+    pub(crate) fn excluded_variants_note(
+        &self,
+        excluded: impl Fn(&TargetVariant) -> bool,
+        operation: &str,
+    ) -> String {
+        let links = self
+            .iter_variants()
+            .filter(|variant| excluded(variant))
+            .map(|variant| {
+                format!(
+                    "[`{}::{}`]",
+                    self.ident.to_unraw_string(),
+                    variant.declared_ident().to_unraw_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        match links.as_slice() {
+            [] => format!("with no variant excluded from {operation}."),
+            [link] => format!("with the {link} variant excluded from {operation}."),
+            [init @ .., last] => format!(
+                "with the {} and {last} variants excluded from {operation}.",
+                init.join(", "),
+            ),
+        }
+    }
+
+    /// Returns a human readable name of the `enum` type the macro is being
+    /// derived on, made of the lowercase words of its identifier (e.g.
+    /// `CardinalDirection` becomes `cardinal direction`), to be used in
+    /// parsing error messages.
+    pub(crate) fn human_readable_name(&self) -> String {
+        self.ident
+            .to_case_string(RenameCase::TitleCase)
+            .to_lowercase()
+    }
+
+    /// Returns variant data of the `enum` type the macro is being derived on.
+    #[inline]
+    pub(crate) fn variants(&self) -> &[TargetVariant] {
+        match self.data {
+            Data::Enum(ref variants) => variants,
+            Data::Struct(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the identifier of the default variant of the `enum` type the
+    /// macro is being derived on, i.e. the variant marked with the
+    /// `#[variants(default)]` attribute, if any.
+    #[inline]
+    pub(crate) fn default_variant_ident(&self) -> Option<&Ident> {
+        self.iter_variants()
+            .find(|variant| variant.is_default())
+            .and_then(TargetVariant::ident)
+    }
+
+    /// Returns the identifier of the catch-all variant of the `enum` type the
+    /// macro is being derived on, i.e. the variant marked with the
+    /// `#[variants(other)]` attribute, if any.
+    #[inline]
+    pub(crate) fn other_variant_ident(&self) -> Option<&Ident> {
+        self.iter_variants()
+            .find(|variant| variant.is_other())
+            .map(TargetVariant::declared_ident)
+    }
+
+    /// Whether to generate a [`Display`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string
+    /// representation.
+    ///
+    /// [`Display`]: ::std::fmt::Display
+    #[inline]
+    pub(crate) fn implement_display(&self) -> bool {
+        self.display
+    }
+
+    /// Returns the ordering strategy of the [`PartialOrd`] and [`Ord`] trait
+    /// implementations to be generated for the `enum` type the macro is being
+    /// derived on, if any.
+    #[inline]
+    pub(crate) fn ord_strategy(&self) -> Option<OrdStrategy> {
+        self.ord
+    }
+
+    /// Whether to generate the `{Enum}OrUnknown` companion type for the `enum`
+    /// type the macro is being derived on.
+    #[inline]
+    pub(crate) fn implement_or_unknown(&self) -> bool {
+        self.or_unknown
+    }
+
+    /// Whether to generate the `{Enum}Set` bitset type for the `enum` type the
+    /// macro is being derived on.
+    #[inline]
+    pub(crate) fn implement_set(&self) -> bool {
+        self.set
+    }
+
+    /// Whether to generate the `{Enum}Map<V>` type for the `enum` type the
+    /// macro is being derived on.
+    #[inline]
+    pub(crate) fn implement_map(&self) -> bool {
+        self.map
+    }
+
+    /// Whether to generate the conversions between the `enum` type the macro is
+    /// being derived on and its primitive integer representation.
+    #[inline]
+    pub(crate) fn implement_repr(&self) -> bool {
+        self.repr
+    }
+
+    /// Whether to generate the marker types and the `{Enum}Variant` trait for
+    /// the `enum` type the macro is being derived on.
+    #[inline]
+    pub(crate) fn implement_markers(&self) -> bool {
+        self.markers
+    }
+
+    /// Returns the identifier of the generated module containing the marker
+    /// types, i.e. the snake case identifier of the `enum` type the macro is
+    /// being derived on (e.g. `weekday`), made raw if it's a keyword.
+    ///
+    /// Path keywords (i.e. `crate`, `self` and `super`) cannot be raw
+    /// identifiers, so the `_markers` suffix is appended to them instead
+    /// (e.g. `crate_markers`).
+    pub(crate) fn markers_mod_ident(&self) -> Ident {
+        let name = self.ident.to_case_string(RenameCase::SnakeCase);
+
+        if Self::PATH_KEYWORDS.contains(&name.as_str()) {
+            return Ident::new(&format!("{name}_markers"), Span::call_site());
+        }
+
+        syn::parse_str::<Ident>(&name).unwrap_or_else(|_| Ident::new_raw(&name, Span::call_site()))
+    }
+
+    /// Returns the smallest unsigned integer type with at least as many bits
+    /// as the _iterable_ (i.e. non-skipped) variants of the `enum` type the
+    /// macro is being derived on, backing the generated `{Enum}Set` bitset
+    /// type, along with its number of bits.
+    ///
+    /// This method returns `None` if there are more than 128 _iterable_
+    /// variants.
+    pub(crate) fn set_bits_type(&self) -> Option<(Ident, usize)> {
+        let count = self.variants_count();
+
+        Self::SET_BITS_TYPES
+            .iter()
+            .find(|&&(_, bits)| bits >= count)
+            .map(|&(ty, bits)| (Ident::new(ty, Span::call_site()), bits))
+    }
+
+    /// Whether to generate a [`FromStr`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string or
+    /// abbreviated string representations.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[inline]
+    pub(crate) fn implement_from_str(&self) -> bool {
+        self.from_str.is_some()
+    }
+
+    /// Returns the parsing options of the [`FromStr`] trait implementation for
+    /// the `enum` type the macro is being derived on.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn from_str_options(&self) -> ParseOptions {
+        self.from_str
+            .as_ref()
+            .map(FromStrOptions::parse)
+            .unwrap_or_default()
+    }
+
+    /// Returns the identifier of the parsing error type generated along with
+    /// the [`FromStr`] trait implementation, if customized.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[inline]
+    pub(crate) fn parse_error_ident(&self) -> Option<&Ident> {
+        self.from_str.as_ref().and_then(FromStrOptions::error)
+    }
+
+    /// Returns the visibility of the parsing error type generated along with
+    /// the [`FromStr`] trait implementation, defaulting to `pub`.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    pub(crate) fn parse_error_vis(&self) -> Visibility {
+        match self.from_str.as_ref().and_then(FromStrOptions::error_vis) {
+            Some(vis) => vis.clone(),
+            None => syn::parse_quote!(pub),
+        }
+    }
+
+    /// Returns the additional traits to be derived on the parsing error type
+    /// generated along with the [`FromStr`] trait implementation.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[inline]
+    pub(crate) fn parse_error_derives(&self) -> &[Path] {
+        self.from_str
+            .as_ref()
+            .map_or(&[], FromStrOptions::error_derive)
+    }
+
+    /// Returns the user-supplied error type to be used as the associated error
+    /// type of the [`FromStr`] trait implementation, if any.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[inline]
+    pub(crate) fn parse_error_type(&self) -> Option<&Path> {
+        self.from_str.as_ref().and_then(FromStrOptions::error_type)
+    }
+
+    /// Returns the maximum number of characters of the rejected input to be
+    /// kept by the parsing error type generated along with the [`FromStr`]
+    /// trait implementation, if bounded.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[inline]
+    pub(crate) fn parse_error_input_limit(&self) -> Option<usize> {
+        self.from_str.as_ref().and_then(FromStrOptions::input_limit)
+    }
+
+    /// Whether to generate any parsing implementation (i.e. [`FromStr`] or
+    /// [`Deserialize`]) for the `enum` type the macro is being derived on.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    #[inline]
+    pub(crate) fn implement_parse(&self) -> bool {
+        #[cfg(feature = "serde")]
+        if self.implement_deserialize() {
+            return true;
+        }
+
+        self.implement_from_str()
+    }
+
+    /// Returns the forms of string representations accepted by the generated
+    /// parsing implementations for the `enum` type the macro is being derived
+    /// on, including the forms accepted by the `from_str_full` and
+    /// `from_str_abbr` methods generated along with the [`FromStr`] trait
+    /// implementation.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    pub(crate) fn parse_forms(&self) -> BTreeSet<ParseForms> {
+        let mut forms = BTreeSet::new();
+
+        if self.implement_from_str() {
+            forms.extend([
+                ParseForms::Full,
+                ParseForms::Abbr,
+                self.from_str_options().forms(),
+            ]);
+        }
+
+        #[cfg(feature = "serde")]
+        if self.implement_deserialize() {
+            forms.insert(self.deserialize_options().forms());
+        }
+
+        forms
+    }
+
+    /// Wether to generate a [`Deserialize`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string
+    /// representation.
+    ///
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub(crate) fn implement_deserialize(&self) -> bool {
+        self.deserialize.is_some()
+    }
+
+    /// Returns the parsing options of the [`Deserialize`] trait implementation
+    /// for the `enum` type the macro is being derived on.
+    ///
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub(crate) fn deserialize_options(&self) -> ParseOptions {
+        self.deserialize
+            .map(DeserializeOptions::parse)
+            .unwrap_or_default()
+    }
+
+    /// Whether the [`Deserialize`] trait implementation for the `enum` type
+    /// the macro is being derived on falls back to the default variant on
+    /// unknown values.
+    ///
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub(crate) fn deserialize_fallback(&self) -> bool {
+        self.deserialize.is_some_and(DeserializeOptions::fallback)
+    }
+
+    /// Wether to generate a [`Serialize`] trait implementation for the `enum`
+    /// type the macro is being derived on, based on the final string
+    /// representation.
+    ///
+    /// [`Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub(crate) fn implement_serialize(&self) -> bool {
+        self.serialize
+    }
+
+    /// Returns the abbreviation strategy to be applied to the given variant of
+    /// the `enum` type the macro is being derived on.
+    ///
+    /// This method resolves the [`AbbrStrategy::UniquePrefix`] strategy into
+    /// an [`AbbrStrategy::Length`] strategy, by computing the length (in
+    /// extended grapheme clusters) of the shortest prefix of the variant's
+    /// string representation that is not a prefix of any other variant's
+    /// string representation. Falls back to the whole string representation if
+    /// no such prefix exists.
+    pub(crate) fn abbr_strategy(&self, variant: &TargetVariant) -> AbbrStrategy {
+        let abbr = self.abbr.unwrap_or_default();
+
+        if abbr != AbbrStrategy::UniquePrefix {
+            return abbr;
+        }
+
+        let Some(base) = variant.abbr_base(self.rename, self.rename_abbr) else {
+            return abbr;
+        };
+
+        let others = self
+            .iter_variants()
+            .filter(|other| !std::ptr::eq(*other, variant))
+            .filter_map(|other| other.abbr_base(self.rename, self.rename_abbr))
+            .collect::<Vec<_>>();
+
+        let mut prefixes = base
+            .grapheme_indices(true)
+            .map(|(offset, grapheme)| &base[..offset + grapheme.len()]);
+
+        let len = prefixes
+            .position(|prefix| others.iter().all(|other| !other.starts_with(prefix)))
+            .map_or_else(|| base.graphemes(true).count(), |index| index + 1);
+
+        AbbrStrategy::Length(len)
+    }
+
+    /// Returns an iterator over each and every variant of the `enum` type the
+    /// macro is being derived on.
+    #[inline]
+    pub(crate) fn iter_variants(&self) -> impl Iterator<Item = &TargetVariant> {
+        self.variants().iter()
+    }
+
+    /// Returns an iterator over _iterable_ (i.e. non-skipped) variants of the
+    /// `enum` type the macro is being derived on.
+    #[inline]
+    #[rustfmt::skip]
+    pub(crate) fn iter_iterable_variants(&self) -> impl Iterator<Item = &TargetVariant> {
+        self.iter_variants().filter(|variant| variant.is_iterable())
+    }
+
+    /// Returns an iterator over _listed_ (i.e. not marked as `skip`,
+    /// `skip(list)` or `other`) variants of the `enum` type the macro is being
+    /// derived on.
+    #[inline]
+    #[rustfmt::skip]
+    pub(crate) fn iter_listed_variants(&self) -> impl Iterator<Item = &TargetVariant> {
+        self.iter_variants().filter(|variant| variant.is_listed())
+    }
+
+    /// Returns an iterator over _listed_ (i.e. not marked as `skip`,
+    /// `skip(list)` or `other`) and _parseable_ (i.e. not marked as `skip(parse)`)
+    /// variants of the `enum` type the macro is being derived on, to be listed
+    /// in parsing error messages.
+    #[inline]
+    #[rustfmt::skip]
+    fn iter_listed_parseable_variants(&self) -> impl Iterator<Item = &TargetVariant> {
+        self.iter_listed_variants().filter(|variant| variant.is_parseable())
+    }
+
+    /// Whether any variant of the `enum` type the macro is being derived on is
+    /// excluded from iteration, in which case it has no index and the generated
+    /// `index` and navigation methods return `Option`s.
+    pub(crate) fn has_non_iterable_variants(&self) -> bool {
+        self.iter_variants().any(|variant| !variant.is_iterable())
+    }
+
+    /// Returns the count of _iterable_ (i.e. non-skipped) variants of the
+    /// `enum` type the macro is being derived on.
+    pub(crate) fn variants_count(&self) -> usize {
+        self.iter_iterable_variants().count()
+    }
+
+    /// Returns an iterator over identifiers of _iterable_ (i.e. non-skipped)
+    /// variants of the `enum` type the macro is being derived on.
+    #[rustfmt::skip]
+    pub(crate) fn iter_variant_idents(&self) -> impl Iterator<Item = &Ident> {
+        self.iter_iterable_variants().filter_map(TargetVariant::ident)
+    }
+
+    /// Returns the identifiers of the first and last _iterable_ (i.e.
+    /// non-skipped) variants of the `enum` type the macro is being derived on,
+    /// if any.
+    pub(crate) fn first_last_variant_idents(&self) -> Option<(&Ident, &Ident)> {
+        let first = self.iter_variant_idents().next()?;
+        let last = self.iter_variant_idents().last()?;
+
+        Some((first, last))
+    }
+
+    /// Returns each and every variant of the `enum` type the macro is being
+    /// derived on, in the order established by the given ordering `strategy`.
+    ///
+    /// Variants are sorted stably, hence variants with equal string
+    /// representations keep their declaration order.
+    fn ordered_variants(&self, strategy: OrdStrategy) -> Vec<&TargetVariant> {
+        let mut variants = self.iter_variants().collect::<Vec<_>>();
+
+        match strategy {
+            OrdStrategy::Declaration => {}
+            OrdStrategy::Name => variants.sort_by_cached_key(|variant| variant.as_str(self.rename)),
+            OrdStrategy::Rank => variants.sort_by_key(|variant| variant.rank()),
+        }
+
+        variants
+    }
+
+    /// Returns an iterator over "_match branches_", associating each variant
+    /// of the `enum` type the macro is being derived on to its position in the
+    /// order established by the given ordering `strategy`, to be used in the
+    /// generation of the [`Ord`] trait implementation.
+    pub(crate) fn iter_variant_ord_key_match_branches(
+        &self,
+        strategy: OrdStrategy,
+    ) -> impl Iterator<Item = TokenStream> {
+        self.ordered_variants(strategy)
+            .into_iter()
+            .enumerate()
+            .map(|(position, variant)| variant.ord_key_match_branch(position))
+    }
+
+    /// Returns the prefix of the generated predicate methods, defaulting to
+    /// `is_`.
+    pub(crate) fn predicate_prefix(&self) -> String {
+        self.predicate_prefix
+            .as_ref()
+            .map_or_else(|| String::from("is_"), LitStr::value)
+    }
+
+    /// Returns an iterator over each and every variant of the `enum` type the
+    /// macro is being derived on, along with the identifier of its generated
+    /// predicate method.
+    pub(crate) fn iter_variant_predicates(&self) -> impl Iterator<Item = (Ident, &TargetVariant)> {
+        let prefix = self.predicate_prefix();
+
+        self.iter_variants()
+            .map(move |variant| (variant.predicate_ident(&prefix), variant))
+    }
+
+    /// Returns the identifier of the generated predicate method of the variant
+    /// group with the given `name`, prefixed like the variant predicates.
+    pub(crate) fn group_predicate_ident(&self, name: &str) -> Ident {
+        let prefix = self.predicate_prefix();
+
+        Ident::new(&format!("{prefix}{name}"), Span::call_site())
+    }
+
+    /// Returns an iterator over "_match patterns_", matching a pair of values
+    /// both being the same variant, for each and every variant of the `enum`
+    /// type the macro is being derived on, to be used in the generation of the
+    /// `const_eq` method.
+    pub(crate) fn iter_variant_eq_match_patterns(&self) -> impl Iterator<Item = TokenStream> {
+        self.iter_variants().map(TargetVariant::eq_match_pattern)
+    }
+
+    /// Returns the names of the variant groups of the `enum` type the macro is
+    /// being derived on, in order of first appearance.
+    pub(crate) fn groups(&self) -> Vec<String> {
+        self.iter_variants()
+            .flat_map(TargetVariant::iter_groups)
+            .unique()
+            .collect()
+    }
+
+    /// Returns the identifiers of each and every variant of the `enum` type the
+    /// macro is being derived on belonging to the group with the given `name`.
+    pub(crate) fn group_variant_idents(&self, name: &str) -> Vec<&Ident> {
+        self.iter_variants()
+            .filter(|variant| variant.is_in_group(name))
+            .map(TargetVariant::declared_ident)
+            .collect()
+    }
+
+    /// Returns the identifiers of _iterable_ (i.e. non-skipped) variants of the
+    /// `enum` type the macro is being derived on belonging to the group with
+    /// the given `name`.
+    pub(crate) fn group_iterable_variant_idents(&self, name: &str) -> Vec<&Ident> {
+        self.iter_iterable_variants()
+            .filter(|variant| variant.is_in_group(name))
+            .filter_map(TargetVariant::ident)
+            .collect()
+    }
+
+    /// Returns the identifiers of _iterable_ (i.e. non-skipped) variants of the
+    /// `enum` type the macro is being derived on, in the order established by
+    /// the given ordering `strategy`.
+    pub(crate) fn sorted_variant_idents(&self, strategy: OrdStrategy) -> Vec<&Ident> {
+        self.ordered_variants(strategy)
+            .into_iter()
+            .filter_map(TargetVariant::ident)
+            .collect()
+    }
+
+    /// Returns an iterator over "_match branches_", associating the variant of
+    /// the `enum` type the macro is being derived on to its index among
+    /// _iterable_ (i.e. non-skipped) variants, to be used in the generation of
+    /// the `index` method.
+    ///
+    /// The indexes are wrapped in `Option`s if any variant is excluded from
+    /// iteration (see [`TargetEnum::has_non_iterable_variants`]).
+    pub(crate) fn iter_variant_index_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        let optional = self.has_non_iterable_variants();
+        let mut next_index = 0;
+
+        self.iter_variants().map(move |variant| {
+            let index = variant.is_iterable().then(|| {
+                next_index += 1;
+                next_index - 1
+            });
+
+            variant.index_match_branch(index, optional)
+        })
+    }
+
+    /// Returns an iterator over "_match branches_", associating the variant of
+    /// the `enum` type the macro is being derived on to its bit within the
+    /// generated `{Enum}Set` bitset type, i.e. the bit at its index among
+    /// _iterable_ (i.e. non-skipped) variants, to be used in the generation of
+    /// the `set_bit` method.
+    pub(crate) fn iter_variant_set_bit_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        let mut next_index = 0;
+
+        self.iter_variants().map(move |variant| {
+            let index = variant.is_iterable().then(|| {
+                next_index += 1;
+                next_index - 1
+            });
+
+            variant.set_bit_match_branch(index)
+        })
+    }
+
+    /// Returns an iterator over "_match branches_", associating each and every
+    /// variant of the `enum` type the macro is being derived on to its
+    /// serialization, to be used in the generation of the [`Serialize`] trait
+    /// implementation.
+    ///
+    /// This method returns `None` if every variant is _serializable_, in which
+    /// case no "_match branches_" are needed.
+    ///
+    /// [`Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
+    #[cfg(feature = "serde")]
+    pub(crate) fn variants_serialize_match_branches(&self) -> Option<Vec<TokenStream>> {
+        if self.iter_variants().all(TargetVariant::is_serializable) {
+            return None;
+        }
+
+        let enum_name = self.human_readable_name();
+
+        Some(
+            self.iter_variants()
+                .map(|variant| variant.serialize_match_branch(&enum_name))
+                .collect(),
+        )
+    }
+
+    /// Returns an iterator over "_match branches_", associating each and every
+    /// variant of the `enum` type the macro is being derived on to its
+    /// primitive representation, to be used in the generation of the
+    /// `to_repr` method of non-[`Copy`] `enum` types.
+    #[rustfmt::skip]
+    pub(crate) fn iter_variant_to_repr_match_branches<'a>(&'a self, repr: &'a Ident) -> impl Iterator<Item = TokenStream> + 'a {
+        self.iter_variants().map(move |variant| variant.to_repr_match_branch(repr))
+    }
+
+    /// Returns an iterator over "_match branches_", associating each and every
+    /// variant of the `enum` type the macro is being derived on to its
+    /// identifier as a string, to be used in the generation of the [`Debug`]
+    /// trait implementation.
+    ///
+    /// [`Debug`]: ::std::fmt::Debug
+    pub(crate) fn iter_variant_debug_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        self.iter_variants().map(TargetVariant::debug_match_branch)
+    }
+
+    /// Returns an iterator over "_match branches_", associating the primitive
+    /// representation of each and every variant of the `enum` type the macro
+    /// is being derived on to the variant, to be used in the generation of the
+    /// `from_repr` method.
+    #[rustfmt::skip]
+    pub(crate) fn iter_variant_from_repr_match_branches<'a>(&'a self, repr: &'a Ident) -> impl Iterator<Item = TokenStream> + 'a {
+        self.iter_variants().map(move |variant| variant.from_repr_match_branch(repr))
+    }
+
+    /// Returns an iterator over "_match branches_", associating the index among
+    /// _iterable_ (i.e. non-skipped) variants to the variant of the `enum` type
+    /// the macro is being derived on, to be used in the generation of the
+    /// `from_index` method.
+    #[rustfmt::skip]
+    pub(crate) fn iter_variant_from_index_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        self.iter_iterable_variants().enumerate().map(|(index, variant)| variant.from_index_match_branch(index))
+    }
+
+    /// Returns an iterator over "_match branches_", associating the variant of the
+    /// `enum` type the macro is being derived on to its final string
+    /// representation, to be used in the generation of the `as_str` method.
+    #[rustfmt::skip]
+    pub(crate) fn iter_variant_as_str_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        self.iter_variants().map(|variant| variant.as_str_match_branch(self.rename))
+    }
+
+    /// Returns an iterator over "_match branches_", associating the variant of the
+    /// `enum` type the macro is being derived on to its final abbreviated string
+    /// representation, to be used in the generation of the `as_str_abbr` method.
+    #[rustfmt::skip]
+    pub(crate) fn iter_variant_as_str_abbr_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        self.iter_variants().map(|variant| variant.as_str_abbr_match_branch(self.rename, self.rename_abbr, self.abbr_strategy(variant)))
+    }
+
+    /// Returns a list of quoted (double-quotes) and comma separated string
+    /// representations of _listed_ (i.e. not marked as `skip`, `skip(list)` or
+    /// `other`) variants of the `enum` type the macro is being derived on.
+    #[inline]
+    pub(crate) fn variants_list_string(&self) -> String {
+        self.list_string(self.iter_listed_variants())
+    }
+
+    /// Returns a list of quoted (double-quotes) and comma separated abbreviated
+    /// string representations of _listed_ (i.e. not marked as `skip`,
+    /// `skip(list)` or `other`) variants of the `enum` type the macro is being
+    /// derived on.
+    #[inline]
+    pub(crate) fn variants_list_string_abbr(&self) -> String {
+        self.list_string_abbr(self.iter_listed_variants())
+    }
+
+    /// Returns a list of quoted (double-quotes) and comma separated string
+    /// representations of the given variants of the `enum` type the macro is
+    /// being derived on.
+    fn list_string<'a>(&'a self, variants: impl Iterator<Item = &'a TargetVariant>) -> String {
+        Itertools::intersperse(
+            variants
+                .map(|variant| variant.as_quoted_string(self.rename))
+                .map(Cow::Owned),
+            Cow::Borrowed(", "),
+        )
+        .collect()
+    }
+
+    /// Returns a list of quoted (double-quotes) and comma separated abbreviated
+    /// string representations of the given variants of the `enum` type the
+    /// macro is being derived on.
+    fn list_string_abbr<'a>(&'a self, variants: impl Iterator<Item = &'a TargetVariant>) -> String {
+        Itertools::intersperse(
+            variants
+                .map(|variant| {
+                    variant.as_quoted_string_abbr(
+                        self.rename,
+                        self.rename_abbr,
+                        self.abbr_strategy(variant),
+                    )
+                })
+                .map(Cow::Owned),
+            Cow::Borrowed(", "),
+        )
+        .collect()
+    }
+
+    /// Returns a list of quoted (double-quotes) and comma separated aliases of
+    /// _listed_ and _parseable_ variants of the `enum` type the macro is being
+    /// derived on, either deprecated or not.
+    fn aliases_list_string(&self, deprecated: bool) -> String {
+        Itertools::intersperse(
+            self.iter_listed_parseable_variants()
+                .flat_map(TargetVariant::aliases)
+                .filter(|alias| alias.is_deprecated() == deprecated)
+                .map(|alias| Cow::Owned(format!("\"{}\"", alias.value()))),
+            Cow::Borrowed(", "),
+        )
+        .collect()
+    }
+
+    /// Returns a note listing the aliases and the deprecated aliases of
+    /// _listed_ and _parseable_ variants of the `enum` type the macro is being
+    /// derived on, to be appended to parsing error messages.
+    ///
+    /// This method returns `None` if no alias has been specified for any
+    /// _listed_ and _parseable_ variant.
+    fn aliases_note(&self) -> Option<String> {
+        let aliases = self.aliases_list_string(false);
+        let deprecated_aliases = self.aliases_list_string(true);
+
+        match (aliases.is_empty(), deprecated_aliases.is_empty()) {
+            (true, true) => None,
+            (false, true) => Some(format!(" (aliases: {aliases})")),
+            (true, false) => Some(format!(" (deprecated aliases: {deprecated_aliases})")),
+            (false, false) => Some(format!(
+                " (aliases: {aliases}; deprecated aliases: {deprecated_aliases})"
+            )),
+        }
+    }
+
+    /// Returns the list of string representations accepted when parsing the
+    /// given `forms` of _listed_ and _parseable_ variants of the `enum` type
+    /// the macro is being derived on, to be used in parsing error messages
+    /// (e.g. `one of "Monday", "Tuesday" or one of "Mon", "Tue"`).
+    pub(crate) fn expected_list_string(&self, forms: ParseForms) -> String {
+        let aliases_note = self.aliases_note().unwrap_or_default();
+        let list = self.list_string(self.iter_listed_parseable_variants());
+        let list_abbr = self.list_string_abbr(self.iter_listed_parseable_variants());
+
+        match forms {
+            ParseForms::Full => format!("one of {list}{aliases_note}"),
+            ParseForms::Abbr => format!("one of {list_abbr}{aliases_note}"),
+            ParseForms::Both => format!("one of {list} or one of {list_abbr}{aliases_note}"),
+        }
+    }
+
+    /// Returns an iterator over the final string and abbreviated string
+    /// representations accepted when parsing the given `forms`, and the
+    /// aliases of the given variant of the `enum` type the macro is being
+    /// derived on, paired with whether they're deprecated.
+    fn variant_parse_names<'a>(
+        &'a self,
+        variant: &'a TargetVariant,
+        forms: ParseForms,
+    ) -> impl Iterator<Item = (Cow<'a, str>, bool)> {
+        let name = forms.accepts_full().then(|| variant.as_str(self.rename));
+        let name_abbr = forms.accepts_abbr().then(|| {
+            variant.as_str_abbr(self.rename, self.rename_abbr, self.abbr_strategy(variant))
+        });
+
+        [name, name_abbr]
+            .into_iter()
+            .flatten()
+            .map(|name| (name, false))
+            .chain(
+                variant
+                    .aliases()
+                    .iter()
+                    .map(|alias| (Cow::Borrowed(alias.value()), alias.is_deprecated())),
+            )
+    }
+
+    /// Returns an iterator over the variants of the `enum` type the macro is
+    /// being derived on, paired with the final string and abbreviated string
+    /// representations accepted when parsing the given `forms` and the
+    /// aliases to be matched against when parsing.
+    ///
+    /// Variants marked as `skip(parse)` are excluded altogether. Each string
+    /// representation is yielded at most once, associated to the first
+    /// declared variant it belongs to, so that the generated "_match
+    /// branches_" never contain unreachable patterns (e.g. when the
+    /// abbreviated string representation of a variant equals its full length
+    /// string representation, or when collisions are allowed). String
+    /// representations are compared regardless of their ASCII case if the
+    /// `options` are case insensitive.
+    #[rustfmt::skip]
+    fn iter_variants_parse_names(&self, options: ParseOptions, forms: ParseForms) -> impl Iterator<Item = (&TargetVariant, Vec<String>)> {
+        let mut claimed = HashSet::new();
+
+        self.iter_variants().filter(|variant| variant.is_parseable()).map(move |variant| {
+            let names = self
+                .variant_parse_names(variant, forms)
+                .map(|(name, _)| name.into_owned())
+                .filter(|name| match options.case_insensitive() {
+                    true => claimed.insert(name.to_ascii_lowercase()),
+                    false => claimed.insert(name.clone()),
+                })
+                .collect();
+
+            (variant, names)
+        })
+    }
+
+    /// Returns an iterator over "_parse candidates_", associating the final
+    /// string and abbreviated string representations accepted when parsing the
+    /// given `forms` and the non-deprecated aliases to the respective variant
+    /// of the `enum` type the macro is being derived on, to be used in the
+    /// generation of parsing suggestions and of the `from_str_fuzzy` method.
+    ///
+    /// Each string representation is yielded at most once (regardless of its
+    /// ASCII case), associated to the first declared variant it belongs to.
+    /// Variants marked as `skip`, `skip(list)` or `skip(parse)` are never
+    /// suggested.
+    pub(crate) fn iter_parse_candidates(
+        &self,
+        forms: ParseForms,
+    ) -> impl Iterator<Item = TokenStream> {
+        let mut claimed = HashSet::new();
+
+        self.iter_listed_parseable_variants()
+            .flat_map(move |variant| {
+                self.variant_parse_names(variant, forms)
+                    .filter(|(_, deprecated)| !deprecated)
+                    .filter(|(name, _)| claimed.insert(name.to_ascii_lowercase()))
+                    .map(|(name, _)| variant.parse_candidate(&name))
+                    .collect::<Vec<_>>()
+            })
+    }
+
+    /// Returns an iterator over "_match branches_", associating the final
+    /// string and abbreviated string representations accepted when parsing
+    /// the given `forms` to the respective variant of the `enum` type the
+    /// macro is being derived on, to be used on the generation of the
+    /// [`FromStr`] trait implementation and of the `from_str_full` and
+    /// `from_str_abbr` methods.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[rustfmt::skip]
+    pub(crate) fn variants_from_str_match_branches(&self, forms: ParseForms) -> impl Iterator<Item = TokenStream> {
+        let options = self.from_str_options();
+        self.iter_variants_parse_names(options, forms).filter_map(move |(variant, names)| variant.from_str_match_branch(&names, options))
+    }
+
+    /// Returns an iterator over "_match branches_", associating the string
+    /// representations accepted with the given parsing `options` to the
+    /// respective known variants of the `{Enum}OrUnknown` companion type, to be
+    /// used in the generation of its [`FromStr`] (with the [`FromStr`] options)
+    /// and `Deserialize` (with the `Deserialize` options) trait
+    /// implementations, so that they agree with the ones of the `enum` type.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[rustfmt::skip]
+    pub(crate) fn variants_or_unknown_match_branches(&self, options: ParseOptions) -> impl Iterator<Item = TokenStream> {
+        self.iter_variants_parse_names(options, options.forms()).filter_map(move |(variant, names)| variant.or_unknown_match_branch(&names, options, &self.ident))
+    }
+
+    /// Returns an iterator over "_match branches_", associating the final
+    /// string and abbreviated string representations to the respective
+    /// variants of the `enum` type the macro is being derived on, to be
+    /// used on the generation of the `Deserialize` trait implementation.
+    ///
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    #[cfg(feature = "serde")]
+    #[rustfmt::skip]
+    pub(crate) fn variants_deserialize_match_branches(&self) -> impl Iterator<Item = TokenStream> {
+        let options = self.deserialize_options();
+        self.iter_variants_parse_names(options, options.forms()).filter_map(move |(variant, names)| variant.deserialize_match_branch(&names, options))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod r#enum;
+pub mod variant;
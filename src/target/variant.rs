@@ -0,0 +1,940 @@
+use std::borrow::Cow;
+
+use darling::ast::NestedMeta;
+use darling::{FromMeta, FromVariant};
+use proc_macro2::{Span, TokenStream};
+use syn::{Ident, LitStr, Variant};
+
+use crate::abbr::AbbrStrategy;
+use crate::alias::Alias;
+use crate::ident::IdentExt;
+use crate::parse::ParseOptions;
+use crate::rename::case::RenameCase;
+use crate::rename::inner::InnerRenameStrategy;
+use crate::rename::outer::OuterRenameStrategy;
+use crate::skip::Skip;
+use crate::string::StringExt;
+
+/// The type representing a [`TargetEnum`] variant.
+///
+/// This type is constructed while [`TargetEnum`] variants are being parsed,
+/// and it's populated with information about the variant identifier and its
+/// inner attributes.
+#[derive(Debug, Clone)]
+pub(crate) struct TargetVariant {
+    /// The identifier of the [`TargetEnum`] variant.
+    ident: Ident,
+    /// The options of the variant, parsed out of its `#[variants(...)]` inner
+    /// attributes.
+    options: VariantOptions,
+}
+
+/// The options of a [`TargetVariant`].
+///
+/// This type is populated by merging the items of all the `#[variants(...)]`
+/// inner attributes of the variant.
+#[derive(Debug, Clone, Default, FromMeta)]
+struct VariantOptions {
+    /// The rename strategy for the variant's string representation.
+    ///
+    /// This field is populated by the `#[variants(rename(...))]` inner
+    /// attribute of the variant.
+    #[darling(default)]
+    rename: Option<InnerRenameStrategy>,
+    /// The rename strategy for the variant's abbreviated string representation.
+    ///
+    /// This field is populated by the `#[variants(rename_abbr(...))]` inner
+    /// attribute of the variant.
+    #[darling(default)]
+    rename_abbr: Option<InnerRenameStrategy>,
+    /// The aliases of the variant, i.e. additional string representations
+    /// accepted when parsing the variant.
+    ///
+    /// This field is populated by the (repeatable) `#[variants(alias = "...")]`
+    /// and `#[variants(alias("...", deprecated))]` inner attributes of the
+    /// variant.
+    #[darling(multiple)]
+    alias: Vec<Alias>,
+    /// The skip modes of the variant.
+    ///
+    /// This field is populated by the `#[variants(skip)]` and
+    /// `#[variants(skip(...))]` inner attributes of the variant.
+    #[darling(default)]
+    skip: Skip,
+    /// Whether the variant is the default variant of the [`TargetEnum`].
+    ///
+    /// This field is populated by the `#[variants(default)]` inner attribute
+    /// of the variant.
+    #[darling(default)]
+    default: bool,
+    /// Whether the variant is the catch-all variant of the [`TargetEnum`],
+    /// i.e. the variant unknown values are parsed as.
+    ///
+    /// This field is populated by the `#[variants(other)]` inner attribute of
+    /// the variant.
+    #[darling(default)]
+    other: bool,
+    /// The rank of the variant, determining its order when the [`TargetEnum`]
+    /// is ordered by rank.
+    ///
+    /// This field is populated by the `#[variants(rank = N)]` inner attribute
+    /// of the variant.
+    #[darling(default)]
+    rank: Option<i64>,
+    /// The groups the variant belongs to.
+    ///
+    /// This field is populated by the (repeatable) `#[variants(group = "...")]`
+    /// inner attribute of the variant.
+    #[darling(multiple)]
+    group: Vec<LitStr>,
+}
+
+impl FromVariant for TargetVariant {
+    fn from_variant(variant: &Variant) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+        let mut items = Vec::new();
+
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("variants"))
+        {
+            let parsed = darling::util::parse_attribute_to_meta_list(attr)
+                .and_then(|list| NestedMeta::parse_meta_list(list.tokens).map_err(Into::into));
+
+            if let Some(nested) = errors.handle(parsed) {
+                items.extend(nested);
+            }
+        }
+
+        let options = errors
+            .handle(VariantOptions::from_list(&items))
+            .unwrap_or_default();
+
+        errors.finish_with(Self {
+            ident: variant.ident.clone(),
+            options,
+        })
+    }
+}
+
+impl TargetVariant {
+    /// Checks whether the variant is _iterable_, i.e. non-skipped.
+    ///
+    /// This method returns `true` if the variant is _iterable_,
+    /// `false` if the variant has been marked as `skip` or `skip(iter)`.
+    #[inline]
+    pub(crate) fn is_iterable(&self) -> bool {
+        !self.options.skip.skips_iter()
+    }
+
+    /// Checks whether the variant is _listed_, i.e. included in the generated
+    /// listings, parsing error messages and parsing suggestions.
+    ///
+    /// This method returns `true` if the variant is _listed_, `false` if the
+    /// variant has been marked as `skip`, `skip(list)` or `other`, the latter
+    /// not being a parseable value.
+    #[inline]
+    pub(crate) fn is_listed(&self) -> bool {
+        !self.options.skip.skips_list() && !self.options.other
+    }
+
+    /// Checks whether the variant is _parseable_, i.e. whether its string
+    /// representations are accepted when parsing.
+    ///
+    /// This method returns `true` if the variant is _parseable_, `false` if
+    /// the variant has been marked as `skip(parse)` or `other`, the latter
+    /// being parsed out of unknown values only.
+    #[inline]
+    pub(crate) fn is_parseable(&self) -> bool {
+        !self.options.skip.skips_parse() && !self.options.other
+    }
+
+    /// Checks whether the variant is _serializable_.
+    ///
+    /// This method returns `true` if the variant is _serializable_, `false` if
+    /// the variant has been marked as `skip(serialize)`.
+    #[inline]
+    #[cfg(feature = "serde")]
+    pub(crate) fn is_serializable(&self) -> bool {
+        !self.options.skip.skips_serialize()
+    }
+
+    /// Checks whether the variant is the default variant, i.e. whether it's
+    /// been marked as `default`.
+    #[inline]
+    pub(crate) fn is_default(&self) -> bool {
+        self.options.default
+    }
+
+    /// Checks whether the variant is the catch-all variant, i.e. whether it's
+    /// been marked as `other`.
+    #[inline]
+    pub(crate) fn is_other(&self) -> bool {
+        self.options.other
+    }
+
+    /// Returns the variant identifier, if it's not been marked as `skip`.
+    ///
+    /// This method provides conditional access to the identifier of the
+    /// variant: returns `Some` if the variant should not be skipped,
+    /// `None` otherwise.
+    #[inline]
+    pub(crate) fn ident(&self) -> Option<&Ident> {
+        self.is_iterable().then_some(&self.ident)
+    }
+
+    /// Returns the variant identifier, regardless of whether it's been marked
+    /// as `skip`.
+    #[inline]
+    pub(crate) fn declared_ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the aliases of the variant, i.e. additional string
+    /// representations accepted when parsing the variant.
+    #[inline]
+    pub(crate) fn aliases(&self) -> &[Alias] {
+        &self.options.alias
+    }
+
+    /// Returns an iterator over the names of the groups the variant belongs to.
+    #[inline]
+    pub(crate) fn iter_groups(&self) -> impl Iterator<Item = String> {
+        self.options.group.iter().map(LitStr::value)
+    }
+
+    /// Checks whether the variant belongs to the group with the given name.
+    #[inline]
+    pub(crate) fn is_in_group(&self, name: &str) -> bool {
+        self.iter_groups().any(|group| group == name)
+    }
+
+    /// Returns the rank of the variant, if any.
+    #[inline]
+    pub(crate) fn rank(&self) -> Option<i64> {
+        self.options.rank
+    }
+
+    /// Returns the identifier of the generated predicate method checking
+    /// whether a value is the variant, i.e. the given `prefix` followed by the
+    /// snake case variant identifier (e.g. `is_monday`).
+    pub(crate) fn predicate_ident(&self, prefix: &str) -> Ident {
+        let name = self.ident.to_case_string(RenameCase::SnakeCase);
+
+        Ident::new(&format!("{prefix}{name}"), Span::call_site())
+    }
+}
+
+/// Enum variant's ordinal implementation.
+impl TargetVariant {
+    /// Returns a "_match branch_", associating the variant to its index among
+    /// _iterable_ (i.e. non-skipped) variants, to be used in the generation of
+    /// the `index` method.
+    ///
+    /// If `optional`, the index is wrapped in an `Option`, and skipped variants
+    /// (i.e. `index` is `None`) are associated to `None`, as they have no
+    /// index.
+    pub(crate) fn index_match_branch(&self, index: Option<usize>, optional: bool) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        match (index, optional) {
+            (Some(index), false) => quote::quote! { Self::#ident => #index },
+            (Some(index), true) => {
+                quote::quote! { Self::#ident => ::std::option::Option::Some(#index) }
+            }
+            (None, _) => quote::quote! { Self::#ident => ::std::option::Option::None },
+        }
+    }
+
+    /// Returns a "_match branch_", associating the variant to its bit within
+    /// the generated `{Enum}Set` bitset type, to be used in the generation of
+    /// the `set_bit` method.
+    ///
+    /// Skipped variants (i.e. `index` is `None`) have no bit, hence they're
+    /// associated to an empty bitmask.
+    pub(crate) fn set_bit_match_branch(&self, index: Option<usize>) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        if let Some(index) = index {
+            quote::quote! { Self::#ident => 1 << #index }
+        } else {
+            quote::quote! { Self::#ident => 0 }
+        }
+    }
+
+    /// Returns a "_match branch_", associating the variant to its position in
+    /// the order established by the ordering strategy, to be used in the
+    /// generation of the [`Ord`] trait implementation.
+    pub(crate) fn ord_key_match_branch(&self, position: usize) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        quote::quote! { Self::#ident => #position }
+    }
+
+    /// Returns a "_match branch_", associating the variant to its serialization
+    /// (i.e. its final string representation), or to a serialization error if
+    /// the variant is not _serializable_, to be used in the generation of the
+    /// `Serialize` trait implementation.
+    ///
+    /// [`Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
+    #[cfg(feature = "serde")]
+    pub(crate) fn serialize_match_branch(&self, enum_name: &str) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        if self.is_serializable() {
+            quote::quote! { Self::#ident => serializer.serialize_str(self.as_str()) }
+        } else {
+            quote::quote! {
+                Self::#ident => ::std::result::Result::Err(::serde::ser::Error::custom(::std::format_args!(
+                    "the {} `{}` cannot be serialized",
+                    #enum_name,
+                    self.as_str(),
+                )))
+            }
+        }
+    }
+
+    /// Returns a "_match branch_", associating the variant to its primitive
+    /// representation (i.e. its discriminant, cast to the `repr` type), to be
+    /// used in the generation of the `to_repr` method of non-[`Copy`] `enum`
+    /// types, which can't be cast by reference.
+    pub(crate) fn to_repr_match_branch(&self, repr: &Ident) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        quote::quote! { Self::#ident => Self::#ident as #repr }
+    }
+
+    /// Returns a "_match pattern_", matching a pair of values both being the
+    /// variant, to be used in the generation of the `const_eq` method.
+    pub(crate) fn eq_match_pattern(&self) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        quote::quote! { (Self::#ident, Self::#ident) }
+    }
+
+    /// Returns a "_match branch_", associating the variant to its identifier
+    /// as a string, to be used in the generation of the [`Debug`] trait
+    /// implementation.
+    ///
+    /// [`Debug`]: ::std::fmt::Debug
+    pub(crate) fn debug_match_branch(&self) -> TokenStream {
+        let Self { ident, .. } = self;
+        let name = ident.to_unraw_string();
+
+        quote::quote! { Self::#ident => #name }
+    }
+
+    /// Returns a "_match branch_", associating the primitive representation of
+    /// the variant (i.e. its discriminant, cast to the `repr` type) to the
+    /// variant, to be used in the generation of the `from_repr` method.
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn from_repr_match_branch(&self, repr: &Ident) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        quote::quote! {
+            value if value == Self::#ident as #repr => ::std::option::Option::Some(Self::#ident)
+        }
+    }
+
+    /// Returns a "_match branch_", associating the index of the variant among
+    /// _iterable_ (i.e. non-skipped) variants to the variant, to be used in
+    /// the generation of the `from_index` method.
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn from_index_match_branch(&self, index: usize) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        quote::quote! { #index => ::std::option::Option::Some(Self::#ident) }
+    }
+}
+
+/// Enum variant's string representation implementation.
+impl TargetVariant {
+    /// Returns a string representation based on the `#[variants(rename(...))]`
+    /// inner attribute strategy, if one has been specified for the variant.
+    ///
+    /// This method provides conditional access to the custom string
+    /// representation of the variant: returns `Some` if the inner attribute has
+    /// been specified for the variant, `None` otherwise.
+    fn inner_rename(&self) -> Option<Cow<'_, str>> {
+        self.options.rename.as_ref().map(|rename| match rename {
+            InnerRenameStrategy::Literal(literal) => Cow::Borrowed(literal.as_str()),
+            InnerRenameStrategy::Case(case) => Cow::Owned(self.ident.to_case_string(*case)),
+        })
+    }
+
+    /// Returns a string representation based on the `#[variants(rename(...))]`
+    /// outer attribute strategy (`outer_rename`), if one has been specified for
+    /// the type, falling back to the variant ident's stringification otherwise.
+    fn outer_rename(&self, outer_rename: Option<OuterRenameStrategy>) -> String {
+        match outer_rename {
+            Some(rename) => self.ident.to_case_string(rename.case()),
+            None => self.ident.to_unraw_string(),
+        }
+    }
+
+    /// Returns the string the final string representation of the variant is
+    /// converted from, along with the [`RenameCase`] it is converted to, if any.
+    ///
+    /// This method follows the same priority-based fallback approach as
+    /// [`TargetVariant::as_str`], keeping the case conversion apart so that
+    /// abbreviations can take the word boundaries from the variant identifier.
+    fn as_str_source(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+    ) -> (Cow<'_, str>, Option<RenameCase>) {
+        match (&self.options.rename, outer_rename) {
+            (Some(InnerRenameStrategy::Literal(literal)), _) => {
+                (Cow::Borrowed(literal.as_str()), None)
+            }
+            (Some(InnerRenameStrategy::Case(case)), _) => {
+                (Cow::Owned(self.ident.to_unraw_string()), Some(*case))
+            }
+            (None, Some(rename)) => (
+                Cow::Owned(self.ident.to_unraw_string()),
+                Some(rename.case()),
+            ),
+            (None, None) => (Cow::Owned(self.ident.to_unraw_string()), None),
+        }
+    }
+
+    /// Returns the final string representation of the variant.
+    //
+    /// This method applies rename strategies following a priority-based
+    /// fallback approach:
+    ///
+    /// 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+    ///    produced by the rename strategy from the `#[variants(rename(...))]`
+    ///    inner attribute, if one has been specified for the variant;
+    /// 1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by
+    ///    the rename strategy from the `#[variants(rename(...))]` outer
+    ///    attribute, if one has been specified for the type;
+    /// 1. **No renaming** (_default_) - converts the variant identifier to a
+    ///    string if neither the inner nor the outer rename attribute has been
+    ///    specified.
+    pub(crate) fn as_str(&self, outer_rename: Option<OuterRenameStrategy>) -> Cow<'_, str> {
+        self.inner_rename().unwrap_or_else(|| {
+            let outer_rename = self.outer_rename(outer_rename);
+            Cow::Owned(outer_rename)
+        })
+    }
+
+    /// Retuns a "_match branch_", associating the variant to the final string
+    /// representation, to be used in the generation of the `as_str` method.
+    pub(crate) fn as_str_match_branch(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+    ) -> TokenStream {
+        let Self { ident, .. } = self;
+        let name = self.as_str(outer_rename);
+
+        quote::quote! { Self::#ident => #name }
+    }
+
+    /// Returns a quoted (double-quotes) version of the final string
+    /// representation of the variant.
+    ///
+    /// For further details about the final string representation (i.e. rename
+    /// strategies, etc.) see [`TargetVariant::as_str`].
+    pub(crate) fn as_quoted_string(&self, outer_rename: Option<OuterRenameStrategy>) -> String {
+        format!("\"{}\"", self.as_str(outer_rename))
+    }
+}
+
+/// Enum variant's abbreviated string representation implementation.
+impl TargetVariant {
+    /// Returns the full length string representation to be abbreviated when a
+    /// case convention is specified for the abbreviated string representation.
+    ///
+    /// The renaming follows a priority-based fallback approach to determine the
+    /// full length string representation before applying the abbreviation:
+    ///
+    /// 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+    ///    produced by the rename strategy from the `#[variants(rename(...))]`
+    ///    inner attribute, if one has been specified for the variant;
+    /// 1. **No renaming** (_fallback_) - converts the variant identifier to a
+    ///    string if the inner rename attribute hasn't been specified.
+    fn rename_abbr_case_base(&self) -> Cow<'_, str> {
+        self.inner_rename()
+            .unwrap_or_else(|| Cow::Owned(self.ident.to_unraw_string()))
+    }
+
+    /// Returns an abbreviated string representation by applying the given
+    /// [`AbbrStrategy`] and then the given [`RenameCase`] to the abbreviation.
+    ///
+    /// See [`TargetVariant::rename_abbr_case_base`] for further details about
+    /// the full length string representation being abbreviated.
+    fn rename_abbr_case(&self, case: RenameCase, abbr: AbbrStrategy) -> String {
+        let (base, base_case) = self.as_str_source(None);
+
+        base.into_owned()
+            .to_case_abbr_in_place(base_case, abbr)
+            .to_case_in_place(case)
+    }
+
+    /// Returns an abbreviated string representation based on the
+    /// `#[variants(rename_abbr(...))]` inner attribute strategy, if one has
+    /// been specified for the variant.
+    ///
+    /// This method provides conditional access to the custom abbreviated string
+    /// representation of the variant: returns `Some` if the inner attribute has
+    /// been specified for the variant, `None` otherwise.
+    ///
+    /// For the cases where the `#[variants(rename_abbr(...))]` inner attribute
+    /// strategy is [`InnerRenameStrategy::Case`], renaming follows a
+    /// priority-based fallback approach to determine the full length string
+    /// representation before applying the abbreviation:
+    ///
+    /// 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+    ///    produced by the rename strategy from the `#[variants(rename(...))]`
+    ///    inner attribute, if one has been specified for the type;
+    /// 1. **No renaming** (_fallback_) - converts the variant identifier to a
+    ///    string if the inner rename attribute hasn't been specified.
+    fn inner_rename_abbr(&self, abbr: AbbrStrategy) -> Option<Cow<'_, str>> {
+        self.options
+            .rename_abbr
+            .as_ref()
+            .map(|rename_abbr| match rename_abbr {
+                InnerRenameStrategy::Literal(literal) => Cow::Borrowed(literal.as_str()),
+                InnerRenameStrategy::Case(case) => Cow::Owned(self.rename_abbr_case(*case, abbr)),
+            })
+    }
+
+    /// Returns an abbreviated string representation based on the
+    /// `#[variants(rename_abbr(...))]` outer attribute strategy
+    /// (`outer_rename_abbr`), if one has been specified for the type, falling
+    /// back to abbreviating the full length final string representation of the
+    /// variant as is (see [`TargetVariant::as_str`] documentation for further
+    /// details).
+    ///
+    /// The renaming follows a priority-based fallback approach to determine the
+    /// full length string representation before applying the abbreviation:
+    ///
+    /// 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string produced
+    ///    by the rename strategy from the `#[variants(rename(...))]` inner
+    ///    attribute, if one has been specified for the variant;
+    /// 1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+    ///    rename strategy from the `#[variants(rename(...))]` outer attribute, if
+    ///    one has been specified for the type;
+    /// 1. **No renaming** (_default_) - converts the variant identifier to a string
+    ///    if the outer rename attribute is not specified.
+    #[rustfmt::skip]
+    fn outer_rename_abbr(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+        outer_rename_abbr: Option<OuterRenameStrategy>,
+        abbr: AbbrStrategy,
+    ) -> String {
+        if let Some(rename_abbr) = outer_rename_abbr {
+            return self.rename_abbr_case(rename_abbr.case(), abbr);
+        }
+
+        let (base, base_case) = self.as_str_source(outer_rename);
+        base.into_owned().to_case_abbr_in_place(base_case, abbr)
+    }
+
+    /// Returns the final abbreviated string representation of the variant.
+    ///
+    /// This method applies rename strategies for the abbreviated string
+    /// representation of the variant, following a priority-based fallback
+    /// approach:
+    ///
+    /// 1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+    ///    string produced by the rename strategy from the
+    ///    `#[variants(rename_abbr(...))]` inner attribute, if one has been
+    ///    specified for the variant;
+    /// 1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string
+    ///    produced by the rename strategy from the
+    ///    `#[variants(rename_abbr(...))]` outer attribute, if one has been
+    ///    specified for the type;
+    /// 1. **No renaming** (_default_) - abbreviates the full length string
+    ///    representation of the variant as is, without applyaing any renaming
+    ///    strategy (see [`TargetVariant::as_str`]).
+    ///
+    /// Likewise, the renaming follows a priority-based fallback approach to
+    /// determine the full length string representation before applying the
+    /// abbreviation:
+    ///
+    /// 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+    ///    produced by the rename strategy from the `#[variants(rename(...))]`
+    ///    inner attribute, if one has been specified for the variant;
+    /// 1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by
+    ///    the rename strategy from the `#[variants(rename(...))]` outer
+    ///    attribute, if one has been specified for the type;
+    /// 1. **No renaming** (_default_) - converts the variant identifier to a
+    ///    string if neither the inner nor the outer rename attribute has been
+    ///    specified.
+    pub(crate) fn as_str_abbr(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+        outer_rename_abbr: Option<OuterRenameStrategy>,
+        abbr: AbbrStrategy,
+    ) -> Cow<'_, str> {
+        self.inner_rename_abbr(abbr).unwrap_or_else(|| {
+            let outer_rename_abbr = self.outer_rename_abbr(outer_rename, outer_rename_abbr, abbr);
+            Cow::Owned(outer_rename_abbr)
+        })
+    }
+
+    /// Returns the full length string representation the final abbreviated
+    /// string representation of the variant is produced from, if any.
+    ///
+    /// This method returns `None` if the abbreviated string representation
+    /// has been overridden with a string literal by the
+    /// `#[variants(rename_abbr(...))]` inner attribute, `Some` otherwise (see
+    /// [`TargetVariant::as_str_abbr`] for further details).
+    pub(crate) fn abbr_base(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+        outer_rename_abbr: Option<OuterRenameStrategy>,
+    ) -> Option<Cow<'_, str>> {
+        match (&self.options.rename_abbr, outer_rename_abbr) {
+            (Some(InnerRenameStrategy::Literal(_)), _) => None,
+            (Some(InnerRenameStrategy::Case(_)), _) | (None, Some(_)) => {
+                Some(self.rename_abbr_case_base())
+            }
+            (None, None) => Some(self.as_str(outer_rename)),
+        }
+    }
+
+    /// Retuns a "_match branch_", associating the variant to the final abbreviated
+    /// string representation, to be used in the generation of the `as_str_abbr`
+    /// method.
+    #[rustfmt::skip]
+    pub(crate) fn as_str_abbr_match_branch(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+        outer_rename_abbr: Option<OuterRenameStrategy>,
+        abbr: AbbrStrategy,
+    ) -> TokenStream {
+        let Self { ident, .. } = self;
+        let name_abbr = self.as_str_abbr(outer_rename, outer_rename_abbr, abbr);
+
+        quote::quote! { Self::#ident => #name_abbr }
+    }
+
+    /// Returns a quoted (double-quotes) version of the final abbreviated string
+    /// representation of the variant.
+    ///
+    /// For further details about the final abbreviated string representation
+    /// (i.e. rename strategies, etc.) see [`TargetVariant::as_str_abbr`].
+    pub(crate) fn as_quoted_string_abbr(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+        outer_rename_abbr: Option<OuterRenameStrategy>,
+        abbr: AbbrStrategy,
+    ) -> String {
+        format!(
+            "\"{}\"",
+            self.as_str_abbr(outer_rename, outer_rename_abbr, abbr)
+        )
+    }
+}
+
+/// Enum variant's validation implementation.
+impl TargetVariant {
+    /// Validates the final string and abbreviated string representations of
+    /// the variant.
+    ///
+    /// This method returns an error spanning the variant identifier if either
+    /// the final string or abbreviated string representation, or any alias of
+    /// the variant is empty (e.g. when renamed to an empty string literal), `Ok`
+    /// otherwise.
+    pub(crate) fn validate(
+        &self,
+        outer_rename: Option<OuterRenameStrategy>,
+        outer_rename_abbr: Option<OuterRenameStrategy>,
+        abbr: AbbrStrategy,
+    ) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+
+        if self.as_str(outer_rename).is_empty() {
+            errors.push(
+                darling::Error::custom("string representation must not be empty")
+                    .with_span(&self.ident),
+            );
+        }
+
+        if self
+            .as_str_abbr(outer_rename, outer_rename_abbr, abbr)
+            .is_empty()
+        {
+            errors.push(
+                darling::Error::custom("abbreviated string representation must not be empty")
+                    .with_span(&self.ident),
+            );
+        }
+
+        if self
+            .options
+            .alias
+            .iter()
+            .any(|alias| alias.value().is_empty())
+        {
+            errors.push(darling::Error::custom("alias must not be empty").with_span(&self.ident));
+        }
+
+        for (index, group) in self.options.group.iter().enumerate() {
+            let name = group.value();
+
+            if !is_lowercase_ident(&name) {
+                errors.push(
+                    darling::Error::custom(
+                        "group name must be a lowercase identifier (e.g. `weekend`)",
+                    )
+                    .with_span(group),
+                );
+            } else if RESERVED_GROUP_NAMES.contains(&name.as_str()) {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "group name `{name}` clashes with a generated item"
+                    ))
+                    .with_span(group),
+                );
+            } else if self.options.group[..index]
+                .iter()
+                .any(|other| other.value() == name)
+            {
+                errors.push(
+                    darling::Error::custom(format!("duplicate group `{name}`")).with_span(group),
+                );
+            }
+        }
+
+        if self.options.default && self.options.skip != Skip::default() {
+            errors.push(
+                darling::Error::custom("default variant must not be skipped")
+                    .with_span(&self.ident),
+            );
+        }
+
+        errors.finish()
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that its
+    /// `kind` representation (`value`) collides with the `other_kind`
+    /// representation of the `other` variant.
+    pub(crate) fn collision_error(
+        &self,
+        kind: &str,
+        value: &str,
+        other: &Self,
+        other_kind: &str,
+    ) -> darling::Error {
+        darling::Error::custom(format!(
+            "{kind} representation \"{value}\" of variant `{}` collides with the \
+             {other_kind} representation of variant `{}` (use \
+             `#[variants(allow_collisions)]` to allow it)",
+            self.ident.to_unraw_string(),
+            other.ident.to_unraw_string(),
+        ))
+        .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that a
+    /// previously declared variant has already been marked as `default`.
+    pub(crate) fn duplicate_default_error(&self) -> darling::Error {
+        darling::Error::custom("at most one variant can be marked as `default`")
+            .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that it's
+    /// been excluded from iteration although the `enum` generates a map type.
+    pub(crate) fn map_skipped_error(&self) -> darling::Error {
+        darling::Error::custom(
+            "`map` requires every variant to be iterable, remove `skip` or `skip(iter)`",
+        )
+        .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that it's
+    /// been excluded from the given `serde` trait implementation (i.e. marked
+    /// with the given `skip` mode) although the `enum` generates a map type,
+    /// whose keys go through such implementation.
+    #[cfg(feature = "serde")]
+    pub(crate) fn map_serde_skipped_error(
+        &self,
+        implementation: &str,
+        skip: &str,
+    ) -> darling::Error {
+        darling::Error::custom(format!(
+            "`map` with `{implementation}` requires every variant to support it, remove `{skip}`"
+        ))
+        .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that it's
+    /// been ranked although the `enum` is not ordered by rank.
+    pub(crate) fn unexpected_rank_error(&self) -> darling::Error {
+        darling::Error::custom("`rank` requires `#[variants(ord(rank))]`").with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that it's
+    /// not been ranked although the `enum` is ordered by rank.
+    pub(crate) fn missing_rank_error(&self) -> darling::Error {
+        darling::Error::custom("variant must be ranked with `#[variants(rank = N)]`")
+            .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that its
+    /// rank equals the rank of the `other` variant.
+    pub(crate) fn rank_collision_error(&self, other: &Self) -> darling::Error {
+        darling::Error::custom(format!(
+            "rank of variant `{}` collides with the rank of variant `{}`",
+            self.ident.to_unraw_string(),
+            other.ident.to_unraw_string(),
+        ))
+        .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that its
+    /// generated predicate method shares the given identifier with another
+    /// generated method, described by `other`.
+    pub(crate) fn predicate_collision_error(
+        &self,
+        predicate: &Ident,
+        other: &str,
+    ) -> darling::Error {
+        darling::Error::custom(format!(
+            "predicate `{predicate}` of variant `{}` collides with {other}",
+            self.ident.to_unraw_string(),
+        ))
+        .with_span(&self.ident)
+    }
+
+    /// Returns an error spanning the variant identifier, reporting that a
+    /// previously declared variant has already been marked as `other`.
+    pub(crate) fn duplicate_other_error(&self) -> darling::Error {
+        darling::Error::custom("at most one variant can be marked as `other`")
+            .with_span(&self.ident)
+    }
+}
+
+/// Enum variant's [`FromStr`] related implementation.
+///
+/// [`FromStr`]: ::std::str::FromStr
+impl TargetVariant {
+    /// Returns a "_parse candidate_", associating the given string
+    /// representation (`name`) to the variant, to be used in the generation of
+    /// parsing suggestions and of the `from_str_fuzzy` method.
+    pub(crate) fn parse_candidate(&self, name: &str) -> TokenStream {
+        let Self { ident, .. } = self;
+
+        quote::quote! { (#name, Self::#ident) }
+    }
+
+    /// Returns a "_match branch_", associating the given string representations
+    /// (`names`) to the variant, to be used in the generation of the [`FromStr`]
+    /// trait implementation.
+    ///
+    /// This method returns `None` if no string representation is given, since
+    /// an empty pattern can't be matched.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn from_str_match_branch(
+        &self,
+        names: &[String],
+        options: ParseOptions,
+    ) -> Option<TokenStream> {
+        let Self { ident, .. } = self;
+        let pattern = parse_match_pattern(names, options)?;
+
+        Some(quote::quote! { #pattern => ::std::result::Result::Ok(Self::#ident) })
+    }
+
+    /// Returns a "_match branch_", associating the given string representations
+    /// (`names`) to the known variant of the `{Enum}OrUnknown` companion type
+    /// wrapping the variant, to be used in the generation of its [`FromStr`]
+    /// and `Deserialize` trait implementations.
+    ///
+    /// This method returns `None` if no string representation is given, since
+    /// an empty pattern can't be matched.
+    ///
+    /// [`FromStr`]: ::std::str::FromStr
+    pub(crate) fn or_unknown_match_branch(
+        &self,
+        names: &[String],
+        options: ParseOptions,
+        enum_ident: &Ident,
+    ) -> Option<TokenStream> {
+        let Self { ident, .. } = self;
+        let pattern = parse_match_pattern(names, options)?;
+
+        Some(quote::quote! { #pattern => Self::Known(#enum_ident::#ident) })
+    }
+}
+
+/// Enum variant's `serde` related implementation.
+#[cfg(feature = "serde")]
+impl TargetVariant {
+    /// Returns a "_match branch_", associating the given string representations
+    /// (`names`) to the variant, to be used in the generation of the
+    /// `Deserialize` trait implementation.
+    ///
+    /// This method returns `None` if no string representation is given, since
+    /// an empty pattern can't be matched.
+    ///
+    /// [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+    pub(crate) fn deserialize_match_branch(
+        &self,
+        names: &[String],
+        options: ParseOptions,
+    ) -> Option<TokenStream> {
+        let Self { ident, .. } = self;
+        let pattern = parse_match_pattern(names, options)?;
+
+        Some(quote::quote! { #pattern => ::std::result::Result::Ok(Self::Value::#ident) })
+    }
+}
+
+/// The group names whose generated `{GROUP}` constant or `iter_{group}` method
+/// would clash with other items generated by the derive macro.
+const RESERVED_GROUP_NAMES: &[&str] = &[
+    "all",
+    "count",
+    "default",
+    "first",
+    "last",
+    "max",
+    "min",
+    "iterable_variants",
+    "iterable_variants_count",
+    "parse_candidates",
+    "parse_candidates_abbr",
+    "parse_candidates_full",
+    "variants",
+    "variants_as_str",
+    "variants_as_str_abbr",
+    "variants_sorted",
+];
+
+/// Checks whether the given name (e.g. a group name or a predicate prefix) is
+/// a lowercase identifier, i.e. it's made of lowercase ASCII letters, digits
+/// and underscores and starts with a lowercase ASCII letter, so that the
+/// generated items named after it are valid identifiers.
+pub(crate) fn is_lowercase_ident(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Returns the pattern of a parsing "_match branch_", matching the `value`
+/// being parsed against the given string representations (`names`).
+///
+/// The pattern is an alternation of string literals (e.g. `"Monday" | "Mon"`),
+/// or a guarded wildcard comparing the `value` to each string representation
+/// regardless of its ASCII case (i.e. without allocating) if the `options` are
+/// case insensitive. This function returns `None` if no string representation
+/// is given, since an empty pattern can't be matched.
+fn parse_match_pattern(names: &[String], options: ParseOptions) -> Option<TokenStream> {
+    if names.is_empty() {
+        return None;
+    }
+
+    match options.case_insensitive() {
+        true => Some(quote::quote! { _ if #(value.eq_ignore_ascii_case(#names))||* }),
+        false => Some(quote::quote! { #(#names)|* }),
+    }
+}
@@ -0,0 +1,653 @@
+use beerec_variants::Variants;
+#[variants(abbr(unique_prefix))]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+impl ::std::marker::Copy for Month {}
+impl ::std::clone::Clone for Month {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Month {
+    ///The array of _iterable_ (i.e. non-skipped) [`Month`] variants.
+    const ITERABLE_VARIANTS: [Self; 12usize] = [
+        Self::January,
+        Self::February,
+        Self::March,
+        Self::April,
+        Self::May,
+        Self::June,
+        Self::July,
+        Self::August,
+        Self::September,
+        Self::October,
+        Self::November,
+        Self::December,
+    ];
+    ///The number of _iterable_ (i.e. non-skipped) [`Month`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 12usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Month`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Month`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Month`] variant, in declaration order.
+    pub const FIRST: Self = Self::January;
+    ///The last _iterable_ (i.e. non-skipped) [`Month`] variant, in declaration order.
+    pub const LAST: Self = Self::December;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Month`] variant following this one in [`Month::ALL`], wrapping around to [`Month::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Month`] variant preceding this one in [`Month::ALL`], wrapping around to [`Month::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Month`] variant following this one in [`Month::ALL`], stopping at [`Month::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Month`] variant preceding this one in [`Month::ALL`], stopping at [`Month::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Month`] variant `n` positions away from this one in [`Month::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Month`] variant to `other` in [`Month::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Month`] variant in [`Month::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::January => 0usize,
+            Self::February => 1usize,
+            Self::March => 2usize,
+            Self::April => 3usize,
+            Self::May => 4usize,
+            Self::June => 5usize,
+            Self::July => 6usize,
+            Self::August => 7usize,
+            Self::September => 8usize,
+            Self::October => 9usize,
+            Self::November => 10usize,
+            Self::December => 11usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Month`] variant at the given position in [`Month::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::January),
+            1usize => ::std::option::Option::Some(Self::February),
+            2usize => ::std::option::Option::Some(Self::March),
+            3usize => ::std::option::Option::Some(Self::April),
+            4usize => ::std::option::Option::Some(Self::May),
+            5usize => ::std::option::Option::Some(Self::June),
+            6usize => ::std::option::Option::Some(Self::July),
+            7usize => ::std::option::Option::Some(Self::August),
+            8usize => ::std::option::Option::Some(Self::September),
+            9usize => ::std::option::Option::Some(Self::October),
+            10usize => ::std::option::Option::Some(Self::November),
+            11usize => ::std::option::Option::Some(Self::December),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Month`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::January => "January",
+            Self::February => "February",
+            Self::March => "March",
+            Self::April => "April",
+            Self::May => "May",
+            Self::June => "June",
+            Self::July => "July",
+            Self::August => "August",
+            Self::September => "September",
+            Self::October => "October",
+            Self::November => "November",
+            Self::December => "December",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Month`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::January => "Ja",
+            Self::February => "F",
+            Self::March => "Mar",
+            Self::April => "Ap",
+            Self::May => "May",
+            Self::June => "Jun",
+            Self::July => "Jul",
+            Self::August => "Au",
+            Self::September => "S",
+            Self::October => "O",
+            Self::November => "N",
+            Self::December => "D",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Month`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> MonthIter {
+        MonthIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Month`] variants of [`Month::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> MonthIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        MonthIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Month`] variants of [`Month::ALL`] within the given range, wrapping around to [`Month::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> MonthIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        MonthIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Month`]
+variants.
+
+See [`Month::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> MonthAsStrIter {
+        MonthAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Month`] variants.
+
+See [`Month::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> MonthAsStrAbbrIter {
+        MonthAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Month`]
+variants.
+
+See [`Month::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"January\", \"February\", \"March\", \"April\", \"May\", \"June\", \"July\", \"August\", \"September\", \"October\", \"November\", \"December\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Month`]
+variants.
+
+See [`Month::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Ja\", \"F\", \"Mar\", \"Ap\", \"May\", \"Jun\", \"Jul\", \"Au\", \"S\", \"O\", \"N\", \"D\""
+    }
+}
+#[automatically_derived]
+impl Month {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::January`] variant.
+    pub const fn is_january(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::January => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::February`] variant.
+    pub const fn is_february(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::February => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::March`] variant.
+    pub const fn is_march(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::March => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::April`] variant.
+    pub const fn is_april(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::April => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::May`] variant.
+    pub const fn is_may(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::May => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::June`] variant.
+    pub const fn is_june(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::June => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::July`] variant.
+    pub const fn is_july(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::July => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::August`] variant.
+    pub const fn is_august(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::August => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::September`] variant.
+    pub const fn is_september(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::September => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::October`] variant.
+    pub const fn is_october(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::October => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::November`] variant.
+    pub const fn is_november(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::November => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Month`] value is the [`Month::December`] variant.
+    pub const fn is_december(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::December => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Month`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::January, Self::January)
+            | (Self::February, Self::February)
+            | (Self::March, Self::March)
+            | (Self::April, Self::April)
+            | (Self::May, Self::May)
+            | (Self::June, Self::June)
+            | (Self::July, Self::July)
+            | (Self::August, Self::August)
+            | (Self::September, Self::September)
+            | (Self::October, Self::October)
+            | (Self::November, Self::November)
+            | (Self::December, Self::December) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Month`] variants, returned by [`Month::iter_variants`].
+pub struct MonthIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for MonthIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "MonthIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for MonthIter {
+    #[inline]
+    fn clone(&self) -> MonthIter {
+        MonthIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for MonthIter {
+    type Item = Month;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Month::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Month::from_index(
+            if index < Month::COUNT { index } else { index - Month::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for MonthIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Month::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Month::from_index(
+            if self.back < Month::COUNT { self.back } else { self.back - Month::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for MonthIter {}
+impl ::std::iter::FusedIterator for MonthIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Month`] variants, returned by [`Month::iter_variants_as_str`].
+pub struct MonthAsStrIter(MonthIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for MonthAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "MonthAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for MonthAsStrIter {
+    #[inline]
+    fn clone(&self) -> MonthAsStrIter {
+        MonthAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for MonthAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Month::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Month::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for MonthAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Month::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Month::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for MonthAsStrIter {}
+impl ::std::iter::FusedIterator for MonthAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Month`] variants, returned by [`Month::iter_variants_as_str_abbr`].
+pub struct MonthAsStrAbbrIter(MonthIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for MonthAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "MonthAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for MonthAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> MonthAsStrAbbrIter {
+        MonthAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for MonthAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Month::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Month::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for MonthAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Month::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Month::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for MonthAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for MonthAsStrAbbrIter {}
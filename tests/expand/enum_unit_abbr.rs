@@ -0,0 +1,18 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(abbr(unique_prefix))]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
@@ -0,0 +1,527 @@
+use beerec_variants::Variants;
+#[variants(rename(uppercase), abbr(initials))]
+pub enum Direction {
+    NorthEast,
+    SouthWest,
+    #[variants(rename(lowercase))]
+    NorthWest,
+    #[variants(rename_abbr(lowercase))]
+    SouthEast,
+}
+impl ::std::marker::Copy for Direction {}
+impl ::std::clone::Clone for Direction {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Direction {
+    ///The array of _iterable_ (i.e. non-skipped) [`Direction`] variants.
+    const ITERABLE_VARIANTS: [Self; 4usize] = [
+        Self::NorthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+        Self::SouthEast,
+    ];
+    ///The number of _iterable_ (i.e. non-skipped) [`Direction`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 4usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Direction`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Direction`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Direction`] variant, in declaration order.
+    pub const FIRST: Self = Self::NorthEast;
+    ///The last _iterable_ (i.e. non-skipped) [`Direction`] variant, in declaration order.
+    pub const LAST: Self = Self::SouthEast;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant following this one in [`Direction::ALL`], wrapping around to [`Direction::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant preceding this one in [`Direction::ALL`], wrapping around to [`Direction::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant following this one in [`Direction::ALL`], stopping at [`Direction::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant preceding this one in [`Direction::ALL`], stopping at [`Direction::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant `n` positions away from this one in [`Direction::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Direction`] variant to `other` in [`Direction::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Direction`] variant in [`Direction::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NorthEast => 0usize,
+            Self::SouthWest => 1usize,
+            Self::NorthWest => 2usize,
+            Self::SouthEast => 3usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant at the given position in [`Direction::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::NorthEast),
+            1usize => ::std::option::Option::Some(Self::SouthWest),
+            2usize => ::std::option::Option::Some(Self::NorthWest),
+            3usize => ::std::option::Option::Some(Self::SouthEast),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Direction`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::NorthEast => "NORTHEAST",
+            Self::SouthWest => "SOUTHWEST",
+            Self::NorthWest => "northwest",
+            Self::SouthEast => "SOUTHEAST",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Direction`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::NorthEast => "NE",
+            Self::SouthWest => "SW",
+            Self::NorthWest => "nw",
+            Self::SouthEast => "se",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Direction`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> DirectionIter {
+        DirectionIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Direction`] variants of [`Direction::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> DirectionIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        DirectionIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Direction`] variants of [`Direction::ALL`] within the given range, wrapping around to [`Direction::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> DirectionIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        DirectionIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Direction`]
+variants.
+
+See [`Direction::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> DirectionAsStrIter {
+        DirectionAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Direction`] variants.
+
+See [`Direction::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> DirectionAsStrAbbrIter {
+        DirectionAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Direction`]
+variants.
+
+See [`Direction::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"NORTHEAST\", \"SOUTHWEST\", \"northwest\", \"SOUTHEAST\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Direction`]
+variants.
+
+See [`Direction::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"NE\", \"SW\", \"nw\", \"se\""
+    }
+}
+#[automatically_derived]
+impl Direction {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::NorthEast`] variant.
+    pub const fn is_north_east(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::NorthEast => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::SouthWest`] variant.
+    pub const fn is_south_west(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::SouthWest => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::NorthWest`] variant.
+    pub const fn is_north_west(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::NorthWest => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::SouthEast`] variant.
+    pub const fn is_south_east(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::SouthEast => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Direction`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::NorthEast, Self::NorthEast)
+            | (Self::SouthWest, Self::SouthWest)
+            | (Self::NorthWest, Self::NorthWest)
+            | (Self::SouthEast, Self::SouthEast) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Direction`] variants, returned by [`Direction::iter_variants`].
+pub struct DirectionIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for DirectionIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "DirectionIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DirectionIter {
+    #[inline]
+    fn clone(&self) -> DirectionIter {
+        DirectionIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for DirectionIter {
+    type Item = Direction;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Direction::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Direction::from_index(
+            if index < Direction::COUNT { index } else { index - Direction::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DirectionIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Direction::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Direction::from_index(
+            if self.back < Direction::COUNT {
+                self.back
+            } else {
+                self.back - Direction::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for DirectionIter {}
+impl ::std::iter::FusedIterator for DirectionIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Direction`] variants, returned by [`Direction::iter_variants_as_str`].
+pub struct DirectionAsStrIter(DirectionIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for DirectionAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "DirectionAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DirectionAsStrIter {
+    #[inline]
+    fn clone(&self) -> DirectionAsStrIter {
+        DirectionAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for DirectionAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Direction::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Direction::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DirectionAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Direction::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Direction::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for DirectionAsStrIter {}
+impl ::std::iter::FusedIterator for DirectionAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Direction`] variants, returned by [`Direction::iter_variants_as_str_abbr`].
+pub struct DirectionAsStrAbbrIter(DirectionIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for DirectionAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "DirectionAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DirectionAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> DirectionAsStrAbbrIter {
+        DirectionAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for DirectionAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Direction::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Direction::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DirectionAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Direction::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Direction::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for DirectionAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for DirectionAsStrAbbrIter {}
@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(rename(uppercase), abbr(initials))]
+pub enum Direction {
+    NorthEast,
+    SouthWest,
+    #[variants(rename(lowercase))]
+    NorthWest,
+    #[variants(rename_abbr(lowercase))]
+    SouthEast,
+}
@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str)]
+pub enum Color {
+    #[variants(alias = "Crimson", alias("Rosso", deprecated))]
+    Red,
+    #[variants(alias = "Emerald")]
+    Green,
+    #[variants(alias("Azzurro", deprecated))]
+    Blue,
+}
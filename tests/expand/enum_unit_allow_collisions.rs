@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(allow_collisions, from_str)]
+pub enum Planet {
+    March,
+    Mars,
+    May,
+}
@@ -0,0 +1,514 @@
+use beerec_variants::Variants;
+pub enum Priority {
+    Low,
+    #[variants(default)]
+    Medium,
+    High,
+}
+impl ::std::marker::Copy for Priority {}
+impl ::std::clone::Clone for Priority {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Priority {
+    ///The array of _iterable_ (i.e. non-skipped) [`Priority`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [Self::Low, Self::Medium, Self::High];
+    ///The number of _iterable_ (i.e. non-skipped) [`Priority`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Priority`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Priority`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Priority`] variant, in declaration order.
+    pub const FIRST: Self = Self::Low;
+    ///The last _iterable_ (i.e. non-skipped) [`Priority`] variant, in declaration order.
+    pub const LAST: Self = Self::High;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant following this one in [`Priority::ALL`], wrapping around to [`Priority::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant preceding this one in [`Priority::ALL`], wrapping around to [`Priority::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant following this one in [`Priority::ALL`], stopping at [`Priority::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant preceding this one in [`Priority::ALL`], stopping at [`Priority::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant `n` positions away from this one in [`Priority::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Priority`] variant to `other` in [`Priority::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Priority`] variant in [`Priority::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Low => 0usize,
+            Self::Medium => 1usize,
+            Self::High => 2usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant at the given position in [`Priority::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Low),
+            1usize => ::std::option::Option::Some(Self::Medium),
+            2usize => ::std::option::Option::Some(Self::High),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Priority`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Priority`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Med",
+            Self::High => "Hig",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Priority`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> PriorityIter {
+        PriorityIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Priority`] variants of [`Priority::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> PriorityIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        PriorityIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Priority`] variants of [`Priority::ALL`] within the given range, wrapping around to [`Priority::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> PriorityIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        PriorityIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Priority`]
+variants.
+
+See [`Priority::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> PriorityAsStrIter {
+        PriorityAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Priority`] variants.
+
+See [`Priority::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> PriorityAsStrAbbrIter {
+        PriorityAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Priority`]
+variants.
+
+See [`Priority::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Low\", \"Medium\", \"High\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Priority`]
+variants.
+
+See [`Priority::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Low\", \"Med\", \"Hig\""
+    }
+}
+#[automatically_derived]
+impl Priority {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Priority`] value is the [`Priority::Low`] variant.
+    pub const fn is_low(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Low => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Priority`] value is the [`Priority::Medium`] variant.
+    pub const fn is_medium(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Medium => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Priority`] value is the [`Priority::High`] variant.
+    pub const fn is_high(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::High => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Priority`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Low, Self::Low)
+            | (Self::Medium, Self::Medium)
+            | (Self::High, Self::High) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Priority`] variants, returned by [`Priority::iter_variants`].
+pub struct PriorityIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for PriorityIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "PriorityIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PriorityIter {
+    #[inline]
+    fn clone(&self) -> PriorityIter {
+        PriorityIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for PriorityIter {
+    type Item = Priority;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Priority::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Priority::from_index(
+            if index < Priority::COUNT { index } else { index - Priority::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PriorityIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Priority::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Priority::from_index(
+            if self.back < Priority::COUNT {
+                self.back
+            } else {
+                self.back - Priority::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for PriorityIter {}
+impl ::std::iter::FusedIterator for PriorityIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Priority`] variants, returned by [`Priority::iter_variants_as_str`].
+pub struct PriorityAsStrIter(PriorityIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PriorityAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PriorityAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PriorityAsStrIter {
+    #[inline]
+    fn clone(&self) -> PriorityAsStrIter {
+        PriorityAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PriorityAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Priority::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Priority::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PriorityAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Priority::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Priority::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PriorityAsStrIter {}
+impl ::std::iter::FusedIterator for PriorityAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Priority`] variants, returned by [`Priority::iter_variants_as_str_abbr`].
+pub struct PriorityAsStrAbbrIter(PriorityIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PriorityAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PriorityAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PriorityAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> PriorityAsStrAbbrIter {
+        PriorityAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PriorityAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Priority::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Priority::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PriorityAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Priority::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Priority::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PriorityAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for PriorityAsStrAbbrIter {}
+#[automatically_derived]
+impl Priority {
+    ///The default [`Priority`] variant, i.e. [`Priority::Medium`].
+    pub const DEFAULT: Self = Self::Medium;
+}
+impl ::std::default::Default for Priority {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Priority {
+    Low,
+    #[variants(default)]
+    Medium,
+    High,
+}
@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, Clone, Copy, PartialEq, Variants)]
+#[variants(derived(Debug, Clone, Copy, PartialEq), derive(Eq, Hash))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+}
@@ -0,0 +1,10 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[derive(Clone, Copy, PartialEq)]
+#[variants(derive(PartialEq, Eq))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+}
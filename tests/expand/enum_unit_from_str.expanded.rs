@@ -29,14 +29,109 @@ impl Weekday {
     ];
     ///The number of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
     const ITERABLE_VARIANTS_COUNT: usize = 7usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Weekday`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Weekday`] variant, in declaration order.
+    pub const FIRST: Self = Self::Monday;
+    ///The last _iterable_ (i.e. non-skipped) [`Weekday`] variant, in declaration order.
+    pub const LAST: Self = Self::Sunday;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant following this one in [`Weekday::ALL`], wrapping around to [`Weekday::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant preceding this one in [`Weekday::ALL`], wrapping around to [`Weekday::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant following this one in [`Weekday::ALL`], stopping at [`Weekday::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant preceding this one in [`Weekday::ALL`], stopping at [`Weekday::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant `n` positions away from this one in [`Weekday::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Weekday`] variant to `other` in [`Weekday::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Weekday`] variant in [`Weekday::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Monday => 0usize,
+            Self::Tuesday => 1usize,
+            Self::Wednesday => 2usize,
+            Self::Thursday => 3usize,
+            Self::Friday => 4usize,
+            Self::Saturday => 5usize,
+            Self::Sunday => 6usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant at the given position in [`Weekday::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Monday),
+            1usize => ::std::option::Option::Some(Self::Tuesday),
+            2usize => ::std::option::Option::Some(Self::Wednesday),
+            3usize => ::std::option::Option::Some(Self::Thursday),
+            4usize => ::std::option::Option::Some(Self::Friday),
+            5usize => ::std::option::Option::Some(Self::Saturday),
+            6usize => ::std::option::Option::Some(Self::Sunday),
+            _ => ::std::option::Option::None,
+        }
+    }
     #[inline]
     #[must_use]
     /**Returns a string representation of the [`Weekday`] variant.
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`]
-crate, it applies rename strategies following a priority-based fallback approach:
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
 
 1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
    produced by the rename strategy from the `#[variants(rename(...))]`
@@ -67,8 +162,7 @@ crate, it applies rename strategies following a priority-based fallback approach
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`]
-crate, it applies rename strategies on the string representation of the
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
 variant, following a priority-based fallback approach:
 
 1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
@@ -111,13 +205,65 @@ abbreviation:
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from iteration.
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
 
 [`beerec-variants`]: https://docs.rs/beerec-variants
 [`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
-    pub fn iter_variants() -> impl ::std::iter::Iterator<Item = Self> {
-        Self::ITERABLE_VARIANTS.into_iter()
+    pub const fn iter_variants() -> WeekdayIter {
+        WeekdayIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Weekday`] variants of [`Weekday::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> WeekdayIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        WeekdayIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Weekday`] variants of [`Weekday::ALL`] within the given range, wrapping around to [`Weekday::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> WeekdayIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        WeekdayIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
     }
     /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Weekday`]
 variants.
@@ -126,13 +272,12 @@ See [`Weekday::as_str`] for further details about yielded values.
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from iteration.
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
 
 [`beerec-variants`]: https://docs.rs/beerec-variants
 [`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
-    pub fn iter_variants_as_str() -> impl ::std::iter::Iterator<Item = &'static str> {
-        Self::iter_variants().map(Self::as_str)
+    pub const fn iter_variants_as_str() -> WeekdayAsStrIter {
+        WeekdayAsStrIter(Self::iter_variants())
     }
     /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
 [`Weekday`] variants.
@@ -141,25 +286,22 @@ See [`Weekday::as_str_abbr`] for further details about yielded values.
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from iteration.
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
 
 [`beerec-variants`]: https://docs.rs/beerec-variants
 [`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
-    pub fn iter_variants_as_str_abbr() -> impl ::std::iter::Iterator<
-        Item = &'static str,
-    > {
-        Self::iter_variants().map(Self::as_str_abbr)
+    pub const fn iter_variants_as_str_abbr() -> WeekdayAsStrAbbrIter {
+        WeekdayAsStrAbbrIter(Self::iter_variants())
     }
     /**Returns a list of quoted (double-quotes) and comma separated string
-representations of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Weekday`]
+variants.
 
 See [`Weekday::as_str`] for further details about the string representations.
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from the listing.
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
 
 [`beerec-variants`]: https://docs.rs/beerec-variants
 [`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
@@ -167,14 +309,14 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
         "\"Monday\", \"Tuesday\", \"Wednesday\", \"Thursday\", \"Friday\", \"Saturday\", \"Sunday\""
     }
     /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
-representations of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Weekday`]
+variants.
 
 See [`Weekday::as_str_abbr`] for further details about the abbreviated string representations.
 
 # Notes
 
-This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate,
-enum variants marked with the `#[variants(skip)]` attribute are excluded from the listing.
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
 
 [`beerec-variants`]: https://docs.rs/beerec-variants
 [`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
@@ -182,12 +324,385 @@ enum variants marked with the `#[variants(skip)]` attribute are excluded from th
         "\"Mon\", \"Tue\", \"Wed\", \"Thu\", \"Fri\", \"Sat\", \"Sun\""
     }
 }
-pub struct ParseWeekdayError;
+#[automatically_derived]
+impl Weekday {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Monday`] variant.
+    pub const fn is_monday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Monday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Tuesday`] variant.
+    pub const fn is_tuesday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Tuesday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Wednesday`] variant.
+    pub const fn is_wednesday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Wednesday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Thursday`] variant.
+    pub const fn is_thursday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Thursday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Friday`] variant.
+    pub const fn is_friday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Friday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Saturday`] variant.
+    pub const fn is_saturday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Saturday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Sunday`] variant.
+    pub const fn is_sunday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Sunday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Weekday`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Monday, Self::Monday)
+            | (Self::Tuesday, Self::Tuesday)
+            | (Self::Wednesday, Self::Wednesday)
+            | (Self::Thursday, Self::Thursday)
+            | (Self::Friday, Self::Friday)
+            | (Self::Saturday, Self::Saturday)
+            | (Self::Sunday, Self::Sunday) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Weekday`] variants, returned by [`Weekday::iter_variants`].
+pub struct WeekdayIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for WeekdayIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "WeekdayIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for WeekdayIter {
+    #[inline]
+    fn clone(&self) -> WeekdayIter {
+        WeekdayIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for WeekdayIter {
+    type Item = Weekday;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Weekday::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Weekday::from_index(
+            if index < Weekday::COUNT { index } else { index - Weekday::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for WeekdayIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Weekday::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Weekday::from_index(
+            if self.back < Weekday::COUNT {
+                self.back
+            } else {
+                self.back - Weekday::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for WeekdayIter {}
+impl ::std::iter::FusedIterator for WeekdayIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Weekday`] variants, returned by [`Weekday::iter_variants_as_str`].
+pub struct WeekdayAsStrIter(WeekdayIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for WeekdayAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "WeekdayAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for WeekdayAsStrIter {
+    #[inline]
+    fn clone(&self) -> WeekdayAsStrIter {
+        WeekdayAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for WeekdayAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Weekday::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Weekday::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for WeekdayAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Weekday::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Weekday::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for WeekdayAsStrIter {}
+impl ::std::iter::FusedIterator for WeekdayAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Weekday`] variants, returned by [`Weekday::iter_variants_as_str_abbr`].
+pub struct WeekdayAsStrAbbrIter(WeekdayIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for WeekdayAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "WeekdayAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for WeekdayAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> WeekdayAsStrAbbrIter {
+        WeekdayAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for WeekdayAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Weekday::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Weekday::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for WeekdayAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Weekday::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Weekday::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for WeekdayAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for WeekdayAsStrAbbrIter {}
+#[automatically_derived]
+impl Weekday {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_FULL: [(&'static str, Self); 7usize] = [
+        ("Monday", Self::Monday),
+        ("Tuesday", Self::Tuesday),
+        ("Wednesday", Self::Wednesday),
+        ("Thursday", Self::Thursday),
+        ("Friday", Self::Friday),
+        ("Saturday", Self::Saturday),
+        ("Sunday", Self::Sunday),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_ABBR: [(&'static str, Self); 7usize] = [
+        ("Mon", Self::Monday),
+        ("Tue", Self::Tuesday),
+        ("Wed", Self::Wednesday),
+        ("Thu", Self::Thursday),
+        ("Fri", Self::Friday),
+        ("Sat", Self::Saturday),
+        ("Sun", Self::Sunday),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES: [(&'static str, Self); 14usize] = [
+        ("Monday", Self::Monday),
+        ("Mon", Self::Monday),
+        ("Tuesday", Self::Tuesday),
+        ("Tue", Self::Tuesday),
+        ("Wednesday", Self::Wednesday),
+        ("Wed", Self::Wednesday),
+        ("Thursday", Self::Thursday),
+        ("Thu", Self::Thursday),
+        ("Friday", Self::Friday),
+        ("Fri", Self::Friday),
+        ("Saturday", Self::Saturday),
+        ("Sat", Self::Saturday),
+        ("Sunday", Self::Sunday),
+        ("Sun", Self::Sunday),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+    #[must_use]
+    ///Parses a [`Weekday`] variant out of the string or abbreviated string representation (or non-deprecated alias) most similar to the given value, if their similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to `1.0` (i.e. strings equal regardless of their ASCII case).
+    pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+        Self::PARSE_CANDIDATES
+            .into_iter()
+            .map(|(candidate, variant)| {
+                let distance = Self::parse_edit_distance(value, candidate);
+                let len = ::std::cmp::max(
+                    value.chars().count(),
+                    candidate.chars().count(),
+                );
+                let similarity = match len {
+                    0 => 1.0,
+                    len => 1.0 - distance as f64 / len as f64,
+                };
+                (variant, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+            .map(|(variant, _)| variant)
+    }
+}
+pub struct ParseWeekdayError {
+    input: ::std::string::String,
+    suggestion: ::std::option::Option<&'static str>,
+    expected: &'static str,
+}
 #[automatically_derived]
 impl ::core::fmt::Debug for ParseWeekdayError {
     #[inline]
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        ::core::fmt::Formatter::write_str(f, "ParseWeekdayError")
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "ParseWeekdayError",
+            "input",
+            &self.input,
+            "suggestion",
+            &self.suggestion,
+            "expected",
+            &&self.expected,
+        )
     }
 }
 #[automatically_derived]
@@ -196,28 +711,99 @@ impl ::core::marker::StructuralPartialEq for ParseWeekdayError {}
 impl ::core::cmp::PartialEq for ParseWeekdayError {
     #[inline]
     fn eq(&self, other: &ParseWeekdayError) -> bool {
-        true
+        self.input == other.input && self.suggestion == other.suggestion
+            && self.expected == other.expected
     }
 }
 #[automatically_derived]
 impl ::core::cmp::Eq for ParseWeekdayError {
-    #[inline]
     #[doc(hidden)]
     #[coverage(off)]
-    fn assert_receiver_is_total_eq(&self) -> () {}
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+        let _: ::core::cmp::AssertParamIsEq<::std::option::Option<&'static str>>;
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+impl ParseWeekdayError {
+    /// Returns the input that failed to be parsed, possibly truncated.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Returns the closest string or abbreviated string representation (or
+    /// alias) to the input that failed to be parsed, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+        self.suggestion
+    }
 }
 impl ::std::fmt::Display for ParseWeekdayError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::std::fmt::Formatter::write_str(f, "Expected one of ")?;
-        ::std::fmt::Formatter::write_str(f, Weekday::variants_list_str())?;
-        ::std::fmt::Formatter::write_str(f, " or one of ")?;
-        ::std::fmt::Formatter::write_str(f, Weekday::variants_list_str_abbr())?;
-        Ok(())
+        f.write_fmt(format_args!("unknown {0} `{1}`", "weekday", self.input))?;
+        if let ::std::option::Option::Some(suggestion) = self.suggestion {
+            return f.write_fmt(format_args!(", did you mean `{0}`?", suggestion));
+        }
+        f.write_fmt(format_args!(", expected {0}", self.expected))
     }
 }
 impl ::std::error::Error for ParseWeekdayError {}
+#[automatically_derived]
+impl Weekday {
+    ///Parses a [`Weekday`] variant out of its string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_full(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Monday" => ::std::result::Result::Ok(Self::Monday),
+            "Tuesday" => ::std::result::Result::Ok(Self::Tuesday),
+            "Wednesday" => ::std::result::Result::Ok(Self::Wednesday),
+            "Thursday" => ::std::result::Result::Ok(Self::Thursday),
+            "Friday" => ::std::result::Result::Ok(Self::Friday),
+            "Saturday" => ::std::result::Result::Ok(Self::Saturday),
+            "Sunday" => ::std::result::Result::Ok(Self::Sunday),
+            _ => {
+                let error = ParseWeekdayError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_FULL,
+                    ),
+                    expected: "one of \"Monday\", \"Tuesday\", \"Wednesday\", \"Thursday\", \"Friday\", \"Saturday\", \"Sunday\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+    ///Parses a [`Weekday`] variant out of its abbreviated string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_abbr(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Mon" => ::std::result::Result::Ok(Self::Monday),
+            "Tue" => ::std::result::Result::Ok(Self::Tuesday),
+            "Wed" => ::std::result::Result::Ok(Self::Wednesday),
+            "Thu" => ::std::result::Result::Ok(Self::Thursday),
+            "Fri" => ::std::result::Result::Ok(Self::Friday),
+            "Sat" => ::std::result::Result::Ok(Self::Saturday),
+            "Sun" => ::std::result::Result::Ok(Self::Sunday),
+            _ => {
+                let error = ParseWeekdayError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_ABBR,
+                    ),
+                    expected: "one of \"Mon\", \"Tue\", \"Wed\", \"Thu\", \"Fri\", \"Sat\", \"Sun\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
 impl ::std::str::FromStr for Weekday {
     type Err = ParseWeekdayError;
+    ///Parses a [`Weekday`] variant out of its string representations (or aliases), with no variant excluded from parsing.
     fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
         match value {
             "Monday" | "Mon" => ::std::result::Result::Ok(Self::Monday),
@@ -227,7 +813,14 @@ impl ::std::str::FromStr for Weekday {
             "Friday" | "Fri" => ::std::result::Result::Ok(Self::Friday),
             "Saturday" | "Sat" => ::std::result::Result::Ok(Self::Saturday),
             "Sunday" | "Sun" => ::std::result::Result::Ok(Self::Sunday),
-            _ => ::std::result::Result::Err(ParseWeekdayError),
+            _ => {
+                let error = ParseWeekdayError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(value, Self::PARSE_CANDIDATES),
+                    expected: "one of \"Monday\", \"Tuesday\", \"Wednesday\", \"Thursday\", \"Friday\", \"Saturday\", \"Sunday\" or one of \"Mon\", \"Tue\", \"Wed\", \"Thu\", \"Fri\", \"Sat\", \"Sun\"",
+                };
+                ::std::result::Result::Err(error)
+            }
         }
     }
 }
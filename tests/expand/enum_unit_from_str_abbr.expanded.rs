@@ -0,0 +1,748 @@
+use beerec_variants::Variants;
+#[variants(from_str(abbr))]
+pub enum Direction {
+    North,
+    #[variants(alias = "Sud")]
+    South,
+    East,
+    West,
+}
+impl ::std::marker::Copy for Direction {}
+impl ::std::clone::Clone for Direction {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Direction {
+    ///The array of _iterable_ (i.e. non-skipped) [`Direction`] variants.
+    const ITERABLE_VARIANTS: [Self; 4usize] = [
+        Self::North,
+        Self::South,
+        Self::East,
+        Self::West,
+    ];
+    ///The number of _iterable_ (i.e. non-skipped) [`Direction`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 4usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Direction`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Direction`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Direction`] variant, in declaration order.
+    pub const FIRST: Self = Self::North;
+    ///The last _iterable_ (i.e. non-skipped) [`Direction`] variant, in declaration order.
+    pub const LAST: Self = Self::West;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant following this one in [`Direction::ALL`], wrapping around to [`Direction::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant preceding this one in [`Direction::ALL`], wrapping around to [`Direction::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant following this one in [`Direction::ALL`], stopping at [`Direction::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant preceding this one in [`Direction::ALL`], stopping at [`Direction::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant `n` positions away from this one in [`Direction::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Direction`] variant to `other` in [`Direction::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Direction`] variant in [`Direction::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::North => 0usize,
+            Self::South => 1usize,
+            Self::East => 2usize,
+            Self::West => 3usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Direction`] variant at the given position in [`Direction::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::North),
+            1usize => ::std::option::Option::Some(Self::South),
+            2usize => ::std::option::Option::Some(Self::East),
+            3usize => ::std::option::Option::Some(Self::West),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Direction`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::North => "North",
+            Self::South => "South",
+            Self::East => "East",
+            Self::West => "West",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Direction`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::North => "Nor",
+            Self::South => "Sou",
+            Self::East => "Eas",
+            Self::West => "Wes",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Direction`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> DirectionIter {
+        DirectionIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Direction`] variants of [`Direction::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> DirectionIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        DirectionIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Direction`] variants of [`Direction::ALL`] within the given range, wrapping around to [`Direction::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> DirectionIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        DirectionIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Direction`]
+variants.
+
+See [`Direction::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> DirectionAsStrIter {
+        DirectionAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Direction`] variants.
+
+See [`Direction::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> DirectionAsStrAbbrIter {
+        DirectionAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Direction`]
+variants.
+
+See [`Direction::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"North\", \"South\", \"East\", \"West\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Direction`]
+variants.
+
+See [`Direction::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Nor\", \"Sou\", \"Eas\", \"Wes\""
+    }
+}
+#[automatically_derived]
+impl Direction {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::North`] variant.
+    pub const fn is_north(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::North => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::South`] variant.
+    pub const fn is_south(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::South => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::East`] variant.
+    pub const fn is_east(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::East => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Direction`] value is the [`Direction::West`] variant.
+    pub const fn is_west(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::West => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Direction`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::North, Self::North)
+            | (Self::South, Self::South)
+            | (Self::East, Self::East)
+            | (Self::West, Self::West) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Direction`] variants, returned by [`Direction::iter_variants`].
+pub struct DirectionIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for DirectionIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "DirectionIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DirectionIter {
+    #[inline]
+    fn clone(&self) -> DirectionIter {
+        DirectionIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for DirectionIter {
+    type Item = Direction;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Direction::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Direction::from_index(
+            if index < Direction::COUNT { index } else { index - Direction::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DirectionIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Direction::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Direction::from_index(
+            if self.back < Direction::COUNT {
+                self.back
+            } else {
+                self.back - Direction::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for DirectionIter {}
+impl ::std::iter::FusedIterator for DirectionIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Direction`] variants, returned by [`Direction::iter_variants_as_str`].
+pub struct DirectionAsStrIter(DirectionIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for DirectionAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "DirectionAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DirectionAsStrIter {
+    #[inline]
+    fn clone(&self) -> DirectionAsStrIter {
+        DirectionAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for DirectionAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Direction::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Direction::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DirectionAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Direction::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Direction::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for DirectionAsStrIter {}
+impl ::std::iter::FusedIterator for DirectionAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Direction`] variants, returned by [`Direction::iter_variants_as_str_abbr`].
+pub struct DirectionAsStrAbbrIter(DirectionIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for DirectionAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "DirectionAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DirectionAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> DirectionAsStrAbbrIter {
+        DirectionAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for DirectionAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Direction::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Direction::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DirectionAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Direction::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Direction::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for DirectionAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for DirectionAsStrAbbrIter {}
+#[automatically_derived]
+impl Direction {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_FULL: [(&'static str, Self); 5usize] = [
+        ("North", Self::North),
+        ("South", Self::South),
+        ("Sud", Self::South),
+        ("East", Self::East),
+        ("West", Self::West),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_ABBR: [(&'static str, Self); 5usize] = [
+        ("Nor", Self::North),
+        ("Sou", Self::South),
+        ("Sud", Self::South),
+        ("Eas", Self::East),
+        ("Wes", Self::West),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+    #[must_use]
+    ///Parses a [`Direction`] variant out of the string or abbreviated string representation (or non-deprecated alias) most similar to the given value, if their similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to `1.0` (i.e. strings equal regardless of their ASCII case).
+    pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+        Self::PARSE_CANDIDATES_ABBR
+            .into_iter()
+            .map(|(candidate, variant)| {
+                let distance = Self::parse_edit_distance(value, candidate);
+                let len = ::std::cmp::max(
+                    value.chars().count(),
+                    candidate.chars().count(),
+                );
+                let similarity = match len {
+                    0 => 1.0,
+                    len => 1.0 - distance as f64 / len as f64,
+                };
+                (variant, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+            .map(|(variant, _)| variant)
+    }
+}
+pub struct ParseDirectionError {
+    input: ::std::string::String,
+    suggestion: ::std::option::Option<&'static str>,
+    expected: &'static str,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ParseDirectionError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "ParseDirectionError",
+            "input",
+            &self.input,
+            "suggestion",
+            &self.suggestion,
+            "expected",
+            &&self.expected,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for ParseDirectionError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ParseDirectionError {
+    #[inline]
+    fn eq(&self, other: &ParseDirectionError) -> bool {
+        self.input == other.input && self.suggestion == other.suggestion
+            && self.expected == other.expected
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ParseDirectionError {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+        let _: ::core::cmp::AssertParamIsEq<::std::option::Option<&'static str>>;
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+impl ParseDirectionError {
+    /// Returns the input that failed to be parsed, possibly truncated.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Returns the closest string or abbreviated string representation (or
+    /// alias) to the input that failed to be parsed, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+        self.suggestion
+    }
+}
+impl ::std::fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_fmt(format_args!("unknown {0} `{1}`", "direction", self.input))?;
+        if let ::std::option::Option::Some(suggestion) = self.suggestion {
+            return f.write_fmt(format_args!(", did you mean `{0}`?", suggestion));
+        }
+        f.write_fmt(format_args!(", expected {0}", self.expected))
+    }
+}
+impl ::std::error::Error for ParseDirectionError {}
+#[automatically_derived]
+impl Direction {
+    ///Parses a [`Direction`] variant out of its string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_full(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "North" => ::std::result::Result::Ok(Self::North),
+            "South" | "Sud" => ::std::result::Result::Ok(Self::South),
+            "East" => ::std::result::Result::Ok(Self::East),
+            "West" => ::std::result::Result::Ok(Self::West),
+            _ => {
+                let error = ParseDirectionError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_FULL,
+                    ),
+                    expected: "one of \"North\", \"South\", \"East\", \"West\" (aliases: \"Sud\")",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+    ///Parses a [`Direction`] variant out of its abbreviated string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_abbr(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Nor" => ::std::result::Result::Ok(Self::North),
+            "Sou" | "Sud" => ::std::result::Result::Ok(Self::South),
+            "Eas" => ::std::result::Result::Ok(Self::East),
+            "Wes" => ::std::result::Result::Ok(Self::West),
+            _ => {
+                let error = ParseDirectionError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_ABBR,
+                    ),
+                    expected: "one of \"Nor\", \"Sou\", \"Eas\", \"Wes\" (aliases: \"Sud\")",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
+impl ::std::str::FromStr for Direction {
+    type Err = ParseDirectionError;
+    ///Parses a [`Direction`] variant out of its string representations (or aliases), with no variant excluded from parsing.
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        match value {
+            "Nor" => ::std::result::Result::Ok(Self::North),
+            "Sou" | "Sud" => ::std::result::Result::Ok(Self::South),
+            "Eas" => ::std::result::Result::Ok(Self::East),
+            "Wes" => ::std::result::Result::Ok(Self::West),
+            _ => {
+                let error = ParseDirectionError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_ABBR,
+                    ),
+                    expected: "one of \"Nor\", \"Sou\", \"Eas\", \"Wes\" (aliases: \"Sud\")",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
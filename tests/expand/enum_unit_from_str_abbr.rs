@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str(abbr))]
+pub enum Direction {
+    North,
+    #[variants(alias = "Sud")]
+    South,
+    East,
+    West,
+}
@@ -0,0 +1,14 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str(case_insensitive, trim))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    #[variants(alias = "THURS")]
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
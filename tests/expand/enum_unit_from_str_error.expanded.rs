@@ -0,0 +1,793 @@
+use beerec_variants::Variants;
+pub struct InvalidInput(pub String);
+impl From<UnknownCurrency> for InvalidInput {
+    fn from(error: UnknownCurrency) -> Self {
+        Self(error.to_string())
+    }
+}
+#[variants(
+    from_str(
+        error = "UnknownCurrency",
+        error_vis = "pub(crate)",
+        error_derive(Clone, Hash),
+        error_type = "InvalidInput",
+        input_limit = 16,
+    )
+)]
+pub enum Currency {
+    Euro,
+    Dollar,
+    Pound,
+}
+impl ::std::marker::Copy for Currency {}
+impl ::std::clone::Clone for Currency {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Currency {
+    ///The array of _iterable_ (i.e. non-skipped) [`Currency`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [Self::Euro, Self::Dollar, Self::Pound];
+    ///The number of _iterable_ (i.e. non-skipped) [`Currency`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Currency`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Currency`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Currency`] variant, in declaration order.
+    pub const FIRST: Self = Self::Euro;
+    ///The last _iterable_ (i.e. non-skipped) [`Currency`] variant, in declaration order.
+    pub const LAST: Self = Self::Pound;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Currency`] variant following this one in [`Currency::ALL`], wrapping around to [`Currency::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Currency`] variant preceding this one in [`Currency::ALL`], wrapping around to [`Currency::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Currency`] variant following this one in [`Currency::ALL`], stopping at [`Currency::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Currency`] variant preceding this one in [`Currency::ALL`], stopping at [`Currency::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Currency`] variant `n` positions away from this one in [`Currency::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Currency`] variant to `other` in [`Currency::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Currency`] variant in [`Currency::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Euro => 0usize,
+            Self::Dollar => 1usize,
+            Self::Pound => 2usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Currency`] variant at the given position in [`Currency::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Euro),
+            1usize => ::std::option::Option::Some(Self::Dollar),
+            2usize => ::std::option::Option::Some(Self::Pound),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Currency`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Euro => "Euro",
+            Self::Dollar => "Dollar",
+            Self::Pound => "Pound",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Currency`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Euro => "Eur",
+            Self::Dollar => "Dol",
+            Self::Pound => "Pou",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Currency`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> CurrencyIter {
+        CurrencyIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Currency`] variants of [`Currency::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> CurrencyIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        CurrencyIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Currency`] variants of [`Currency::ALL`] within the given range, wrapping around to [`Currency::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> CurrencyIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        CurrencyIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Currency`]
+variants.
+
+See [`Currency::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> CurrencyAsStrIter {
+        CurrencyAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Currency`] variants.
+
+See [`Currency::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> CurrencyAsStrAbbrIter {
+        CurrencyAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Currency`]
+variants.
+
+See [`Currency::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Euro\", \"Dollar\", \"Pound\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Currency`]
+variants.
+
+See [`Currency::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Eur\", \"Dol\", \"Pou\""
+    }
+}
+#[automatically_derived]
+impl Currency {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Currency`] value is the [`Currency::Euro`] variant.
+    pub const fn is_euro(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Euro => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Currency`] value is the [`Currency::Dollar`] variant.
+    pub const fn is_dollar(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Dollar => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Currency`] value is the [`Currency::Pound`] variant.
+    pub const fn is_pound(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Pound => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Currency`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Euro, Self::Euro)
+            | (Self::Dollar, Self::Dollar)
+            | (Self::Pound, Self::Pound) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Currency`] variants, returned by [`Currency::iter_variants`].
+pub struct CurrencyIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for CurrencyIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "CurrencyIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CurrencyIter {
+    #[inline]
+    fn clone(&self) -> CurrencyIter {
+        CurrencyIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for CurrencyIter {
+    type Item = Currency;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Currency::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Currency::from_index(
+            if index < Currency::COUNT { index } else { index - Currency::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for CurrencyIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Currency::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Currency::from_index(
+            if self.back < Currency::COUNT {
+                self.back
+            } else {
+                self.back - Currency::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for CurrencyIter {}
+impl ::std::iter::FusedIterator for CurrencyIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Currency`] variants, returned by [`Currency::iter_variants_as_str`].
+pub struct CurrencyAsStrIter(CurrencyIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for CurrencyAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "CurrencyAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CurrencyAsStrIter {
+    #[inline]
+    fn clone(&self) -> CurrencyAsStrIter {
+        CurrencyAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for CurrencyAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Currency::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Currency::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for CurrencyAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Currency::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Currency::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for CurrencyAsStrIter {}
+impl ::std::iter::FusedIterator for CurrencyAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Currency`] variants, returned by [`Currency::iter_variants_as_str_abbr`].
+pub struct CurrencyAsStrAbbrIter(CurrencyIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for CurrencyAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "CurrencyAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CurrencyAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> CurrencyAsStrAbbrIter {
+        CurrencyAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for CurrencyAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Currency::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Currency::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for CurrencyAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Currency::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Currency::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for CurrencyAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for CurrencyAsStrAbbrIter {}
+#[automatically_derived]
+impl Currency {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_FULL: [(&'static str, Self); 3usize] = [
+        ("Euro", Self::Euro),
+        ("Dollar", Self::Dollar),
+        ("Pound", Self::Pound),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_ABBR: [(&'static str, Self); 3usize] = [
+        ("Eur", Self::Euro),
+        ("Dol", Self::Dollar),
+        ("Pou", Self::Pound),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES: [(&'static str, Self); 6usize] = [
+        ("Euro", Self::Euro),
+        ("Eur", Self::Euro),
+        ("Dollar", Self::Dollar),
+        ("Dol", Self::Dollar),
+        ("Pound", Self::Pound),
+        ("Pou", Self::Pound),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+    #[must_use]
+    ///Parses a [`Currency`] variant out of the string or abbreviated string representation (or non-deprecated alias) most similar to the given value, if their similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to `1.0` (i.e. strings equal regardless of their ASCII case).
+    pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+        Self::PARSE_CANDIDATES
+            .into_iter()
+            .map(|(candidate, variant)| {
+                let distance = Self::parse_edit_distance(value, candidate);
+                let len = ::std::cmp::max(
+                    value.chars().count(),
+                    candidate.chars().count(),
+                );
+                let similarity = match len {
+                    0 => 1.0,
+                    len => 1.0 - distance as f64 / len as f64,
+                };
+                (variant, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+            .map(|(variant, _)| variant)
+    }
+}
+pub(crate) struct UnknownCurrency {
+    input: ::std::string::String,
+    suggestion: ::std::option::Option<&'static str>,
+    expected: &'static str,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for UnknownCurrency {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "UnknownCurrency",
+            "input",
+            &self.input,
+            "suggestion",
+            &self.suggestion,
+            "expected",
+            &&self.expected,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for UnknownCurrency {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for UnknownCurrency {
+    #[inline]
+    fn eq(&self, other: &UnknownCurrency) -> bool {
+        self.input == other.input && self.suggestion == other.suggestion
+            && self.expected == other.expected
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for UnknownCurrency {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+        let _: ::core::cmp::AssertParamIsEq<::std::option::Option<&'static str>>;
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for UnknownCurrency {
+    #[inline]
+    fn clone(&self) -> UnknownCurrency {
+        UnknownCurrency {
+            input: ::core::clone::Clone::clone(&self.input),
+            suggestion: ::core::clone::Clone::clone(&self.suggestion),
+            expected: ::core::clone::Clone::clone(&self.expected),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::hash::Hash for UnknownCurrency {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.input, state);
+        ::core::hash::Hash::hash(&self.suggestion, state);
+        ::core::hash::Hash::hash(&self.expected, state)
+    }
+}
+impl UnknownCurrency {
+    /// Returns the input that failed to be parsed, possibly truncated.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Returns the closest string or abbreviated string representation (or
+    /// alias) to the input that failed to be parsed, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+        self.suggestion
+    }
+}
+impl ::std::fmt::Display for UnknownCurrency {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_fmt(format_args!("unknown {0} `{1}`", "currency", self.input))?;
+        if let ::std::option::Option::Some(suggestion) = self.suggestion {
+            return f.write_fmt(format_args!(", did you mean `{0}`?", suggestion));
+        }
+        f.write_fmt(format_args!(", expected {0}", self.expected))
+    }
+}
+impl ::std::error::Error for UnknownCurrency {}
+#[automatically_derived]
+impl Currency {
+    ///Parses a [`Currency`] variant out of its string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_full(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Euro" => ::std::result::Result::Ok(Self::Euro),
+            "Dollar" => ::std::result::Result::Ok(Self::Dollar),
+            "Pound" => ::std::result::Result::Ok(Self::Pound),
+            _ => {
+                let error = UnknownCurrency {
+                    input: match value.char_indices().nth(16usize) {
+                        ::std::option::Option::Some((end, _)) => {
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(format_args!("{0}…", & value[..end]))
+                            })
+                        }
+                        ::std::option::Option::None => {
+                            ::std::string::ToString::to_string(value)
+                        }
+                    },
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_FULL,
+                    ),
+                    expected: "one of \"Euro\", \"Dollar\", \"Pound\"",
+                };
+                ::std::result::Result::Err(
+                    <InvalidInput as ::std::convert::From<UnknownCurrency>>::from(error),
+                )
+            }
+        }
+    }
+    ///Parses a [`Currency`] variant out of its abbreviated string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_abbr(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Eur" => ::std::result::Result::Ok(Self::Euro),
+            "Dol" => ::std::result::Result::Ok(Self::Dollar),
+            "Pou" => ::std::result::Result::Ok(Self::Pound),
+            _ => {
+                let error = UnknownCurrency {
+                    input: match value.char_indices().nth(16usize) {
+                        ::std::option::Option::Some((end, _)) => {
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(format_args!("{0}…", & value[..end]))
+                            })
+                        }
+                        ::std::option::Option::None => {
+                            ::std::string::ToString::to_string(value)
+                        }
+                    },
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_ABBR,
+                    ),
+                    expected: "one of \"Eur\", \"Dol\", \"Pou\"",
+                };
+                ::std::result::Result::Err(
+                    <InvalidInput as ::std::convert::From<UnknownCurrency>>::from(error),
+                )
+            }
+        }
+    }
+}
+impl ::std::str::FromStr for Currency {
+    type Err = InvalidInput;
+    ///Parses a [`Currency`] variant out of its string representations (or aliases), with no variant excluded from parsing.
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        match value {
+            "Euro" | "Eur" => ::std::result::Result::Ok(Self::Euro),
+            "Dollar" | "Dol" => ::std::result::Result::Ok(Self::Dollar),
+            "Pound" | "Pou" => ::std::result::Result::Ok(Self::Pound),
+            _ => {
+                let error = UnknownCurrency {
+                    input: match value.char_indices().nth(16usize) {
+                        ::std::option::Option::Some((end, _)) => {
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(format_args!("{0}…", & value[..end]))
+                            })
+                        }
+                        ::std::option::Option::None => {
+                            ::std::string::ToString::to_string(value)
+                        }
+                    },
+                    suggestion: Self::parse_suggestion(value, Self::PARSE_CANDIDATES),
+                    expected: "one of \"Euro\", \"Dollar\", \"Pound\" or one of \"Eur\", \"Dol\", \"Pou\"",
+                };
+                ::std::result::Result::Err(
+                    <InvalidInput as ::std::convert::From<UnknownCurrency>>::from(error),
+                )
+            }
+        }
+    }
+}
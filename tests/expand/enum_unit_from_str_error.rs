@@ -0,0 +1,23 @@
+use beerec_variants::Variants;
+
+pub struct InvalidInput(pub String);
+
+impl From<UnknownCurrency> for InvalidInput {
+    fn from(error: UnknownCurrency) -> Self {
+        Self(error.to_string())
+    }
+}
+
+#[derive(Variants)]
+#[variants(from_str(
+    error = "UnknownCurrency",
+    error_vis = "pub(crate)",
+    error_derive(Clone, Hash),
+    error_type = "InvalidInput",
+    input_limit = 16,
+))]
+pub enum Currency {
+    Euro,
+    Dollar,
+    Pound,
+}
@@ -0,0 +1,655 @@
+use beerec_variants::Variants;
+pub enum Weekday {
+    #[variants(group = "workday")]
+    Monday,
+    #[variants(group = "workday", group = "midweek")]
+    Wednesday,
+    #[variants(group = "workday")]
+    Friday,
+    #[variants(group = "weekend")]
+    Saturday,
+    #[variants(group = "weekend", skip)]
+    Sunday,
+}
+impl ::std::marker::Copy for Weekday {}
+impl ::std::clone::Clone for Weekday {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Weekday {
+    ///The array of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+    const ITERABLE_VARIANTS: [Self; 4usize] = [
+        Self::Monday,
+        Self::Wednesday,
+        Self::Friday,
+        Self::Saturday,
+    ];
+    ///The number of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 4usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Weekday`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Weekday`] variant, in declaration order.
+    pub const FIRST: Self = Self::Monday;
+    ///The last _iterable_ (i.e. non-skipped) [`Weekday`] variant, in declaration order.
+    pub const LAST: Self = Self::Saturday;
+    #[inline]
+    #[must_use]
+    /**Returns the [`Weekday`] variant following this one in [`Weekday::ALL`], wrapping around to [`Weekday::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Weekday`] variant preceding this one in [`Weekday::ALL`], wrapping around to [`Weekday::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Weekday`] variant following this one in [`Weekday::ALL`], stopping at [`Weekday::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Weekday`] variant preceding this one in [`Weekday::ALL`], stopping at [`Weekday::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Weekday`] variant `n` positions away from this one in [`Weekday::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`Weekday`] variant to `other` in [`Weekday::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`Weekday`] variant in [`Weekday::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Monday => ::std::option::Option::Some(0usize),
+            Self::Wednesday => ::std::option::Option::Some(1usize),
+            Self::Friday => ::std::option::Option::Some(2usize),
+            Self::Saturday => ::std::option::Option::Some(3usize),
+            Self::Sunday => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Weekday`] variant at the given position in [`Weekday::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Monday),
+            1usize => ::std::option::Option::Some(Self::Wednesday),
+            2usize => ::std::option::Option::Some(Self::Friday),
+            3usize => ::std::option::Option::Some(Self::Saturday),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Weekday`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Monday => "Monday",
+            Self::Wednesday => "Wednesday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Sunday => "Sunday",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Weekday`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Monday => "Mon",
+            Self::Wednesday => "Wed",
+            Self::Friday => "Fri",
+            Self::Saturday => "Sat",
+            Self::Sunday => "Sun",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Weekday`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Weekday::Sunday`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> WeekdayIter {
+        WeekdayIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`Weekday`] variants of [`Weekday::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> WeekdayIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return WeekdayIter { front: 0, back: 0 };
+        };
+        WeekdayIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`Weekday`] variants of [`Weekday::ALL`] within the given range, wrapping around to [`Weekday::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> WeekdayIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return WeekdayIter { front: 0, back: 0 };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        WeekdayIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Weekday`]
+variants.
+
+See [`Weekday::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Weekday::Sunday`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> WeekdayAsStrIter {
+        WeekdayAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Weekday`] variants.
+
+See [`Weekday::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Weekday::Sunday`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> WeekdayAsStrAbbrIter {
+        WeekdayAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Weekday`]
+variants.
+
+See [`Weekday::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Weekday::Sunday`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Monday\", \"Wednesday\", \"Friday\", \"Saturday\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Weekday`]
+variants.
+
+See [`Weekday::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Weekday::Sunday`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Mon\", \"Wed\", \"Fri\", \"Sat\""
+    }
+}
+#[automatically_derived]
+impl Weekday {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Monday`] variant.
+    pub const fn is_monday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Monday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Wednesday`] variant.
+    pub const fn is_wednesday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Wednesday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Friday`] variant.
+    pub const fn is_friday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Friday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Saturday`] variant.
+    pub const fn is_saturday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Saturday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] value is the [`Weekday::Sunday`] variant.
+    pub const fn is_sunday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Sunday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Weekday`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Monday, Self::Monday)
+            | (Self::Wednesday, Self::Wednesday)
+            | (Self::Friday, Self::Friday)
+            | (Self::Saturday, Self::Saturday)
+            | (Self::Sunday, Self::Sunday) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Weekday`] variants, returned by [`Weekday::iter_variants`].
+pub struct WeekdayIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for WeekdayIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "WeekdayIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for WeekdayIter {
+    #[inline]
+    fn clone(&self) -> WeekdayIter {
+        WeekdayIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for WeekdayIter {
+    type Item = Weekday;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Weekday::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Weekday::from_index(
+            if index < Weekday::COUNT { index } else { index - Weekday::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for WeekdayIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Weekday::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Weekday::from_index(
+            if self.back < Weekday::COUNT {
+                self.back
+            } else {
+                self.back - Weekday::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for WeekdayIter {}
+impl ::std::iter::FusedIterator for WeekdayIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Weekday`] variants, returned by [`Weekday::iter_variants_as_str`].
+pub struct WeekdayAsStrIter(WeekdayIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for WeekdayAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "WeekdayAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for WeekdayAsStrIter {
+    #[inline]
+    fn clone(&self) -> WeekdayAsStrIter {
+        WeekdayAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for WeekdayAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Weekday::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Weekday::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for WeekdayAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Weekday::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Weekday::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for WeekdayAsStrIter {}
+impl ::std::iter::FusedIterator for WeekdayAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Weekday`] variants, returned by [`Weekday::iter_variants_as_str_abbr`].
+pub struct WeekdayAsStrAbbrIter(WeekdayIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for WeekdayAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "WeekdayAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for WeekdayAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> WeekdayAsStrAbbrIter {
+        WeekdayAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for WeekdayAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Weekday::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Weekday::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for WeekdayAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Weekday::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Weekday::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for WeekdayAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for WeekdayAsStrAbbrIter {}
+#[automatically_derived]
+impl Weekday {
+    ///The array of _iterable_ (i.e. non-skipped) [`Weekday`] variants of the `workday` group.
+    pub const WORKDAY: [Self; 3usize] = [Self::Monday, Self::Wednesday, Self::Friday];
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] variant belongs to the `workday` group, skipped ones included.
+    pub const fn is_workday(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Monday | Self::Wednesday | Self::Friday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Iterates over _iterable_ (i.e. non-skipped) [`Weekday`] variants of the `workday` group.
+    pub fn iter_workday() -> ::std::array::IntoIter<Self, 3usize> {
+        Self::WORKDAY.into_iter()
+    }
+}
+#[automatically_derived]
+impl Weekday {
+    ///The array of _iterable_ (i.e. non-skipped) [`Weekday`] variants of the `midweek` group.
+    pub const MIDWEEK: [Self; 1usize] = [Self::Wednesday];
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] variant belongs to the `midweek` group, skipped ones included.
+    pub const fn is_midweek(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Wednesday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Iterates over _iterable_ (i.e. non-skipped) [`Weekday`] variants of the `midweek` group.
+    pub fn iter_midweek() -> ::std::array::IntoIter<Self, 1usize> {
+        Self::MIDWEEK.into_iter()
+    }
+}
+#[automatically_derived]
+impl Weekday {
+    ///The array of _iterable_ (i.e. non-skipped) [`Weekday`] variants of the `weekend` group.
+    pub const WEEKEND: [Self; 1usize] = [Self::Saturday];
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Weekday`] variant belongs to the `weekend` group, skipped ones included.
+    pub const fn is_weekend(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Saturday | Self::Sunday => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Iterates over _iterable_ (i.e. non-skipped) [`Weekday`] variants of the `weekend` group.
+    pub fn iter_weekend() -> ::std::array::IntoIter<Self, 1usize> {
+        Self::WEEKEND.into_iter()
+    }
+}
+fn main() {}
@@ -0,0 +1,17 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Weekday {
+    #[variants(group = "workday")]
+    Monday,
+    #[variants(group = "workday", group = "midweek")]
+    Wednesday,
+    #[variants(group = "workday")]
+    Friday,
+    #[variants(group = "weekend")]
+    Saturday,
+    #[variants(group = "weekend", skip)]
+    Sunday,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(map)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+}
+
+fn main() {}
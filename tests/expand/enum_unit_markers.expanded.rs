@@ -0,0 +1,725 @@
+use beerec_variants::Variants;
+#[variants(markers)]
+pub enum DoorState {
+    Open,
+    Closed,
+    #[variants(skip)]
+    Broken,
+}
+impl ::std::marker::Copy for DoorState {}
+impl ::std::clone::Clone for DoorState {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl DoorState {
+    ///The array of _iterable_ (i.e. non-skipped) [`DoorState`] variants.
+    const ITERABLE_VARIANTS: [Self; 2usize] = [Self::Open, Self::Closed];
+    ///The number of _iterable_ (i.e. non-skipped) [`DoorState`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 2usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`DoorState`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`DoorState`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`DoorState`] variant, in declaration order.
+    pub const FIRST: Self = Self::Open;
+    ///The last _iterable_ (i.e. non-skipped) [`DoorState`] variant, in declaration order.
+    pub const LAST: Self = Self::Closed;
+    #[inline]
+    #[must_use]
+    /**Returns the [`DoorState`] variant following this one in [`DoorState::ALL`], wrapping around to [`DoorState::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`DoorState`] variant preceding this one in [`DoorState::ALL`], wrapping around to [`DoorState::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`DoorState`] variant following this one in [`DoorState::ALL`], stopping at [`DoorState::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`DoorState`] variant preceding this one in [`DoorState::ALL`], stopping at [`DoorState::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`DoorState`] variant `n` positions away from this one in [`DoorState::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`DoorState`] variant to `other` in [`DoorState::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`DoorState`] variant in [`DoorState::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Open => ::std::option::Option::Some(0usize),
+            Self::Closed => ::std::option::Option::Some(1usize),
+            Self::Broken => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`DoorState`] variant at the given position in [`DoorState::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Open),
+            1usize => ::std::option::Option::Some(Self::Closed),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`DoorState`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::Closed => "Closed",
+            Self::Broken => "Broken",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`DoorState`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Open => "Ope",
+            Self::Closed => "Clo",
+            Self::Broken => "Bro",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`DoorState`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`DoorState::Broken`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> DoorStateIter {
+        DoorStateIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`DoorState`] variants of [`DoorState::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> DoorStateIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return DoorStateIter { front: 0, back: 0 };
+        };
+        DoorStateIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`DoorState`] variants of [`DoorState::ALL`] within the given range, wrapping around to [`DoorState::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> DoorStateIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return DoorStateIter { front: 0, back: 0 };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        DoorStateIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`DoorState`]
+variants.
+
+See [`DoorState::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`DoorState::Broken`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> DoorStateAsStrIter {
+        DoorStateAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`DoorState`] variants.
+
+See [`DoorState::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`DoorState::Broken`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> DoorStateAsStrAbbrIter {
+        DoorStateAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`DoorState`]
+variants.
+
+See [`DoorState::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`DoorState::Broken`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Open\", \"Closed\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`DoorState`]
+variants.
+
+See [`DoorState::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`DoorState::Broken`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Ope\", \"Clo\""
+    }
+}
+#[automatically_derived]
+impl DoorState {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`DoorState`] value is the [`DoorState::Open`] variant.
+    pub const fn is_open(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Open => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`DoorState`] value is the [`DoorState::Closed`] variant.
+    pub const fn is_closed(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Closed => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`DoorState`] value is the [`DoorState::Broken`] variant.
+    pub const fn is_broken(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Broken => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`DoorState`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Open, Self::Open)
+            | (Self::Closed, Self::Closed)
+            | (Self::Broken, Self::Broken) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`DoorState`] variants, returned by [`DoorState::iter_variants`].
+pub struct DoorStateIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for DoorStateIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "DoorStateIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DoorStateIter {
+    #[inline]
+    fn clone(&self) -> DoorStateIter {
+        DoorStateIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for DoorStateIter {
+    type Item = DoorState;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if DoorState::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        DoorState::from_index(
+            if index < DoorState::COUNT { index } else { index - DoorState::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DoorStateIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if DoorState::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        DoorState::from_index(
+            if self.back < DoorState::COUNT {
+                self.back
+            } else {
+                self.back - DoorState::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for DoorStateIter {}
+impl ::std::iter::FusedIterator for DoorStateIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`DoorState`] variants, returned by [`DoorState::iter_variants_as_str`].
+pub struct DoorStateAsStrIter(DoorStateIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for DoorStateAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "DoorStateAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DoorStateAsStrIter {
+    #[inline]
+    fn clone(&self) -> DoorStateAsStrIter {
+        DoorStateAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for DoorStateAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(DoorState::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(DoorState::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DoorStateAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(DoorState::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(DoorState::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for DoorStateAsStrIter {}
+impl ::std::iter::FusedIterator for DoorStateAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`DoorState`] variants, returned by [`DoorState::iter_variants_as_str_abbr`].
+pub struct DoorStateAsStrAbbrIter(DoorStateIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for DoorStateAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "DoorStateAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for DoorStateAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> DoorStateAsStrAbbrIter {
+        DoorStateAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for DoorStateAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(DoorState::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(DoorState::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for DoorStateAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(DoorState::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(DoorState::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for DoorStateAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for DoorStateAsStrAbbrIter {}
+///A type-level [`DoorState`] variant, implemented by the types of [`door_state`].
+pub trait DoorStateVariant {
+    /// The variant represented by the marker type.
+    const VALUE: DoorState;
+    /// The string representation of the variant represented by the marker type.
+    const NAME: &'static str;
+}
+/**Type-level marker types of the [`DoorState`](super::DoorState) variants.
+
+# Notes
+
+This module is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(markers)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub mod door_state {
+    ///Type-level marker of the [`DoorState::Open`](super::DoorState::Open) variant.
+    pub struct Open;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Open {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Open")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Open {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Open {
+        #[inline]
+        fn clone(&self) -> Open {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Open {}
+    #[automatically_derived]
+    impl ::core::default::Default for Open {
+        #[inline]
+        fn default() -> Open {
+            Open {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Open {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Open {
+        #[inline]
+        fn eq(&self, other: &Open) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Open {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Open {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::DoorStateVariant for Open {
+        const VALUE: super::DoorState = super::DoorState::Open;
+        const NAME: &'static str = super::DoorState::Open.as_str();
+    }
+    ///Type-level marker of the [`DoorState::Closed`](super::DoorState::Closed) variant.
+    pub struct Closed;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Closed {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Closed")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Closed {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Closed {
+        #[inline]
+        fn clone(&self) -> Closed {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Closed {}
+    #[automatically_derived]
+    impl ::core::default::Default for Closed {
+        #[inline]
+        fn default() -> Closed {
+            Closed {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Closed {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Closed {
+        #[inline]
+        fn eq(&self, other: &Closed) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Closed {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Closed {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::DoorStateVariant for Closed {
+        const VALUE: super::DoorState = super::DoorState::Closed;
+        const NAME: &'static str = super::DoorState::Closed.as_str();
+    }
+    ///Type-level marker of the [`DoorState::Broken`](super::DoorState::Broken) variant.
+    pub struct Broken;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Broken {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Broken")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Broken {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Broken {
+        #[inline]
+        fn clone(&self) -> Broken {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Broken {}
+    #[automatically_derived]
+    impl ::core::default::Default for Broken {
+        #[inline]
+        fn default() -> Broken {
+            Broken {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Broken {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Broken {
+        #[inline]
+        fn eq(&self, other: &Broken) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Broken {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Broken {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::DoorStateVariant for Broken {
+        const VALUE: super::DoorState = super::DoorState::Broken;
+        const NAME: &'static str = super::DoorState::Broken.as_str();
+    }
+}
+fn main() {}
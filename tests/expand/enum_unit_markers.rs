@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(markers)]
+pub enum DoorState {
+    Open,
+    Closed,
+    #[variants(skip)]
+    Broken,
+}
+
+fn main() {}
@@ -0,0 +1,1663 @@
+use beerec_variants::Variants;
+#[variants(markers)]
+pub enum Crate {
+    Local,
+    Remote,
+}
+impl ::std::marker::Copy for Crate {}
+impl ::std::clone::Clone for Crate {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Crate {
+    ///The array of _iterable_ (i.e. non-skipped) [`Crate`] variants.
+    const ITERABLE_VARIANTS: [Self; 2usize] = [Self::Local, Self::Remote];
+    ///The number of _iterable_ (i.e. non-skipped) [`Crate`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 2usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Crate`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Crate`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Crate`] variant, in declaration order.
+    pub const FIRST: Self = Self::Local;
+    ///The last _iterable_ (i.e. non-skipped) [`Crate`] variant, in declaration order.
+    pub const LAST: Self = Self::Remote;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Crate`] variant following this one in [`Crate::ALL`], wrapping around to [`Crate::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Crate`] variant preceding this one in [`Crate::ALL`], wrapping around to [`Crate::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Crate`] variant following this one in [`Crate::ALL`], stopping at [`Crate::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Crate`] variant preceding this one in [`Crate::ALL`], stopping at [`Crate::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Crate`] variant `n` positions away from this one in [`Crate::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Crate`] variant to `other` in [`Crate::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Crate`] variant in [`Crate::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Local => 0usize,
+            Self::Remote => 1usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Crate`] variant at the given position in [`Crate::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Local),
+            1usize => ::std::option::Option::Some(Self::Remote),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Crate`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Local => "Local",
+            Self::Remote => "Remote",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Crate`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Local => "Loc",
+            Self::Remote => "Rem",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Crate`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> CrateIter {
+        CrateIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Crate`] variants of [`Crate::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> CrateIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        CrateIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Crate`] variants of [`Crate::ALL`] within the given range, wrapping around to [`Crate::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> CrateIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        CrateIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Crate`]
+variants.
+
+See [`Crate::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> CrateAsStrIter {
+        CrateAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Crate`] variants.
+
+See [`Crate::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> CrateAsStrAbbrIter {
+        CrateAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Crate`]
+variants.
+
+See [`Crate::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Local\", \"Remote\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Crate`]
+variants.
+
+See [`Crate::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Loc\", \"Rem\""
+    }
+}
+#[automatically_derived]
+impl Crate {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Crate`] value is the [`Crate::Local`] variant.
+    pub const fn is_local(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Local => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Crate`] value is the [`Crate::Remote`] variant.
+    pub const fn is_remote(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Remote => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Crate`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Local, Self::Local) | (Self::Remote, Self::Remote) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Crate`] variants, returned by [`Crate::iter_variants`].
+pub struct CrateIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for CrateIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "CrateIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CrateIter {
+    #[inline]
+    fn clone(&self) -> CrateIter {
+        CrateIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for CrateIter {
+    type Item = Crate;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Crate::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Crate::from_index(
+            if index < Crate::COUNT { index } else { index - Crate::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for CrateIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Crate::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Crate::from_index(
+            if self.back < Crate::COUNT { self.back } else { self.back - Crate::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for CrateIter {}
+impl ::std::iter::FusedIterator for CrateIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Crate`] variants, returned by [`Crate::iter_variants_as_str`].
+pub struct CrateAsStrIter(CrateIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for CrateAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "CrateAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CrateAsStrIter {
+    #[inline]
+    fn clone(&self) -> CrateAsStrIter {
+        CrateAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for CrateAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Crate::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Crate::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for CrateAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Crate::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Crate::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for CrateAsStrIter {}
+impl ::std::iter::FusedIterator for CrateAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Crate`] variants, returned by [`Crate::iter_variants_as_str_abbr`].
+pub struct CrateAsStrAbbrIter(CrateIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for CrateAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "CrateAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CrateAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> CrateAsStrAbbrIter {
+        CrateAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for CrateAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Crate::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Crate::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for CrateAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Crate::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Crate::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for CrateAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for CrateAsStrAbbrIter {}
+///A type-level [`Crate`] variant, implemented by the types of [`crate_markers`].
+pub trait CrateVariant {
+    /// The variant represented by the marker type.
+    const VALUE: Crate;
+    /// The string representation of the variant represented by the marker type.
+    const NAME: &'static str;
+}
+/**Type-level marker types of the [`Crate`](super::Crate) variants.
+
+# Notes
+
+This module is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(markers)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub mod crate_markers {
+    ///Type-level marker of the [`Crate::Local`](super::Crate::Local) variant.
+    pub struct Local;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Local {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Local")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Local {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Local {
+        #[inline]
+        fn clone(&self) -> Local {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Local {}
+    #[automatically_derived]
+    impl ::core::default::Default for Local {
+        #[inline]
+        fn default() -> Local {
+            Local {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Local {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Local {
+        #[inline]
+        fn eq(&self, other: &Local) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Local {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Local {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::CrateVariant for Local {
+        const VALUE: super::Crate = super::Crate::Local;
+        const NAME: &'static str = super::Crate::Local.as_str();
+    }
+    ///Type-level marker of the [`Crate::Remote`](super::Crate::Remote) variant.
+    pub struct Remote;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Remote {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Remote")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Remote {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Remote {
+        #[inline]
+        fn clone(&self) -> Remote {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Remote {}
+    #[automatically_derived]
+    impl ::core::default::Default for Remote {
+        #[inline]
+        fn default() -> Remote {
+            Remote {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Remote {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Remote {
+        #[inline]
+        fn eq(&self, other: &Remote) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Remote {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Remote {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::CrateVariant for Remote {
+        const VALUE: super::Crate = super::Crate::Remote;
+        const NAME: &'static str = super::Crate::Remote.as_str();
+    }
+}
+#[variants(markers)]
+pub enum Super {
+    Admin,
+}
+impl ::std::marker::Copy for Super {}
+impl ::std::clone::Clone for Super {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Super {
+    ///The array of _iterable_ (i.e. non-skipped) [`Super`] variants.
+    const ITERABLE_VARIANTS: [Self; 1usize] = [Self::Admin];
+    ///The number of _iterable_ (i.e. non-skipped) [`Super`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 1usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Super`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Super`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Super`] variant, in declaration order.
+    pub const FIRST: Self = Self::Admin;
+    ///The last _iterable_ (i.e. non-skipped) [`Super`] variant, in declaration order.
+    pub const LAST: Self = Self::Admin;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Super`] variant following this one in [`Super::ALL`], wrapping around to [`Super::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Super`] variant preceding this one in [`Super::ALL`], wrapping around to [`Super::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Super`] variant following this one in [`Super::ALL`], stopping at [`Super::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Super`] variant preceding this one in [`Super::ALL`], stopping at [`Super::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Super`] variant `n` positions away from this one in [`Super::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Super`] variant to `other` in [`Super::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Super`] variant in [`Super::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Admin => 0usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Super`] variant at the given position in [`Super::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Admin),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Super`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Admin => "Admin",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Super`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Admin => "Adm",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Super`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> SuperIter {
+        SuperIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Super`] variants of [`Super::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> SuperIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        SuperIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Super`] variants of [`Super::ALL`] within the given range, wrapping around to [`Super::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> SuperIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        SuperIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Super`]
+variants.
+
+See [`Super::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> SuperAsStrIter {
+        SuperAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Super`] variants.
+
+See [`Super::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> SuperAsStrAbbrIter {
+        SuperAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Super`]
+variants.
+
+See [`Super::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Admin\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Super`]
+variants.
+
+See [`Super::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Adm\""
+    }
+}
+#[automatically_derived]
+impl Super {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Super`] value is the [`Super::Admin`] variant.
+    pub const fn is_admin(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Admin => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Super`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Admin, Self::Admin) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Super`] variants, returned by [`Super::iter_variants`].
+pub struct SuperIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for SuperIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "SuperIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for SuperIter {
+    #[inline]
+    fn clone(&self) -> SuperIter {
+        SuperIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for SuperIter {
+    type Item = Super;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Super::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Super::from_index(
+            if index < Super::COUNT { index } else { index - Super::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for SuperIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Super::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Super::from_index(
+            if self.back < Super::COUNT { self.back } else { self.back - Super::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for SuperIter {}
+impl ::std::iter::FusedIterator for SuperIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Super`] variants, returned by [`Super::iter_variants_as_str`].
+pub struct SuperAsStrIter(SuperIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for SuperAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "SuperAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for SuperAsStrIter {
+    #[inline]
+    fn clone(&self) -> SuperAsStrIter {
+        SuperAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for SuperAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Super::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Super::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for SuperAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Super::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Super::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for SuperAsStrIter {}
+impl ::std::iter::FusedIterator for SuperAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Super`] variants, returned by [`Super::iter_variants_as_str_abbr`].
+pub struct SuperAsStrAbbrIter(SuperIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for SuperAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "SuperAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for SuperAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> SuperAsStrAbbrIter {
+        SuperAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for SuperAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Super::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Super::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for SuperAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Super::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Super::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for SuperAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for SuperAsStrAbbrIter {}
+///A type-level [`Super`] variant, implemented by the types of [`super_markers`].
+pub trait SuperVariant {
+    /// The variant represented by the marker type.
+    const VALUE: Super;
+    /// The string representation of the variant represented by the marker type.
+    const NAME: &'static str;
+}
+/**Type-level marker types of the [`Super`](super::Super) variants.
+
+# Notes
+
+This module is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(markers)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub mod super_markers {
+    ///Type-level marker of the [`Super::Admin`](super::Super::Admin) variant.
+    pub struct Admin;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Admin {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Admin")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Admin {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Admin {
+        #[inline]
+        fn clone(&self) -> Admin {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Admin {}
+    #[automatically_derived]
+    impl ::core::default::Default for Admin {
+        #[inline]
+        fn default() -> Admin {
+            Admin {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Admin {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Admin {
+        #[inline]
+        fn eq(&self, other: &Admin) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Admin {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Admin {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::SuperVariant for Admin {
+        const VALUE: super::Super = super::Super::Admin;
+        const NAME: &'static str = super::Super::Admin.as_str();
+    }
+}
+#[variants(markers)]
+pub enum Type {
+    Unit,
+}
+impl ::std::marker::Copy for Type {}
+impl ::std::clone::Clone for Type {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Type {
+    ///The array of _iterable_ (i.e. non-skipped) [`Type`] variants.
+    const ITERABLE_VARIANTS: [Self; 1usize] = [Self::Unit];
+    ///The number of _iterable_ (i.e. non-skipped) [`Type`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 1usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Type`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Type`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Type`] variant, in declaration order.
+    pub const FIRST: Self = Self::Unit;
+    ///The last _iterable_ (i.e. non-skipped) [`Type`] variant, in declaration order.
+    pub const LAST: Self = Self::Unit;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Type`] variant following this one in [`Type::ALL`], wrapping around to [`Type::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Type`] variant preceding this one in [`Type::ALL`], wrapping around to [`Type::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Type`] variant following this one in [`Type::ALL`], stopping at [`Type::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Type`] variant preceding this one in [`Type::ALL`], stopping at [`Type::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Type`] variant `n` positions away from this one in [`Type::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Type`] variant to `other` in [`Type::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Type`] variant in [`Type::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Unit => 0usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Type`] variant at the given position in [`Type::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Unit),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Type`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unit => "Unit",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Type`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Unit => "Uni",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Type`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> TypeIter {
+        TypeIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Type`] variants of [`Type::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> TypeIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        TypeIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Type`] variants of [`Type::ALL`] within the given range, wrapping around to [`Type::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> TypeIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        TypeIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Type`]
+variants.
+
+See [`Type::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> TypeAsStrIter {
+        TypeAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Type`] variants.
+
+See [`Type::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> TypeAsStrAbbrIter {
+        TypeAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Type`]
+variants.
+
+See [`Type::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Unit\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Type`]
+variants.
+
+See [`Type::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Uni\""
+    }
+}
+#[automatically_derived]
+impl Type {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Type`] value is the [`Type::Unit`] variant.
+    pub const fn is_unit(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Unit => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Type`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Unit, Self::Unit) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Type`] variants, returned by [`Type::iter_variants`].
+pub struct TypeIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for TypeIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "TypeIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for TypeIter {
+    #[inline]
+    fn clone(&self) -> TypeIter {
+        TypeIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for TypeIter {
+    type Item = Type;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Type::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Type::from_index(if index < Type::COUNT { index } else { index - Type::COUNT })
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for TypeIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Type::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Type::from_index(
+            if self.back < Type::COUNT { self.back } else { self.back - Type::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for TypeIter {}
+impl ::std::iter::FusedIterator for TypeIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Type`] variants, returned by [`Type::iter_variants_as_str`].
+pub struct TypeAsStrIter(TypeIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for TypeAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "TypeAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for TypeAsStrIter {
+    #[inline]
+    fn clone(&self) -> TypeAsStrIter {
+        TypeAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for TypeAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Type::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Type::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for TypeAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Type::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Type::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for TypeAsStrIter {}
+impl ::std::iter::FusedIterator for TypeAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Type`] variants, returned by [`Type::iter_variants_as_str_abbr`].
+pub struct TypeAsStrAbbrIter(TypeIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for TypeAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "TypeAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for TypeAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> TypeAsStrAbbrIter {
+        TypeAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for TypeAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Type::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Type::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for TypeAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Type::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Type::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for TypeAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for TypeAsStrAbbrIter {}
+///A type-level [`Type`] variant, implemented by the types of [`r#type`].
+pub trait TypeVariant {
+    /// The variant represented by the marker type.
+    const VALUE: Type;
+    /// The string representation of the variant represented by the marker type.
+    const NAME: &'static str;
+}
+/**Type-level marker types of the [`Type`](super::Type) variants.
+
+# Notes
+
+This module is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(markers)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub mod r#type {
+    ///Type-level marker of the [`Type::Unit`](super::Type::Unit) variant.
+    pub struct Unit;
+    #[automatically_derived]
+    impl ::core::fmt::Debug for Unit {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::write_str(f, "Unit")
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    unsafe impl ::core::clone::TrivialClone for Unit {}
+    #[automatically_derived]
+    impl ::core::clone::Clone for Unit {
+        #[inline]
+        fn clone(&self) -> Unit {
+            *self
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::Copy for Unit {}
+    #[automatically_derived]
+    impl ::core::default::Default for Unit {
+        #[inline]
+        fn default() -> Unit {
+            Unit {}
+        }
+    }
+    #[automatically_derived]
+    impl ::core::marker::StructuralPartialEq for Unit {}
+    #[automatically_derived]
+    impl ::core::cmp::PartialEq for Unit {
+        #[inline]
+        fn eq(&self, other: &Unit) -> bool {
+            true
+        }
+    }
+    #[automatically_derived]
+    impl ::core::cmp::Eq for Unit {
+        #[doc(hidden)]
+        #[coverage(off)]
+        fn assert_fields_are_eq(&self) {}
+    }
+    #[automatically_derived]
+    impl ::core::hash::Hash for Unit {
+        #[inline]
+        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
+    }
+    impl super::TypeVariant for Unit {
+        const VALUE: super::Type = super::Type::Unit;
+        const NAME: &'static str = super::Type::Unit.as_str();
+    }
+}
+fn main() {}
@@ -0,0 +1,22 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(markers)]
+pub enum Crate {
+    Local,
+    Remote,
+}
+
+#[derive(Variants)]
+#[variants(markers)]
+pub enum Super {
+    Admin,
+}
+
+#[derive(Variants)]
+#[variants(markers)]
+pub enum Type {
+    Unit,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(no_copy, derive(Debug, PartialEq, Eq, Hash), from_str, repr)]
+#[repr(u8)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+}
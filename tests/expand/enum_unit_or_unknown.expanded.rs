@@ -0,0 +1,847 @@
+use beerec_variants::Variants;
+#[variants(or_unknown, from_str(trim))]
+pub enum Channel {
+    Stable,
+    Beta,
+    Nightly,
+}
+impl ::std::marker::Copy for Channel {}
+impl ::std::clone::Clone for Channel {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Channel {
+    ///The array of _iterable_ (i.e. non-skipped) [`Channel`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [Self::Stable, Self::Beta, Self::Nightly];
+    ///The number of _iterable_ (i.e. non-skipped) [`Channel`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Channel`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Channel`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Channel`] variant, in declaration order.
+    pub const FIRST: Self = Self::Stable;
+    ///The last _iterable_ (i.e. non-skipped) [`Channel`] variant, in declaration order.
+    pub const LAST: Self = Self::Nightly;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant following this one in [`Channel::ALL`], wrapping around to [`Channel::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant preceding this one in [`Channel::ALL`], wrapping around to [`Channel::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant following this one in [`Channel::ALL`], stopping at [`Channel::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant preceding this one in [`Channel::ALL`], stopping at [`Channel::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant `n` positions away from this one in [`Channel::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Channel`] variant to `other` in [`Channel::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Channel`] variant in [`Channel::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Stable => 0usize,
+            Self::Beta => 1usize,
+            Self::Nightly => 2usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant at the given position in [`Channel::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Stable),
+            1usize => ::std::option::Option::Some(Self::Beta),
+            2usize => ::std::option::Option::Some(Self::Nightly),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Channel`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "Stable",
+            Self::Beta => "Beta",
+            Self::Nightly => "Nightly",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Channel`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Stable => "Sta",
+            Self::Beta => "Bet",
+            Self::Nightly => "Nig",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Channel`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> ChannelIter {
+        ChannelIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Channel`] variants of [`Channel::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> ChannelIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        ChannelIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Channel`] variants of [`Channel::ALL`] within the given range, wrapping around to [`Channel::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> ChannelIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        ChannelIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Channel`]
+variants.
+
+See [`Channel::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> ChannelAsStrIter {
+        ChannelAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Channel`] variants.
+
+See [`Channel::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> ChannelAsStrAbbrIter {
+        ChannelAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Channel`]
+variants.
+
+See [`Channel::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Stable\", \"Beta\", \"Nightly\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Channel`]
+variants.
+
+See [`Channel::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Sta\", \"Bet\", \"Nig\""
+    }
+}
+#[automatically_derived]
+impl Channel {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Channel`] value is the [`Channel::Stable`] variant.
+    pub const fn is_stable(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Stable => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Channel`] value is the [`Channel::Beta`] variant.
+    pub const fn is_beta(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Beta => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Channel`] value is the [`Channel::Nightly`] variant.
+    pub const fn is_nightly(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Nightly => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Channel`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Stable, Self::Stable)
+            | (Self::Beta, Self::Beta)
+            | (Self::Nightly, Self::Nightly) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Channel`] variants, returned by [`Channel::iter_variants`].
+pub struct ChannelIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ChannelIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "ChannelIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelIter {
+    #[inline]
+    fn clone(&self) -> ChannelIter {
+        ChannelIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for ChannelIter {
+    type Item = Channel;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Channel::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Channel::from_index(
+            if index < Channel::COUNT { index } else { index - Channel::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for ChannelIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Channel::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Channel::from_index(
+            if self.back < Channel::COUNT {
+                self.back
+            } else {
+                self.back - Channel::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for ChannelIter {}
+impl ::std::iter::FusedIterator for ChannelIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Channel`] variants, returned by [`Channel::iter_variants_as_str`].
+pub struct ChannelAsStrIter(ChannelIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for ChannelAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "ChannelAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelAsStrIter {
+    #[inline]
+    fn clone(&self) -> ChannelAsStrIter {
+        ChannelAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for ChannelAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Channel::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Channel::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for ChannelAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Channel::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Channel::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for ChannelAsStrIter {}
+impl ::std::iter::FusedIterator for ChannelAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Channel`] variants, returned by [`Channel::iter_variants_as_str_abbr`].
+pub struct ChannelAsStrAbbrIter(ChannelIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for ChannelAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "ChannelAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> ChannelAsStrAbbrIter {
+        ChannelAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for ChannelAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Channel::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Channel::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for ChannelAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Channel::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Channel::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for ChannelAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for ChannelAsStrAbbrIter {}
+#[automatically_derived]
+impl Channel {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_FULL: [(&'static str, Self); 3usize] = [
+        ("Stable", Self::Stable),
+        ("Beta", Self::Beta),
+        ("Nightly", Self::Nightly),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_ABBR: [(&'static str, Self); 3usize] = [
+        ("Sta", Self::Stable),
+        ("Bet", Self::Beta),
+        ("Nig", Self::Nightly),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES: [(&'static str, Self); 6usize] = [
+        ("Stable", Self::Stable),
+        ("Sta", Self::Stable),
+        ("Beta", Self::Beta),
+        ("Bet", Self::Beta),
+        ("Nightly", Self::Nightly),
+        ("Nig", Self::Nightly),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+    #[must_use]
+    ///Parses a [`Channel`] variant out of the string or abbreviated string representation (or non-deprecated alias) most similar to the given value, if their similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to `1.0` (i.e. strings equal regardless of their ASCII case).
+    pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+        Self::PARSE_CANDIDATES
+            .into_iter()
+            .map(|(candidate, variant)| {
+                let distance = Self::parse_edit_distance(value, candidate);
+                let len = ::std::cmp::max(
+                    value.chars().count(),
+                    candidate.chars().count(),
+                );
+                let similarity = match len {
+                    0 => 1.0,
+                    len => 1.0 - distance as f64 / len as f64,
+                };
+                (variant, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+            .map(|(variant, _)| variant)
+    }
+}
+pub struct ParseChannelError {
+    input: ::std::string::String,
+    suggestion: ::std::option::Option<&'static str>,
+    expected: &'static str,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ParseChannelError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "ParseChannelError",
+            "input",
+            &self.input,
+            "suggestion",
+            &self.suggestion,
+            "expected",
+            &&self.expected,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for ParseChannelError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ParseChannelError {
+    #[inline]
+    fn eq(&self, other: &ParseChannelError) -> bool {
+        self.input == other.input && self.suggestion == other.suggestion
+            && self.expected == other.expected
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ParseChannelError {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+        let _: ::core::cmp::AssertParamIsEq<::std::option::Option<&'static str>>;
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+impl ParseChannelError {
+    /// Returns the input that failed to be parsed, possibly truncated.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Returns the closest string or abbreviated string representation (or
+    /// alias) to the input that failed to be parsed, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+        self.suggestion
+    }
+}
+impl ::std::fmt::Display for ParseChannelError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_fmt(format_args!("unknown {0} `{1}`", "channel", self.input))?;
+        if let ::std::option::Option::Some(suggestion) = self.suggestion {
+            return f.write_fmt(format_args!(", did you mean `{0}`?", suggestion));
+        }
+        f.write_fmt(format_args!(", expected {0}", self.expected))
+    }
+}
+impl ::std::error::Error for ParseChannelError {}
+#[automatically_derived]
+impl Channel {
+    ///Parses a [`Channel`] variant out of its string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_full(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        let value = value.trim();
+        match value {
+            "Stable" => ::std::result::Result::Ok(Self::Stable),
+            "Beta" => ::std::result::Result::Ok(Self::Beta),
+            "Nightly" => ::std::result::Result::Ok(Self::Nightly),
+            _ => {
+                let error = ParseChannelError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_FULL,
+                    ),
+                    expected: "one of \"Stable\", \"Beta\", \"Nightly\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+    ///Parses a [`Channel`] variant out of its abbreviated string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_abbr(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        let value = value.trim();
+        match value {
+            "Sta" => ::std::result::Result::Ok(Self::Stable),
+            "Bet" => ::std::result::Result::Ok(Self::Beta),
+            "Nig" => ::std::result::Result::Ok(Self::Nightly),
+            _ => {
+                let error = ParseChannelError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_ABBR,
+                    ),
+                    expected: "one of \"Sta\", \"Bet\", \"Nig\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
+impl ::std::str::FromStr for Channel {
+    type Err = ParseChannelError;
+    ///Parses a [`Channel`] variant out of its string representations (or aliases), with no variant excluded from parsing.
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        let value = value.trim();
+        match value {
+            "Stable" | "Sta" => ::std::result::Result::Ok(Self::Stable),
+            "Beta" | "Bet" => ::std::result::Result::Ok(Self::Beta),
+            "Nightly" | "Nig" => ::std::result::Result::Ok(Self::Nightly),
+            _ => {
+                let error = ParseChannelError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(value, Self::PARSE_CANDIDATES),
+                    expected: "one of \"Stable\", \"Beta\", \"Nightly\" or one of \"Sta\", \"Bet\", \"Nig\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
+/**Either a known [`Channel`] variant or an unknown string value, preserved as is.
+
+# Notes
+
+This type is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(or_unknown)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub enum ChannelOrUnknown {
+    /// A known variant.
+    Known(Channel),
+    /// An unknown string value.
+    Unknown(::std::boxed::Box<str>),
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelOrUnknown {
+    #[inline]
+    fn clone(&self) -> ChannelOrUnknown {
+        match self {
+            ChannelOrUnknown::Known(__self_0) => {
+                ChannelOrUnknown::Known(::core::clone::Clone::clone(__self_0))
+            }
+            ChannelOrUnknown::Unknown(__self_0) => {
+                ChannelOrUnknown::Unknown(::core::clone::Clone::clone(__self_0))
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl ChannelOrUnknown {
+    /// Returns the string representation of the known variant, or the unknown
+    /// string value.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Known(variant) => variant.as_str(),
+            Self::Unknown(value) => value,
+        }
+    }
+    /// Returns the known variant, if any.
+    #[inline]
+    #[must_use]
+    pub fn known(&self) -> ::std::option::Option<Channel> {
+        match self {
+            Self::Known(variant) => ::std::option::Option::Some(*variant),
+            Self::Unknown(_) => ::std::option::Option::None,
+        }
+    }
+    /// Returns the unknown string value, if any.
+    #[inline]
+    #[must_use]
+    pub fn unknown(&self) -> ::std::option::Option<&str> {
+        match self {
+            Self::Known(_) => ::std::option::Option::None,
+            Self::Unknown(value) => ::std::option::Option::Some(value),
+        }
+    }
+}
+impl ::std::fmt::Debug for ChannelOrUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Self::Known(variant) => {
+                f.debug_tuple("Known")
+                    .field(&format_args!("{0}", variant.as_str()))
+                    .finish()
+            }
+            Self::Unknown(value) => f.debug_tuple("Unknown").field(value).finish(),
+        }
+    }
+}
+impl ::std::cmp::PartialEq for ChannelOrUnknown {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Known(variant), Self::Known(other)) => {
+                ::std::mem::discriminant(variant) == ::std::mem::discriminant(other)
+            }
+            (Self::Unknown(value), Self::Unknown(other)) => value == other,
+            _ => false,
+        }
+    }
+}
+impl ::std::cmp::Eq for ChannelOrUnknown {}
+impl ::std::hash::Hash for ChannelOrUnknown {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Known(variant) => {
+                ::std::hash::Hash::hash(&::std::mem::discriminant(variant), state)
+            }
+            Self::Unknown(value) => ::std::hash::Hash::hash(value, state),
+        }
+    }
+}
+impl ::std::convert::From<Channel> for ChannelOrUnknown {
+    fn from(variant: Channel) -> Self {
+        Self::Known(variant)
+    }
+}
+impl ::std::fmt::Display for ChannelOrUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Formatter::write_str(f, self.as_str())
+    }
+}
+impl ::std::str::FromStr for ChannelOrUnknown {
+    type Err = ::std::convert::Infallible;
+    fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+        let value = input;
+        let value = value.trim();
+        ::std::result::Result::Ok(
+            match value {
+                "Stable" | "Sta" => Self::Known(Channel::Stable),
+                "Beta" | "Bet" => Self::Known(Channel::Beta),
+                "Nightly" | "Nig" => Self::Known(Channel::Nightly),
+                _ => Self::Unknown(::std::boxed::Box::from(input)),
+            },
+        )
+    }
+}
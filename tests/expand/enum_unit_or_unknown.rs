@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(or_unknown, from_str(trim))]
+pub enum Channel {
+    Stable,
+    Beta,
+    Nightly,
+}
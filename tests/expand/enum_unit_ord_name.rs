@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(PartialEq, Eq, Variants)]
+#[variants(ord(name))]
+pub enum Fruit {
+    Pear,
+    Apple,
+    Banana,
+}
@@ -0,0 +1,620 @@
+use beerec_variants::Variants;
+#[variants(ord(rank))]
+pub enum Severity {
+    #[variants(rank = 3)]
+    Info,
+    #[variants(rank = 2)]
+    Warning,
+    #[variants(rank = 1)]
+    Error,
+    #[variants(rank = 0, skip)]
+    Unset,
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for Severity {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Severity {
+    #[inline]
+    fn eq(&self, other: &Severity) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Severity {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {}
+}
+impl ::std::marker::Copy for Severity {}
+impl ::std::clone::Clone for Severity {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Severity {
+    ///The array of _iterable_ (i.e. non-skipped) [`Severity`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [Self::Info, Self::Warning, Self::Error];
+    ///The number of _iterable_ (i.e. non-skipped) [`Severity`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Severity`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Severity`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Severity`] variant, in declaration order.
+    pub const FIRST: Self = Self::Info;
+    ///The last _iterable_ (i.e. non-skipped) [`Severity`] variant, in declaration order.
+    pub const LAST: Self = Self::Error;
+    #[inline]
+    #[must_use]
+    /**Returns the [`Severity`] variant following this one in [`Severity::ALL`], wrapping around to [`Severity::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Severity`] variant preceding this one in [`Severity::ALL`], wrapping around to [`Severity::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Severity`] variant following this one in [`Severity::ALL`], stopping at [`Severity::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Severity`] variant preceding this one in [`Severity::ALL`], stopping at [`Severity::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Severity`] variant `n` positions away from this one in [`Severity::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`Severity`] variant to `other` in [`Severity::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`Severity`] variant in [`Severity::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Info => ::std::option::Option::Some(0usize),
+            Self::Warning => ::std::option::Option::Some(1usize),
+            Self::Error => ::std::option::Option::Some(2usize),
+            Self::Unset => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Severity`] variant at the given position in [`Severity::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Info),
+            1usize => ::std::option::Option::Some(Self::Warning),
+            2usize => ::std::option::Option::Some(Self::Error),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Severity`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+            Self::Unset => "Unset",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Severity`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Info => "Inf",
+            Self::Warning => "War",
+            Self::Error => "Err",
+            Self::Unset => "Uns",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Severity`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Severity::Unset`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> SeverityIter {
+        SeverityIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`Severity`] variants of [`Severity::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> SeverityIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return SeverityIter { front: 0, back: 0 };
+        };
+        SeverityIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`Severity`] variants of [`Severity::ALL`] within the given range, wrapping around to [`Severity::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> SeverityIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return SeverityIter { front: 0, back: 0 };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        SeverityIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Severity`]
+variants.
+
+See [`Severity::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Severity::Unset`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> SeverityAsStrIter {
+        SeverityAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Severity`] variants.
+
+See [`Severity::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Severity::Unset`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> SeverityAsStrAbbrIter {
+        SeverityAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Severity`]
+variants.
+
+See [`Severity::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Severity::Unset`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Info\", \"Warning\", \"Error\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Severity`]
+variants.
+
+See [`Severity::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Severity::Unset`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Inf\", \"War\", \"Err\""
+    }
+}
+#[automatically_derived]
+impl Severity {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Severity`] value is the [`Severity::Info`] variant.
+    pub const fn is_info(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Info => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Severity`] value is the [`Severity::Warning`] variant.
+    pub const fn is_warning(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Warning => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Severity`] value is the [`Severity::Error`] variant.
+    pub const fn is_error(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Error => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Severity`] value is the [`Severity::Unset`] variant.
+    pub const fn is_unset(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Unset => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Severity`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Info, Self::Info)
+            | (Self::Warning, Self::Warning)
+            | (Self::Error, Self::Error)
+            | (Self::Unset, Self::Unset) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Severity`] variants, returned by [`Severity::iter_variants`].
+pub struct SeverityIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for SeverityIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "SeverityIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for SeverityIter {
+    #[inline]
+    fn clone(&self) -> SeverityIter {
+        SeverityIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for SeverityIter {
+    type Item = Severity;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Severity::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Severity::from_index(
+            if index < Severity::COUNT { index } else { index - Severity::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for SeverityIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Severity::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Severity::from_index(
+            if self.back < Severity::COUNT {
+                self.back
+            } else {
+                self.back - Severity::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for SeverityIter {}
+impl ::std::iter::FusedIterator for SeverityIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Severity`] variants, returned by [`Severity::iter_variants_as_str`].
+pub struct SeverityAsStrIter(SeverityIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for SeverityAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "SeverityAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for SeverityAsStrIter {
+    #[inline]
+    fn clone(&self) -> SeverityAsStrIter {
+        SeverityAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for SeverityAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Severity::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Severity::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for SeverityAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Severity::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Severity::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for SeverityAsStrIter {}
+impl ::std::iter::FusedIterator for SeverityAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Severity`] variants, returned by [`Severity::iter_variants_as_str_abbr`].
+pub struct SeverityAsStrAbbrIter(SeverityIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for SeverityAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "SeverityAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for SeverityAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> SeverityAsStrAbbrIter {
+        SeverityAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for SeverityAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Severity::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Severity::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for SeverityAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Severity::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Severity::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for SeverityAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for SeverityAsStrAbbrIter {}
+#[automatically_derived]
+impl Severity {
+    /// Returns the position of the variant in the order established by the
+    /// ordering strategy.
+    const fn ord_key(&self) -> usize {
+        match self {
+            Self::Unset => 0usize,
+            Self::Error => 1usize,
+            Self::Warning => 2usize,
+            Self::Info => 3usize,
+        }
+    }
+    ///The least _iterable_ (i.e. non-skipped) [`Severity`] variant, as ordered by [`Ord`].
+    pub const MIN: Self = Self::Error;
+    ///The greatest _iterable_ (i.e. non-skipped) [`Severity`] variant, as ordered by [`Ord`].
+    pub const MAX: Self = Self::Info;
+    #[inline]
+    #[must_use]
+    ///Iterates over _iterable_ (i.e. non-skipped) [`Severity`] variants, in the order established by the [`Ord`] trait implementation.
+    pub fn iter_variants_sorted() -> ::std::array::IntoIter<Self, { Self::COUNT }> {
+        [Self::Error, Self::Warning, Self::Info].into_iter()
+    }
+}
+impl ::std::cmp::PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+        ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+    }
+}
+impl ::std::cmp::Ord for Severity {
+    fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+        ::std::cmp::Ord::cmp(&self.ord_key(), &other.ord_key())
+    }
+}
@@ -0,0 +1,14 @@
+use beerec_variants::Variants;
+
+#[derive(PartialEq, Eq, Variants)]
+#[variants(ord(rank))]
+pub enum Severity {
+    #[variants(rank = 3)]
+    Info,
+    #[variants(rank = 2)]
+    Warning,
+    #[variants(rank = 1)]
+    Error,
+    #[variants(rank = 0, skip)]
+    Unset,
+}
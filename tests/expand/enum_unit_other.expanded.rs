@@ -0,0 +1,758 @@
+use beerec_variants::Variants;
+#[variants(from_str)]
+pub enum Event {
+    Created,
+    Updated,
+    Deleted,
+    #[variants(other, skip)]
+    Unknown,
+}
+impl ::std::marker::Copy for Event {}
+impl ::std::clone::Clone for Event {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Event {
+    ///The array of _iterable_ (i.e. non-skipped) [`Event`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [
+        Self::Created,
+        Self::Updated,
+        Self::Deleted,
+    ];
+    ///The number of _iterable_ (i.e. non-skipped) [`Event`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Event`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Event`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Event`] variant, in declaration order.
+    pub const FIRST: Self = Self::Created;
+    ///The last _iterable_ (i.e. non-skipped) [`Event`] variant, in declaration order.
+    pub const LAST: Self = Self::Deleted;
+    #[inline]
+    #[must_use]
+    /**Returns the [`Event`] variant following this one in [`Event::ALL`], wrapping around to [`Event::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Event`] variant preceding this one in [`Event::ALL`], wrapping around to [`Event::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Event`] variant following this one in [`Event::ALL`], stopping at [`Event::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Event`] variant preceding this one in [`Event::ALL`], stopping at [`Event::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Event`] variant `n` positions away from this one in [`Event::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`Event`] variant to `other` in [`Event::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`Event`] variant in [`Event::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Created => ::std::option::Option::Some(0usize),
+            Self::Updated => ::std::option::Option::Some(1usize),
+            Self::Deleted => ::std::option::Option::Some(2usize),
+            Self::Unknown => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Event`] variant at the given position in [`Event::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Created),
+            1usize => ::std::option::Option::Some(Self::Updated),
+            2usize => ::std::option::Option::Some(Self::Deleted),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Event`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "Created",
+            Self::Updated => "Updated",
+            Self::Deleted => "Deleted",
+            Self::Unknown => "Unknown",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Event`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Created => "Cre",
+            Self::Updated => "Upd",
+            Self::Deleted => "Del",
+            Self::Unknown => "Unk",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Event`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Event::Unknown`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> EventIter {
+        EventIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`Event`] variants of [`Event::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> EventIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return EventIter { front: 0, back: 0 };
+        };
+        EventIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`Event`] variants of [`Event::ALL`] within the given range, wrapping around to [`Event::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> EventIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return EventIter { front: 0, back: 0 };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        EventIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Event`]
+variants.
+
+See [`Event::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Event::Unknown`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> EventAsStrIter {
+        EventAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Event`] variants.
+
+See [`Event::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Event::Unknown`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> EventAsStrAbbrIter {
+        EventAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Event`]
+variants.
+
+See [`Event::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Event::Unknown`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Created\", \"Updated\", \"Deleted\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Event`]
+variants.
+
+See [`Event::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Event::Unknown`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Cre\", \"Upd\", \"Del\""
+    }
+}
+#[automatically_derived]
+impl Event {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Event`] value is the [`Event::Created`] variant.
+    pub const fn is_created(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Created => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Event`] value is the [`Event::Updated`] variant.
+    pub const fn is_updated(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Updated => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Event`] value is the [`Event::Deleted`] variant.
+    pub const fn is_deleted(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Deleted => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Event`] value is the [`Event::Unknown`] variant.
+    pub const fn is_unknown(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Unknown => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Event`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Created, Self::Created)
+            | (Self::Updated, Self::Updated)
+            | (Self::Deleted, Self::Deleted)
+            | (Self::Unknown, Self::Unknown) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Event`] variants, returned by [`Event::iter_variants`].
+pub struct EventIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for EventIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "EventIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for EventIter {
+    #[inline]
+    fn clone(&self) -> EventIter {
+        EventIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for EventIter {
+    type Item = Event;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Event::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Event::from_index(
+            if index < Event::COUNT { index } else { index - Event::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for EventIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Event::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Event::from_index(
+            if self.back < Event::COUNT { self.back } else { self.back - Event::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for EventIter {}
+impl ::std::iter::FusedIterator for EventIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Event`] variants, returned by [`Event::iter_variants_as_str`].
+pub struct EventAsStrIter(EventIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for EventAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "EventAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for EventAsStrIter {
+    #[inline]
+    fn clone(&self) -> EventAsStrIter {
+        EventAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for EventAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Event::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Event::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for EventAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Event::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Event::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for EventAsStrIter {}
+impl ::std::iter::FusedIterator for EventAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Event`] variants, returned by [`Event::iter_variants_as_str_abbr`].
+pub struct EventAsStrAbbrIter(EventIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for EventAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "EventAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for EventAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> EventAsStrAbbrIter {
+        EventAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for EventAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Event::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Event::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for EventAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Event::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Event::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for EventAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for EventAsStrAbbrIter {}
+#[automatically_derived]
+impl Event {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_FULL: [(&'static str, Self); 3usize] = [
+        ("Created", Self::Created),
+        ("Updated", Self::Updated),
+        ("Deleted", Self::Deleted),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_ABBR: [(&'static str, Self); 3usize] = [
+        ("Cre", Self::Created),
+        ("Upd", Self::Updated),
+        ("Del", Self::Deleted),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES: [(&'static str, Self); 6usize] = [
+        ("Created", Self::Created),
+        ("Cre", Self::Created),
+        ("Updated", Self::Updated),
+        ("Upd", Self::Updated),
+        ("Deleted", Self::Deleted),
+        ("Del", Self::Deleted),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+    #[must_use]
+    ///Parses a [`Event`] variant out of the string or abbreviated string representation (or non-deprecated alias) most similar to the given value, if their similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to `1.0` (i.e. strings equal regardless of their ASCII case).
+    pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+        Self::PARSE_CANDIDATES
+            .into_iter()
+            .map(|(candidate, variant)| {
+                let distance = Self::parse_edit_distance(value, candidate);
+                let len = ::std::cmp::max(
+                    value.chars().count(),
+                    candidate.chars().count(),
+                );
+                let similarity = match len {
+                    0 => 1.0,
+                    len => 1.0 - distance as f64 / len as f64,
+                };
+                (variant, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+            .map(|(variant, _)| variant)
+    }
+}
+pub struct ParseEventError {
+    input: ::std::string::String,
+    suggestion: ::std::option::Option<&'static str>,
+    expected: &'static str,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ParseEventError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "ParseEventError",
+            "input",
+            &self.input,
+            "suggestion",
+            &self.suggestion,
+            "expected",
+            &&self.expected,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for ParseEventError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ParseEventError {
+    #[inline]
+    fn eq(&self, other: &ParseEventError) -> bool {
+        self.input == other.input && self.suggestion == other.suggestion
+            && self.expected == other.expected
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ParseEventError {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+        let _: ::core::cmp::AssertParamIsEq<::std::option::Option<&'static str>>;
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+impl ParseEventError {
+    /// Returns the input that failed to be parsed, possibly truncated.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Returns the closest string or abbreviated string representation (or
+    /// alias) to the input that failed to be parsed, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+        self.suggestion
+    }
+}
+impl ::std::fmt::Display for ParseEventError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_fmt(format_args!("unknown {0} `{1}`", "event", self.input))?;
+        if let ::std::option::Option::Some(suggestion) = self.suggestion {
+            return f.write_fmt(format_args!(", did you mean `{0}`?", suggestion));
+        }
+        f.write_fmt(format_args!(", expected {0}", self.expected))
+    }
+}
+impl ::std::error::Error for ParseEventError {}
+#[automatically_derived]
+impl Event {
+    ///Parses a [`Event`] variant out of its string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_full(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Created" => ::std::result::Result::Ok(Self::Created),
+            "Updated" => ::std::result::Result::Ok(Self::Updated),
+            "Deleted" => ::std::result::Result::Ok(Self::Deleted),
+            _ => ::std::result::Result::Ok(Self::Unknown),
+        }
+    }
+    ///Parses a [`Event`] variant out of its abbreviated string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_abbr(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Cre" => ::std::result::Result::Ok(Self::Created),
+            "Upd" => ::std::result::Result::Ok(Self::Updated),
+            "Del" => ::std::result::Result::Ok(Self::Deleted),
+            _ => ::std::result::Result::Ok(Self::Unknown),
+        }
+    }
+}
+impl ::std::str::FromStr for Event {
+    type Err = ParseEventError;
+    ///Parses a [`Event`] variant out of its string representations (or aliases), with no variant excluded from parsing.
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        match value {
+            "Created" | "Cre" => ::std::result::Result::Ok(Self::Created),
+            "Updated" | "Upd" => ::std::result::Result::Ok(Self::Updated),
+            "Deleted" | "Del" => ::std::result::Result::Ok(Self::Deleted),
+            _ => ::std::result::Result::Ok(Self::Unknown),
+        }
+    }
+}
@@ -0,0 +1,544 @@
+use beerec_variants::Variants;
+#[variants(predicate_prefix = "is_state_")]
+pub enum State {
+    Idle,
+    Running,
+    #[variants(skip)]
+    Stopped,
+}
+impl ::std::marker::Copy for State {}
+impl ::std::clone::Clone for State {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl State {
+    ///The array of _iterable_ (i.e. non-skipped) [`State`] variants.
+    const ITERABLE_VARIANTS: [Self; 2usize] = [Self::Idle, Self::Running];
+    ///The number of _iterable_ (i.e. non-skipped) [`State`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 2usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`State`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`State`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`State`] variant, in declaration order.
+    pub const FIRST: Self = Self::Idle;
+    ///The last _iterable_ (i.e. non-skipped) [`State`] variant, in declaration order.
+    pub const LAST: Self = Self::Running;
+    #[inline]
+    #[must_use]
+    /**Returns the [`State`] variant following this one in [`State::ALL`], wrapping around to [`State::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`State`] variant preceding this one in [`State::ALL`], wrapping around to [`State::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`State`] variant following this one in [`State::ALL`], stopping at [`State::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`State`] variant preceding this one in [`State::ALL`], stopping at [`State::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`State`] variant `n` positions away from this one in [`State::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`State`] variant to `other` in [`State::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`State`] variant in [`State::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Idle => ::std::option::Option::Some(0usize),
+            Self::Running => ::std::option::Option::Some(1usize),
+            Self::Stopped => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`State`] variant at the given position in [`State::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Idle),
+            1usize => ::std::option::Option::Some(Self::Running),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`State`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Idle => "Idle",
+            Self::Running => "Running",
+            Self::Stopped => "Stopped",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`State`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Idle => "Idl",
+            Self::Running => "Run",
+            Self::Stopped => "Sto",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`State`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`State::Stopped`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> StateIter {
+        StateIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`State`] variants of [`State::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> StateIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return StateIter { front: 0, back: 0 };
+        };
+        StateIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`State`] variants of [`State::ALL`] within the given range, wrapping around to [`State::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> StateIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return StateIter { front: 0, back: 0 };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        StateIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`State`]
+variants.
+
+See [`State::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`State::Stopped`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> StateAsStrIter {
+        StateAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`State`] variants.
+
+See [`State::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`State::Stopped`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> StateAsStrAbbrIter {
+        StateAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`State`]
+variants.
+
+See [`State::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`State::Stopped`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Idle\", \"Running\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`State`]
+variants.
+
+See [`State::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`State::Stopped`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Idl\", \"Run\""
+    }
+}
+#[automatically_derived]
+impl State {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`State`] value is the [`State::Idle`] variant.
+    pub const fn is_state_idle(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Idle => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`State`] value is the [`State::Running`] variant.
+    pub const fn is_state_running(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Running => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`State`] value is the [`State::Stopped`] variant.
+    pub const fn is_state_stopped(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Stopped => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`State`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Idle, Self::Idle)
+            | (Self::Running, Self::Running)
+            | (Self::Stopped, Self::Stopped) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`State`] variants, returned by [`State::iter_variants`].
+pub struct StateIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for StateIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "StateIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for StateIter {
+    #[inline]
+    fn clone(&self) -> StateIter {
+        StateIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for StateIter {
+    type Item = State;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if State::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        State::from_index(
+            if index < State::COUNT { index } else { index - State::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for StateIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if State::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        State::from_index(
+            if self.back < State::COUNT { self.back } else { self.back - State::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for StateIter {}
+impl ::std::iter::FusedIterator for StateIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`State`] variants, returned by [`State::iter_variants_as_str`].
+pub struct StateAsStrIter(StateIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for StateAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "StateAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for StateAsStrIter {
+    #[inline]
+    fn clone(&self) -> StateAsStrIter {
+        StateAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for StateAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(State::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(State::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for StateAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(State::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(State::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for StateAsStrIter {}
+impl ::std::iter::FusedIterator for StateAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`State`] variants, returned by [`State::iter_variants_as_str_abbr`].
+pub struct StateAsStrAbbrIter(StateIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for StateAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "StateAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for StateAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> StateAsStrAbbrIter {
+        StateAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for StateAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(State::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(State::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for StateAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(State::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(State::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for StateAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for StateAsStrAbbrIter {}
+fn main() {}
@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(predicate_prefix = "is_state_")]
+pub enum State {
+    Idle,
+    Running,
+    #[variants(skip)]
+    Stopped,
+}
+
+fn main() {}
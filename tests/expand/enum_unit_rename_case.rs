@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(rename(snake_case), rename_abbr(screaming_snake_case))]
+pub enum Resource {
+    HTTPServer,
+    Utf8Stream,
+    #[variants(rename(train_case))]
+    PlainText,
+    #[variants(rename(dot_case), rename_abbr(camel_case))]
+    NorthEast,
+}
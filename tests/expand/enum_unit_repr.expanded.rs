@@ -0,0 +1,1272 @@
+use beerec_variants::Variants;
+#[variants(repr)]
+#[repr(u8)]
+pub enum Status {
+    Pending = 1,
+    Active,
+    #[variants(skip)]
+    Archived,
+    Closed = 10,
+}
+impl ::std::marker::Copy for Status {}
+impl ::std::clone::Clone for Status {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Status {
+    ///The array of _iterable_ (i.e. non-skipped) [`Status`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [
+        Self::Pending,
+        Self::Active,
+        Self::Closed,
+    ];
+    ///The number of _iterable_ (i.e. non-skipped) [`Status`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Status`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Status`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Status`] variant, in declaration order.
+    pub const FIRST: Self = Self::Pending;
+    ///The last _iterable_ (i.e. non-skipped) [`Status`] variant, in declaration order.
+    pub const LAST: Self = Self::Closed;
+    #[inline]
+    #[must_use]
+    /**Returns the [`Status`] variant following this one in [`Status::ALL`], wrapping around to [`Status::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Status`] variant preceding this one in [`Status::ALL`], wrapping around to [`Status::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Status`] variant following this one in [`Status::ALL`], stopping at [`Status::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Status`] variant preceding this one in [`Status::ALL`], stopping at [`Status::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Status`] variant `n` positions away from this one in [`Status::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`Status`] variant to `other` in [`Status::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`Status`] variant in [`Status::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Pending => ::std::option::Option::Some(0usize),
+            Self::Active => ::std::option::Option::Some(1usize),
+            Self::Archived => ::std::option::Option::None,
+            Self::Closed => ::std::option::Option::Some(2usize),
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Status`] variant at the given position in [`Status::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Pending),
+            1usize => ::std::option::Option::Some(Self::Active),
+            2usize => ::std::option::Option::Some(Self::Closed),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Status`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Active => "Active",
+            Self::Archived => "Archived",
+            Self::Closed => "Closed",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Status`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Pending => "Pen",
+            Self::Active => "Act",
+            Self::Archived => "Arc",
+            Self::Closed => "Clo",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Status`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Status::Archived`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> StatusIter {
+        StatusIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`Status`] variants of [`Status::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> StatusIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return StatusIter { front: 0, back: 0 };
+        };
+        StatusIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`Status`] variants of [`Status::ALL`] within the given range, wrapping around to [`Status::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> StatusIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return StatusIter { front: 0, back: 0 };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        StatusIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Status`]
+variants.
+
+See [`Status::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Status::Archived`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> StatusAsStrIter {
+        StatusAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Status`] variants.
+
+See [`Status::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Status::Archived`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> StatusAsStrAbbrIter {
+        StatusAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Status`]
+variants.
+
+See [`Status::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Status::Archived`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Pending\", \"Active\", \"Closed\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Status`]
+variants.
+
+See [`Status::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Status::Archived`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Pen\", \"Act\", \"Clo\""
+    }
+}
+#[automatically_derived]
+impl Status {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Status`] value is the [`Status::Pending`] variant.
+    pub const fn is_pending(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Pending => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Status`] value is the [`Status::Active`] variant.
+    pub const fn is_active(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Active => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Status`] value is the [`Status::Archived`] variant.
+    pub const fn is_archived(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Archived => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Status`] value is the [`Status::Closed`] variant.
+    pub const fn is_closed(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Closed => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Status`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Pending, Self::Pending)
+            | (Self::Active, Self::Active)
+            | (Self::Archived, Self::Archived)
+            | (Self::Closed, Self::Closed) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Status`] variants, returned by [`Status::iter_variants`].
+pub struct StatusIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for StatusIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "StatusIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for StatusIter {
+    #[inline]
+    fn clone(&self) -> StatusIter {
+        StatusIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for StatusIter {
+    type Item = Status;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Status::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Status::from_index(
+            if index < Status::COUNT { index } else { index - Status::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for StatusIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Status::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Status::from_index(
+            if self.back < Status::COUNT { self.back } else { self.back - Status::COUNT },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for StatusIter {}
+impl ::std::iter::FusedIterator for StatusIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Status`] variants, returned by [`Status::iter_variants_as_str`].
+pub struct StatusAsStrIter(StatusIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for StatusAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "StatusAsStrIter", &&self.0)
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for StatusAsStrIter {
+    #[inline]
+    fn clone(&self) -> StatusAsStrIter {
+        StatusAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for StatusAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Status::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Status::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for StatusAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Status::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Status::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for StatusAsStrIter {}
+impl ::std::iter::FusedIterator for StatusAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Status`] variants, returned by [`Status::iter_variants_as_str_abbr`].
+pub struct StatusAsStrAbbrIter(StatusIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for StatusAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "StatusAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for StatusAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> StatusAsStrAbbrIter {
+        StatusAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for StatusAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Status::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Status::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for StatusAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Status::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Status::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for StatusAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for StatusAsStrAbbrIter {}
+pub struct FromReprStatusError {
+    value: ::std::string::String,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for FromReprStatusError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "FromReprStatusError",
+            "value",
+            &&self.value,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for FromReprStatusError {
+    #[inline]
+    fn clone(&self) -> FromReprStatusError {
+        FromReprStatusError {
+            value: ::core::clone::Clone::clone(&self.value),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for FromReprStatusError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for FromReprStatusError {
+    #[inline]
+    fn eq(&self, other: &FromReprStatusError) -> bool {
+        self.value == other.value
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for FromReprStatusError {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+    }
+}
+impl FromReprStatusError {
+    /// Returns the value that failed to be converted.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+impl ::std::fmt::Display for FromReprStatusError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_fmt(
+            format_args!("unknown {0} representation `{1}`", "status", self.value),
+        )
+    }
+}
+impl ::std::error::Error for FromReprStatusError {}
+#[automatically_derived]
+impl Status {
+    #[inline]
+    #[must_use]
+    ///Returns the discriminant of the [`Status`] variant as a `u8` value.
+    pub const fn to_repr(self) -> u8 {
+        self as u8
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Status`] variant whose discriminant is the given `u8` value, if any.
+    pub const fn from_repr(value: u8) -> ::std::option::Option<Self> {
+        match value {
+            value if value == Self::Pending as u8 => {
+                ::std::option::Option::Some(Self::Pending)
+            }
+            value if value == Self::Active as u8 => {
+                ::std::option::Option::Some(Self::Active)
+            }
+            value if value == Self::Archived as u8 => {
+                ::std::option::Option::Some(Self::Archived)
+            }
+            value if value == Self::Closed as u8 => {
+                ::std::option::Option::Some(Self::Closed)
+            }
+            _ => ::std::option::Option::None,
+        }
+    }
+}
+impl ::std::convert::From<Status> for u8 {
+    fn from(variant: Status) -> Self {
+        variant.to_repr()
+    }
+}
+impl ::std::convert::TryFrom<u8> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: u8) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<u8>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<u16> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: u16) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<u16>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<u32> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: u32) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<u32>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<u64> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: u64) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<u64>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<u128> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: u128) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<u128>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<usize> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: usize) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<usize>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<i8> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: i8) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<i8>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<i16> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: i16) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<i16>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<i32> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<i32>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<i64> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: i64) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<i64>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<i128> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: i128) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<i128>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+impl ::std::convert::TryFrom<isize> for Status {
+    type Error = FromReprStatusError;
+    fn try_from(value: isize) -> ::std::result::Result<Self, Self::Error> {
+        <u8 as ::std::convert::TryFrom<isize>>::try_from(value)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or_else(|| FromReprStatusError {
+                value: ::std::string::ToString::to_string(&value),
+            })
+    }
+}
+#[repr(u8)]
+pub enum Priority {
+    Low,
+    High,
+}
+impl ::std::marker::Copy for Priority {}
+impl ::std::clone::Clone for Priority {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Priority {
+    ///The array of _iterable_ (i.e. non-skipped) [`Priority`] variants.
+    const ITERABLE_VARIANTS: [Self; 2usize] = [Self::Low, Self::High];
+    ///The number of _iterable_ (i.e. non-skipped) [`Priority`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 2usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Priority`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Priority`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Priority`] variant, in declaration order.
+    pub const FIRST: Self = Self::Low;
+    ///The last _iterable_ (i.e. non-skipped) [`Priority`] variant, in declaration order.
+    pub const LAST: Self = Self::High;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant following this one in [`Priority::ALL`], wrapping around to [`Priority::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant preceding this one in [`Priority::ALL`], wrapping around to [`Priority::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant following this one in [`Priority::ALL`], stopping at [`Priority::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant preceding this one in [`Priority::ALL`], stopping at [`Priority::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant `n` positions away from this one in [`Priority::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Priority`] variant to `other` in [`Priority::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Priority`] variant in [`Priority::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Low => 0usize,
+            Self::High => 1usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Priority`] variant at the given position in [`Priority::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Low),
+            1usize => ::std::option::Option::Some(Self::High),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Priority`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::High => "High",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Priority`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::High => "Hig",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Priority`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> PriorityIter {
+        PriorityIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Priority`] variants of [`Priority::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> PriorityIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        PriorityIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Priority`] variants of [`Priority::ALL`] within the given range, wrapping around to [`Priority::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> PriorityIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        PriorityIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Priority`]
+variants.
+
+See [`Priority::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> PriorityAsStrIter {
+        PriorityAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Priority`] variants.
+
+See [`Priority::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> PriorityAsStrAbbrIter {
+        PriorityAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Priority`]
+variants.
+
+See [`Priority::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Low\", \"High\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Priority`]
+variants.
+
+See [`Priority::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Low\", \"Hig\""
+    }
+}
+#[automatically_derived]
+impl Priority {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Priority`] value is the [`Priority::Low`] variant.
+    pub const fn is_low(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Low => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Priority`] value is the [`Priority::High`] variant.
+    pub const fn is_high(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::High => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Priority`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Low, Self::Low) | (Self::High, Self::High) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Priority`] variants, returned by [`Priority::iter_variants`].
+pub struct PriorityIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for PriorityIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "PriorityIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PriorityIter {
+    #[inline]
+    fn clone(&self) -> PriorityIter {
+        PriorityIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for PriorityIter {
+    type Item = Priority;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Priority::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Priority::from_index(
+            if index < Priority::COUNT { index } else { index - Priority::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PriorityIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Priority::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Priority::from_index(
+            if self.back < Priority::COUNT {
+                self.back
+            } else {
+                self.back - Priority::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for PriorityIter {}
+impl ::std::iter::FusedIterator for PriorityIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Priority`] variants, returned by [`Priority::iter_variants_as_str`].
+pub struct PriorityAsStrIter(PriorityIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PriorityAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PriorityAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PriorityAsStrIter {
+    #[inline]
+    fn clone(&self) -> PriorityAsStrIter {
+        PriorityAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PriorityAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Priority::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Priority::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PriorityAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Priority::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Priority::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PriorityAsStrIter {}
+impl ::std::iter::FusedIterator for PriorityAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Priority`] variants, returned by [`Priority::iter_variants_as_str_abbr`].
+pub struct PriorityAsStrAbbrIter(PriorityIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PriorityAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PriorityAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PriorityAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> PriorityAsStrAbbrIter {
+        PriorityAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PriorityAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Priority::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Priority::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PriorityAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Priority::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Priority::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PriorityAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for PriorityAsStrAbbrIter {}
@@ -0,0 +1,19 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(repr)]
+#[repr(u8)]
+pub enum Status {
+    Pending = 1,
+    Active,
+    #[variants(skip)]
+    Archived,
+    Closed = 10,
+}
+
+#[derive(Variants)]
+#[repr(u8)]
+pub enum Priority {
+    Low,
+    High,
+}
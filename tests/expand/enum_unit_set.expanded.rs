@@ -0,0 +1,1200 @@
+use beerec_variants::Variants;
+#[variants(set, from_str)]
+pub enum Permission {
+    Read,
+    Write,
+    Execute,
+    #[variants(skip)]
+    Admin,
+}
+impl ::std::marker::Copy for Permission {}
+impl ::std::clone::Clone for Permission {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Permission {
+    ///The array of _iterable_ (i.e. non-skipped) [`Permission`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [Self::Read, Self::Write, Self::Execute];
+    ///The number of _iterable_ (i.e. non-skipped) [`Permission`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Permission`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Permission`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Permission`] variant, in declaration order.
+    pub const FIRST: Self = Self::Read;
+    ///The last _iterable_ (i.e. non-skipped) [`Permission`] variant, in declaration order.
+    pub const LAST: Self = Self::Execute;
+    #[inline]
+    #[must_use]
+    /**Returns the [`Permission`] variant following this one in [`Permission::ALL`], wrapping around to [`Permission::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::FIRST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Permission`] variant preceding this one in [`Permission::ALL`], wrapping around to [`Permission::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::LAST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Permission`] variant following this one in [`Permission::ALL`], stopping at [`Permission::LAST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_next(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match Self::from_index(index + 1) {
+                ::std::option::Option::Some(variant) => variant,
+                ::std::option::Option::None => Self::LAST,
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Permission`] variant preceding this one in [`Permission::ALL`], stopping at [`Permission::FIRST`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn saturating_prev(self) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(
+            match index {
+                0 => Self::FIRST,
+                index => Self::from_index(index - 1).unwrap(),
+            },
+        )
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the [`Permission`] variant `n` positions away from this one in [`Permission::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn offset(self, n: isize) -> ::std::option::Option<Self> {
+        let ::std::option::Option::Some(index) = self.index() else {
+            return ::std::option::Option::None;
+        };
+        if Self::COUNT == 1 {
+            return ::std::option::Option::Some(Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        ::std::option::Option::Some(Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the signed number of positions from this [`Permission`] variant to `other` in [`Permission::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+
+Returns `None` if either variant is excluded from iteration, as it has no index.*/
+    pub const fn distance_to(self, other: Self) -> ::std::option::Option<isize> {
+        let (
+            ::std::option::Option::Some(index),
+            ::std::option::Option::Some(other_index),
+        ) = (self.index(), other.index()) else {
+            return ::std::option::Option::None;
+        };
+        ::std::option::Option::Some(other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    /**Returns the position of the [`Permission`] variant in [`Permission::ALL`].
+
+Returns `None` if the variant is excluded from iteration, as it has no index.*/
+    pub const fn index(self) -> ::std::option::Option<usize> {
+        match self {
+            Self::Read => ::std::option::Option::Some(0usize),
+            Self::Write => ::std::option::Option::Some(1usize),
+            Self::Execute => ::std::option::Option::Some(2usize),
+            Self::Admin => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Permission`] variant at the given position in [`Permission::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Read),
+            1usize => ::std::option::Option::Some(Self::Write),
+            2usize => ::std::option::Option::Some(Self::Execute),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Permission`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "Read",
+            Self::Write => "Write",
+            Self::Execute => "Execute",
+            Self::Admin => "Admin",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Permission`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Read => "Rea",
+            Self::Write => "Wri",
+            Self::Execute => "Exe",
+            Self::Admin => "Adm",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Permission`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Permission::Admin`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> PermissionIter {
+        PermissionIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    /**Iterates over the [`Permission`] variants of [`Permission::ALL`] within the given range, yielding no variants if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range<R>(range: R) -> PermissionIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return PermissionIter {
+                front: 0,
+                back: 0,
+            };
+        };
+        PermissionIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    /**Iterates over the [`Permission`] variants of [`Permission::ALL`] within the given range, wrapping around to [`Permission::FIRST`] if its start follows its end.
+
+Ranges with any bound excluded from iteration yield no variants.*/
+    pub fn range_wrapping<R>(range: R) -> PermissionIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let ::std::option::Option::Some((front, back)) = Self::range_indexes(&range)
+        else {
+            return PermissionIter {
+                front: 0,
+                back: 0,
+            };
+        };
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        PermissionIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one, or `None` if any bound of the range has no index.*/
+    fn range_indexes<R>(range: &R) -> ::std::option::Option<(usize, usize)>
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()?,
+            ::std::ops::Bound::Excluded(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index()? + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index()?,
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ::std::option::Option::Some((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Permission`]
+variants.
+
+See [`Permission::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Permission::Admin`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> PermissionAsStrIter {
+        PermissionAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Permission`] variants.
+
+See [`Permission::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Permission::Admin`] variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> PermissionAsStrAbbrIter {
+        PermissionAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Permission`]
+variants.
+
+See [`Permission::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Permission::Admin`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Read\", \"Write\", \"Execute\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Permission`]
+variants.
+
+See [`Permission::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with the [`Permission::Admin`] variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Rea\", \"Wri\", \"Exe\""
+    }
+}
+#[automatically_derived]
+impl Permission {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Permission`] value is the [`Permission::Read`] variant.
+    pub const fn is_read(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Read => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Permission`] value is the [`Permission::Write`] variant.
+    pub const fn is_write(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Write => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Permission`] value is the [`Permission::Execute`] variant.
+    pub const fn is_execute(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Execute => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Permission`] value is the [`Permission::Admin`] variant.
+    pub const fn is_admin(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Admin => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Permission`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Read, Self::Read)
+            | (Self::Write, Self::Write)
+            | (Self::Execute, Self::Execute)
+            | (Self::Admin, Self::Admin) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Permission`] variants, returned by [`Permission::iter_variants`].
+pub struct PermissionIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for PermissionIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "PermissionIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PermissionIter {
+    #[inline]
+    fn clone(&self) -> PermissionIter {
+        PermissionIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for PermissionIter {
+    type Item = Permission;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Permission::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Permission::from_index(
+            if index < Permission::COUNT { index } else { index - Permission::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PermissionIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Permission::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Permission::from_index(
+            if self.back < Permission::COUNT {
+                self.back
+            } else {
+                self.back - Permission::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for PermissionIter {}
+impl ::std::iter::FusedIterator for PermissionIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Permission`] variants, returned by [`Permission::iter_variants_as_str`].
+pub struct PermissionAsStrIter(PermissionIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PermissionAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PermissionAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PermissionAsStrIter {
+    #[inline]
+    fn clone(&self) -> PermissionAsStrIter {
+        PermissionAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PermissionAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Permission::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Permission::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PermissionAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Permission::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Permission::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PermissionAsStrIter {}
+impl ::std::iter::FusedIterator for PermissionAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Permission`] variants, returned by [`Permission::iter_variants_as_str_abbr`].
+pub struct PermissionAsStrAbbrIter(PermissionIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PermissionAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PermissionAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PermissionAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> PermissionAsStrAbbrIter {
+        PermissionAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PermissionAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Permission::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Permission::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PermissionAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Permission::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Permission::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PermissionAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for PermissionAsStrAbbrIter {}
+#[automatically_derived]
+impl Permission {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_FULL: [(&'static str, Self); 3usize] = [
+        ("Read", Self::Read),
+        ("Write", Self::Write),
+        ("Execute", Self::Execute),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES_ABBR: [(&'static str, Self); 3usize] = [
+        ("Rea", Self::Read),
+        ("Wri", Self::Write),
+        ("Exe", Self::Execute),
+    ];
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES: [(&'static str, Self); 6usize] = [
+        ("Read", Self::Read),
+        ("Rea", Self::Read),
+        ("Write", Self::Write),
+        ("Wri", Self::Write),
+        ("Execute", Self::Execute),
+        ("Exe", Self::Execute),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+    #[must_use]
+    ///Parses a [`Permission`] variant out of the string or abbreviated string representation (or non-deprecated alias) most similar to the given value, if their similarity is at least `threshold`, from `0.0` (i.e. completely different strings) to `1.0` (i.e. strings equal regardless of their ASCII case).
+    pub fn from_str_fuzzy(value: &str, threshold: f64) -> ::std::option::Option<Self> {
+        Self::PARSE_CANDIDATES
+            .into_iter()
+            .map(|(candidate, variant)| {
+                let distance = Self::parse_edit_distance(value, candidate);
+                let len = ::std::cmp::max(
+                    value.chars().count(),
+                    candidate.chars().count(),
+                );
+                let similarity = match len {
+                    0 => 1.0,
+                    len => 1.0 - distance as f64 / len as f64,
+                };
+                (variant, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .min_by(|(_, similarity), (_, other)| other.total_cmp(similarity))
+            .map(|(variant, _)| variant)
+    }
+}
+pub struct ParsePermissionError {
+    input: ::std::string::String,
+    suggestion: ::std::option::Option<&'static str>,
+    expected: &'static str,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ParsePermissionError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "ParsePermissionError",
+            "input",
+            &self.input,
+            "suggestion",
+            &self.suggestion,
+            "expected",
+            &&self.expected,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for ParsePermissionError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ParsePermissionError {
+    #[inline]
+    fn eq(&self, other: &ParsePermissionError) -> bool {
+        self.input == other.input && self.suggestion == other.suggestion
+            && self.expected == other.expected
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ParsePermissionError {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<::std::string::String>;
+        let _: ::core::cmp::AssertParamIsEq<::std::option::Option<&'static str>>;
+        let _: ::core::cmp::AssertParamIsEq<&'static str>;
+    }
+}
+impl ParsePermissionError {
+    /// Returns the input that failed to be parsed, possibly truncated.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Returns the closest string or abbreviated string representation (or
+    /// alias) to the input that failed to be parsed, if any is close enough.
+    #[must_use]
+    pub fn suggestion(&self) -> ::std::option::Option<&'static str> {
+        self.suggestion
+    }
+}
+impl ::std::fmt::Display for ParsePermissionError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_fmt(format_args!("unknown {0} `{1}`", "permission", self.input))?;
+        if let ::std::option::Option::Some(suggestion) = self.suggestion {
+            return f.write_fmt(format_args!(", did you mean `{0}`?", suggestion));
+        }
+        f.write_fmt(format_args!(", expected {0}", self.expected))
+    }
+}
+impl ::std::error::Error for ParsePermissionError {}
+#[automatically_derived]
+impl Permission {
+    ///Parses a [`Permission`] variant out of its string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_full(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Read" => ::std::result::Result::Ok(Self::Read),
+            "Write" => ::std::result::Result::Ok(Self::Write),
+            "Execute" => ::std::result::Result::Ok(Self::Execute),
+            "Admin" => ::std::result::Result::Ok(Self::Admin),
+            _ => {
+                let error = ParsePermissionError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_FULL,
+                    ),
+                    expected: "one of \"Read\", \"Write\", \"Execute\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+    ///Parses a [`Permission`] variant out of its abbreviated string representation (or alias) only, with the same parsing options as its [`FromStr`](::std::str::FromStr) implementation.
+    pub fn from_str_abbr(
+        value: &str,
+    ) -> ::std::result::Result<Self, <Self as ::std::str::FromStr>::Err> {
+        match value {
+            "Rea" => ::std::result::Result::Ok(Self::Read),
+            "Wri" => ::std::result::Result::Ok(Self::Write),
+            "Exe" => ::std::result::Result::Ok(Self::Execute),
+            "Adm" => ::std::result::Result::Ok(Self::Admin),
+            _ => {
+                let error = ParsePermissionError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(
+                        value,
+                        Self::PARSE_CANDIDATES_ABBR,
+                    ),
+                    expected: "one of \"Rea\", \"Wri\", \"Exe\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
+impl ::std::str::FromStr for Permission {
+    type Err = ParsePermissionError;
+    ///Parses a [`Permission`] variant out of its string representations (or aliases), with no variant excluded from parsing.
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        match value {
+            "Read" | "Rea" => ::std::result::Result::Ok(Self::Read),
+            "Write" | "Wri" => ::std::result::Result::Ok(Self::Write),
+            "Execute" | "Exe" => ::std::result::Result::Ok(Self::Execute),
+            "Admin" | "Adm" => ::std::result::Result::Ok(Self::Admin),
+            _ => {
+                let error = ParsePermissionError {
+                    input: ::std::string::ToString::to_string(value),
+                    suggestion: Self::parse_suggestion(value, Self::PARSE_CANDIDATES),
+                    expected: "one of \"Read\", \"Write\", \"Execute\" or one of \"Rea\", \"Wri\", \"Exe\"",
+                };
+                ::std::result::Result::Err(error)
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl Permission {
+    /// Returns the bit of the variant within the generated bitset type, i.e. the
+    /// bit at its index among iterable variants, or an empty bitmask for skipped
+    /// variants.
+    const fn set_bit(self) -> u8 {
+        match self {
+            Self::Read => 1 << 0usize,
+            Self::Write => 1 << 1usize,
+            Self::Execute => 1 << 2usize,
+            Self::Admin => 0,
+        }
+    }
+}
+/**A set of _iterable_ (i.e. non-skipped) [`Permission`] variants, backed by a `u8` bitset.
+
+# Notes
+
+This type is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(set)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub struct PermissionSet {
+    bits: u8,
+}
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for PermissionSet {}
+#[automatically_derived]
+impl ::core::clone::Clone for PermissionSet {
+    #[inline]
+    fn clone(&self) -> PermissionSet {
+        let _: ::core::clone::AssertParamIsClone<u8>;
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for PermissionSet {}
+#[automatically_derived]
+impl ::core::default::Default for PermissionSet {
+    #[inline]
+    fn default() -> PermissionSet {
+        PermissionSet {
+            bits: ::core::default::Default::default(),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for PermissionSet {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for PermissionSet {
+    #[inline]
+    fn eq(&self, other: &PermissionSet) -> bool {
+        self.bits == other.bits
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for PermissionSet {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<u8>;
+    }
+}
+#[automatically_derived]
+impl ::core::hash::Hash for PermissionSet {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.bits, state)
+    }
+}
+#[automatically_derived]
+impl PermissionSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self { bits: 0 };
+    /// The set of all iterable variants.
+    pub const ALL: Self = Self { bits: (1 << 3usize) - 1 };
+    /// Returns the empty set.
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self::EMPTY
+    }
+    /// Returns the set of all iterable variants.
+    #[inline]
+    #[must_use]
+    pub const fn all() -> Self {
+        Self::ALL
+    }
+    /// Returns the set of the given variants, ignoring skipped ones.
+    #[must_use]
+    pub const fn from_variants(variants: &[Permission]) -> Self {
+        let mut bits = 0;
+        let mut index = 0;
+        while index < variants.len() {
+            bits |= variants[index].set_bit();
+            index += 1;
+        }
+        Self { bits }
+    }
+    /// Returns the set of the variants whose bits are set in the given bitmask,
+    /// ignoring bits not corresponding to any iterable variant.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits_truncate(bits: u8) -> Self {
+        Self {
+            bits: bits & Self::ALL.bits,
+        }
+    }
+    /// Returns the bitmask of the set, i.e. the bit at the index of each variant
+    /// contained in the set.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.bits
+    }
+    /// Returns the number of variants contained in the set.
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+    /// Checks whether the set contains no variants.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+    /// Checks whether the set contains the given variant, which is never the case
+    /// for skipped variants.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, variant: Permission) -> bool {
+        self.bits & variant.set_bit() != 0
+    }
+    /// Returns a copy of the set with the given variant added, unless skipped.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, variant: Permission) -> Self {
+        Self {
+            bits: self.bits | variant.set_bit(),
+        }
+    }
+    /// Returns a copy of the set with the given variant removed.
+    #[inline]
+    #[must_use]
+    pub const fn without(self, variant: Permission) -> Self {
+        Self {
+            bits: self.bits & !variant.set_bit(),
+        }
+    }
+    /// Adds the given variant to the set, unless skipped, returning whether it
+    /// was newly added.
+    #[inline]
+    pub fn insert(&mut self, variant: Permission) -> bool {
+        let bits = self.bits;
+        self.bits |= variant.set_bit();
+        self.bits != bits
+    }
+    /// Removes the given variant from the set, returning whether it was
+    /// contained in the set.
+    #[inline]
+    pub fn remove(&mut self, variant: Permission) -> bool {
+        let bits = self.bits;
+        self.bits &= !variant.set_bit();
+        self.bits != bits
+    }
+    /// Returns the set of the variants contained in either set.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+    /// Returns the set of the variants contained in both sets.
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+    /// Returns the set of the variants contained in this set, but not in the
+    /// other one.
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+    /// Returns the set of the variants contained in exactly one of the sets.
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits ^ other.bits,
+        }
+    }
+    /// Returns the set of the iterable variants not contained in this set.
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self {
+            bits: !self.bits & Self::ALL.bits,
+        }
+    }
+    /// Checks whether every variant contained in this set is also contained in
+    /// the other one.
+    #[inline]
+    #[must_use]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+    /// Checks whether every variant contained in the other set is also contained
+    /// in this one.
+    #[inline]
+    #[must_use]
+    pub const fn is_superset(self, other: Self) -> bool {
+        other.is_subset(self)
+    }
+    /// Checks whether the sets have no variants in common.
+    #[inline]
+    #[must_use]
+    pub const fn is_disjoint(self, other: Self) -> bool {
+        self.bits & other.bits == 0
+    }
+    /// Returns an iterator over the variants contained in the set, in
+    /// declaration order.
+    #[inline]
+    pub const fn iter(self) -> PermissionSetIter {
+        PermissionSetIter {
+            bits: self.bits,
+        }
+    }
+}
+impl ::std::ops::BitOr for PermissionSet {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+impl ::std::ops::BitOrAssign for PermissionSet {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+}
+impl ::std::ops::BitAnd for PermissionSet {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+impl ::std::ops::BitAndAssign for PermissionSet {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = self.intersection(other);
+    }
+}
+impl ::std::ops::Sub for PermissionSet {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+impl ::std::ops::SubAssign for PermissionSet {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+}
+impl ::std::ops::BitXor for PermissionSet {
+    type Output = Self;
+    fn bitxor(self, other: Self) -> Self {
+        self.symmetric_difference(other)
+    }
+}
+impl ::std::ops::BitXorAssign for PermissionSet {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+}
+impl ::std::ops::Not for PermissionSet {
+    type Output = Self;
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+impl ::std::convert::From<Permission> for PermissionSet {
+    fn from(variant: Permission) -> Self {
+        Self::EMPTY.with(variant)
+    }
+}
+impl ::std::iter::FromIterator<Permission> for PermissionSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: ::std::iter::IntoIterator<Item = Permission>,
+    {
+        let mut set = Self::EMPTY;
+        ::std::iter::Extend::extend(&mut set, iter);
+        set
+    }
+}
+impl ::std::iter::Extend<Permission> for PermissionSet {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: ::std::iter::IntoIterator<Item = Permission>,
+    {
+        for variant in iter {
+            self.insert(variant);
+        }
+    }
+}
+impl ::std::iter::IntoIterator for PermissionSet {
+    type Item = Permission;
+    type IntoIter = PermissionSetIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl ::std::fmt::Debug for PermissionSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_set().entries(self.iter().map(Permission::as_str)).finish()
+    }
+}
+impl ::std::fmt::Display for PermissionSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        for (index, name) in self.iter().map(Permission::as_str).enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+        }
+        ::std::result::Result::Ok(())
+    }
+}
+///An iterator over the [`Permission`] variants of a [`PermissionSet`], in declaration order.
+pub struct PermissionSetIter {
+    bits: u8,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for PermissionSetIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "PermissionSetIter",
+            "bits",
+            &&self.bits,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PermissionSetIter {
+    #[inline]
+    fn clone(&self) -> PermissionSetIter {
+        PermissionSetIter {
+            bits: ::core::clone::Clone::clone(&self.bits),
+        }
+    }
+}
+impl ::std::iter::Iterator for PermissionSetIter {
+    type Item = Permission;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.bits == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Permission::from_index(index)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, ::std::option::Option::Some(len))
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PermissionSetIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.bits == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = (u8::BITS - 1 - self.bits.leading_zeros()) as usize;
+        self.bits &= !(1 << index);
+        Permission::from_index(index)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PermissionSetIter {}
+impl ::std::iter::FusedIterator for PermissionSetIter {}
+impl ::std::str::FromStr for PermissionSet {
+    type Err = <Permission as ::std::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        let mut set = Self::EMPTY;
+        if value.trim().is_empty() {
+            return ::std::result::Result::Ok(set);
+        }
+        for name in value.split(',') {
+            let variant = <Permission as ::std::str::FromStr>::from_str(name.trim())?;
+            set.insert(variant);
+        }
+        ::std::result::Result::Ok(set)
+    }
+}
+fn main() {}
@@ -0,0 +1,13 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(set, from_str)]
+pub enum Permission {
+    Read,
+    Write,
+    Execute,
+    #[variants(skip)]
+    Admin,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str)]
+pub enum Command {
+    Start,
+    Stop,
+    #[variants(skip(iter))]
+    Restart,
+    #[variants(skip(list))]
+    Debug,
+    #[variants(skip(parse))]
+    Internal,
+}
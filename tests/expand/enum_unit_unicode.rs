@@ -0,0 +1,14 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(rename(title_case), abbr(len = 4), from_str)]
+pub enum Word {
+    Straße,
+    #[variants(rename = "lunedì", rename_abbr(uppercase))]
+    Monday,
+    #[variants(rename = "Cafe\u{301}")]
+    Cafe,
+    #[variants(rename = "🇮🇹🇫🇷🇩🇪🇪🇸🇬🇧")]
+    Flags,
+    r#Type,
+}
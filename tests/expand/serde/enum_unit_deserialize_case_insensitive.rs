@@ -0,0 +1,13 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(deserialize(case_insensitive))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
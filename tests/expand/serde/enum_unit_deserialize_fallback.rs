@@ -0,0 +1,10 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(deserialize(fallback))]
+pub enum Priority {
+    Low,
+    #[variants(default)]
+    Medium,
+    High,
+}
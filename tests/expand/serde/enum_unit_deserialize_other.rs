@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(deserialize)]
+pub enum Event {
+    Created,
+    Updated,
+    Deleted,
+    #[variants(other)]
+    Unknown,
+}
@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(map, deserialize, serialize)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+}
@@ -0,0 +1,1395 @@
+use beerec_variants::Variants;
+#[variants(or_unknown, deserialize, serialize)]
+pub enum Channel {
+    Stable,
+    Beta,
+    Nightly,
+}
+impl ::std::marker::Copy for Channel {}
+impl ::std::clone::Clone for Channel {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Channel {
+    ///The array of _iterable_ (i.e. non-skipped) [`Channel`] variants.
+    const ITERABLE_VARIANTS: [Self; 3usize] = [Self::Stable, Self::Beta, Self::Nightly];
+    ///The number of _iterable_ (i.e. non-skipped) [`Channel`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 3usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Channel`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Channel`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Channel`] variant, in declaration order.
+    pub const FIRST: Self = Self::Stable;
+    ///The last _iterable_ (i.e. non-skipped) [`Channel`] variant, in declaration order.
+    pub const LAST: Self = Self::Nightly;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant following this one in [`Channel::ALL`], wrapping around to [`Channel::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant preceding this one in [`Channel::ALL`], wrapping around to [`Channel::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant following this one in [`Channel::ALL`], stopping at [`Channel::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant preceding this one in [`Channel::ALL`], stopping at [`Channel::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant `n` positions away from this one in [`Channel::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Channel`] variant to `other` in [`Channel::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Channel`] variant in [`Channel::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Stable => 0usize,
+            Self::Beta => 1usize,
+            Self::Nightly => 2usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Channel`] variant at the given position in [`Channel::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Stable),
+            1usize => ::std::option::Option::Some(Self::Beta),
+            2usize => ::std::option::Option::Some(Self::Nightly),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Channel`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "Stable",
+            Self::Beta => "Beta",
+            Self::Nightly => "Nightly",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Channel`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Stable => "Sta",
+            Self::Beta => "Bet",
+            Self::Nightly => "Nig",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Channel`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> ChannelIter {
+        ChannelIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Channel`] variants of [`Channel::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> ChannelIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        ChannelIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Channel`] variants of [`Channel::ALL`] within the given range, wrapping around to [`Channel::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> ChannelIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        ChannelIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Channel`]
+variants.
+
+See [`Channel::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> ChannelAsStrIter {
+        ChannelAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Channel`] variants.
+
+See [`Channel::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> ChannelAsStrAbbrIter {
+        ChannelAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Channel`]
+variants.
+
+See [`Channel::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Stable\", \"Beta\", \"Nightly\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Channel`]
+variants.
+
+See [`Channel::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Sta\", \"Bet\", \"Nig\""
+    }
+}
+#[automatically_derived]
+impl Channel {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Channel`] value is the [`Channel::Stable`] variant.
+    pub const fn is_stable(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Stable => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Channel`] value is the [`Channel::Beta`] variant.
+    pub const fn is_beta(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Beta => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Channel`] value is the [`Channel::Nightly`] variant.
+    pub const fn is_nightly(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Nightly => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Channel`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Stable, Self::Stable)
+            | (Self::Beta, Self::Beta)
+            | (Self::Nightly, Self::Nightly) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Channel`] variants, returned by [`Channel::iter_variants`].
+pub struct ChannelIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ChannelIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "ChannelIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelIter {
+    #[inline]
+    fn clone(&self) -> ChannelIter {
+        ChannelIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for ChannelIter {
+    type Item = Channel;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Channel::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Channel::from_index(
+            if index < Channel::COUNT { index } else { index - Channel::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for ChannelIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Channel::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Channel::from_index(
+            if self.back < Channel::COUNT {
+                self.back
+            } else {
+                self.back - Channel::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for ChannelIter {}
+impl ::std::iter::FusedIterator for ChannelIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Channel`] variants, returned by [`Channel::iter_variants_as_str`].
+pub struct ChannelAsStrIter(ChannelIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for ChannelAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "ChannelAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelAsStrIter {
+    #[inline]
+    fn clone(&self) -> ChannelAsStrIter {
+        ChannelAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for ChannelAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Channel::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Channel::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for ChannelAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Channel::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Channel::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for ChannelAsStrIter {}
+impl ::std::iter::FusedIterator for ChannelAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Channel`] variants, returned by [`Channel::iter_variants_as_str_abbr`].
+pub struct ChannelAsStrAbbrIter(ChannelIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for ChannelAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "ChannelAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> ChannelAsStrAbbrIter {
+        ChannelAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for ChannelAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Channel::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Channel::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for ChannelAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Channel::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Channel::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for ChannelAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for ChannelAsStrAbbrIter {}
+#[automatically_derived]
+impl Channel {
+    /// The accepted string and abbreviated string representations and the
+    /// non-deprecated aliases of each variant, to be used for parsing suggestions.
+    const PARSE_CANDIDATES: [(&'static str, Self); 6usize] = [
+        ("Stable", Self::Stable),
+        ("Sta", Self::Stable),
+        ("Beta", Self::Beta),
+        ("Bet", Self::Beta),
+        ("Nightly", Self::Nightly),
+        ("Nig", Self::Nightly),
+    ];
+    /// Returns the edit (i.e. Levenshtein) distance between the given strings,
+    /// regardless of their ASCII case.
+    fn parse_edit_distance(value: &str, candidate: &str) -> usize {
+        let candidate = candidate.chars().collect::<::std::vec::Vec<_>>();
+        let mut previous = (0..=candidate.len()).collect::<::std::vec::Vec<_>>();
+        let mut current = ::alloc::vec::from_elem(0, candidate.len() + 1);
+        for (i, value_char) in value.chars().enumerate() {
+            current[0] = i + 1;
+            for (j, candidate_char) in candidate.iter().enumerate() {
+                let substitution = previous[j]
+                    + usize::from(!value_char.eq_ignore_ascii_case(candidate_char));
+                current[j + 1] = substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1);
+            }
+            ::std::mem::swap(&mut previous, &mut current);
+        }
+        previous[candidate.len()]
+    }
+    /// Returns the closest of the given parse candidates to the given value,
+    /// along with its edit distance from the value.
+    fn parse_closest_candidate(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<(&'static str, Self, usize)> {
+        candidates
+            .into_iter()
+            .map(|(candidate, variant)| (
+                candidate,
+                variant,
+                Self::parse_edit_distance(value, candidate),
+            ))
+            .min_by_key(|&(_, _, distance)| distance)
+    }
+    /// Returns the closest of the given parse candidates to the given value, if
+    /// it's close enough to be suggested (i.e. its edit distance from the value
+    /// is at most a third of the value length).
+    fn parse_suggestion(
+        value: &str,
+        candidates: impl ::std::iter::IntoIterator<Item = (&'static str, Self)>,
+    ) -> ::std::option::Option<&'static str> {
+        let max_distance = ::std::cmp::max(value.chars().count(), 3) / 3;
+        Self::parse_closest_candidate(value, candidates)
+            .filter(|&(_, _, distance)| distance <= max_distance)
+            .map(|(candidate, _, _)| candidate)
+    }
+}
+impl<'de> ::serde::de::Deserialize<'de> for Channel {
+    ///Deserializes a [`Channel`] variant out of its string representations (or aliases), with no variant excluded from deserialization.
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::de::Deserializer<'de>,
+    {
+        struct ChannelVisitor;
+        impl<'de> ::serde::de::Visitor<'de> for ChannelVisitor {
+            type Value = Channel;
+            fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Formatter::write_str(
+                    f,
+                    "one of \"Stable\", \"Beta\", \"Nightly\" or one of \"Sta\", \"Bet\", \"Nig\"",
+                )
+            }
+            fn visit_str<E>(self, value: &str) -> ::std::result::Result<Self::Value, E>
+            where
+                E: ::serde::de::Error,
+            {
+                match value {
+                    "Stable" | "Sta" => ::std::result::Result::Ok(Self::Value::Stable),
+                    "Beta" | "Bet" => ::std::result::Result::Ok(Self::Value::Beta),
+                    "Nightly" | "Nig" => ::std::result::Result::Ok(Self::Value::Nightly),
+                    _ => {
+                        match Channel::parse_suggestion(
+                            value,
+                            Channel::PARSE_CANDIDATES,
+                        ) {
+                            ::std::option::Option::Some(suggestion) => {
+                                let error = ::serde::de::Error::custom(
+                                    format_args!(
+                                        "unknown {0} `{1}`, did you mean `{2}`?", "channel", value,
+                                        suggestion
+                                    ),
+                                );
+                                ::std::result::Result::Err(error)
+                            }
+                            ::std::option::Option::None => {
+                                let unexp = ::serde::de::Unexpected::Str(value);
+                                let error = ::serde::de::Error::invalid_value(unexp, &self);
+                                ::std::result::Result::Err(error)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        deserializer.deserialize_str(ChannelVisitor)
+    }
+}
+impl ::serde::ser::Serialize for Channel {
+    ///Serializes the [`Channel`] variant as its string representation, with no variant excluded from serialization.
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+/**Either a known [`Channel`] variant or an unknown string value, preserved as is.
+
+# Notes
+
+This type is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(or_unknown)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub enum ChannelOrUnknown {
+    /// A known variant.
+    Known(Channel),
+    /// An unknown string value.
+    Unknown(::std::boxed::Box<str>),
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ChannelOrUnknown {
+    #[inline]
+    fn clone(&self) -> ChannelOrUnknown {
+        match self {
+            ChannelOrUnknown::Known(__self_0) => {
+                ChannelOrUnknown::Known(::core::clone::Clone::clone(__self_0))
+            }
+            ChannelOrUnknown::Unknown(__self_0) => {
+                ChannelOrUnknown::Unknown(::core::clone::Clone::clone(__self_0))
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl ChannelOrUnknown {
+    /// Returns the string representation of the known variant, or the unknown
+    /// string value.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Known(variant) => variant.as_str(),
+            Self::Unknown(value) => value,
+        }
+    }
+    /// Returns the known variant, if any.
+    #[inline]
+    #[must_use]
+    pub fn known(&self) -> ::std::option::Option<Channel> {
+        match self {
+            Self::Known(variant) => ::std::option::Option::Some(*variant),
+            Self::Unknown(_) => ::std::option::Option::None,
+        }
+    }
+    /// Returns the unknown string value, if any.
+    #[inline]
+    #[must_use]
+    pub fn unknown(&self) -> ::std::option::Option<&str> {
+        match self {
+            Self::Known(_) => ::std::option::Option::None,
+            Self::Unknown(value) => ::std::option::Option::Some(value),
+        }
+    }
+}
+impl ::std::fmt::Debug for ChannelOrUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Self::Known(variant) => {
+                f.debug_tuple("Known")
+                    .field(&format_args!("{0}", variant.as_str()))
+                    .finish()
+            }
+            Self::Unknown(value) => f.debug_tuple("Unknown").field(value).finish(),
+        }
+    }
+}
+impl ::std::cmp::PartialEq for ChannelOrUnknown {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Known(variant), Self::Known(other)) => {
+                ::std::mem::discriminant(variant) == ::std::mem::discriminant(other)
+            }
+            (Self::Unknown(value), Self::Unknown(other)) => value == other,
+            _ => false,
+        }
+    }
+}
+impl ::std::cmp::Eq for ChannelOrUnknown {}
+impl ::std::hash::Hash for ChannelOrUnknown {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Known(variant) => {
+                ::std::hash::Hash::hash(&::std::mem::discriminant(variant), state)
+            }
+            Self::Unknown(value) => ::std::hash::Hash::hash(value, state),
+        }
+    }
+}
+impl ::std::convert::From<Channel> for ChannelOrUnknown {
+    fn from(variant: Channel) -> Self {
+        Self::Known(variant)
+    }
+}
+impl ::std::fmt::Display for ChannelOrUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Formatter::write_str(f, self.as_str())
+    }
+}
+impl ::std::str::FromStr for ChannelOrUnknown {
+    type Err = ::std::convert::Infallible;
+    fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+        let value = input;
+        ::std::result::Result::Ok(
+            match value {
+                "Stable" | "Sta" => Self::Known(Channel::Stable),
+                "Beta" | "Bet" => Self::Known(Channel::Beta),
+                "Nightly" | "Nig" => Self::Known(Channel::Nightly),
+                _ => Self::Unknown(::std::boxed::Box::from(input)),
+            },
+        )
+    }
+}
+impl<'de> ::serde::de::Deserialize<'de> for ChannelOrUnknown {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::de::Deserializer<'de>,
+    {
+        let input = <::std::string::String as ::serde::de::Deserialize>::deserialize(
+            deserializer,
+        )?;
+        let value = input.as_str();
+        ::std::result::Result::Ok(
+            match value {
+                "Stable" | "Sta" => Self::Known(Channel::Stable),
+                "Beta" | "Bet" => Self::Known(Channel::Beta),
+                "Nightly" | "Nig" => Self::Known(Channel::Nightly),
+                _ => Self::Unknown(::std::boxed::Box::from(input)),
+            },
+        )
+    }
+}
+impl ::serde::ser::Serialize for ChannelOrUnknown {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::ser::Serializer,
+    {
+        match self {
+            Self::Known(variant) => {
+                ::serde::ser::Serialize::serialize(variant, serializer)
+            }
+            Self::Unknown(value) => serializer.serialize_str(value),
+        }
+    }
+}
+#[variants(or_unknown, serialize)]
+pub enum Platform {
+    Linux,
+    Windows,
+}
+impl ::std::marker::Copy for Platform {}
+impl ::std::clone::Clone for Platform {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[automatically_derived]
+impl Platform {
+    ///The array of _iterable_ (i.e. non-skipped) [`Platform`] variants.
+    const ITERABLE_VARIANTS: [Self; 2usize] = [Self::Linux, Self::Windows];
+    ///The number of _iterable_ (i.e. non-skipped) [`Platform`] variants.
+    const ITERABLE_VARIANTS_COUNT: usize = 2usize;
+    ///The number of _iterable_ (i.e. non-skipped) [`Platform`] variants.
+    pub const COUNT: usize = Self::ITERABLE_VARIANTS_COUNT;
+    ///The array of _iterable_ (i.e. non-skipped) [`Platform`] variants, in declaration order.
+    pub const ALL: [Self; Self::COUNT] = Self::ITERABLE_VARIANTS;
+    ///The first _iterable_ (i.e. non-skipped) [`Platform`] variant, in declaration order.
+    pub const FIRST: Self = Self::Linux;
+    ///The last _iterable_ (i.e. non-skipped) [`Platform`] variant, in declaration order.
+    pub const LAST: Self = Self::Windows;
+    #[inline]
+    #[must_use]
+    ///Returns the [`Platform`] variant following this one in [`Platform::ALL`], wrapping around to [`Platform::FIRST`].
+    pub const fn next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::FIRST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Platform`] variant preceding this one in [`Platform::ALL`], wrapping around to [`Platform::LAST`].
+    pub const fn prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::LAST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Platform`] variant following this one in [`Platform::ALL`], stopping at [`Platform::LAST`].
+    pub const fn saturating_next(self) -> Self {
+        let index = self.index();
+        (match Self::from_index(index + 1) {
+            ::std::option::Option::Some(variant) => variant,
+            ::std::option::Option::None => Self::LAST,
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Platform`] variant preceding this one in [`Platform::ALL`], stopping at [`Platform::FIRST`].
+    pub const fn saturating_prev(self) -> Self {
+        let index = self.index();
+        (match index {
+            0 => Self::FIRST,
+            index => Self::from_index(index - 1).unwrap(),
+        })
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Platform`] variant `n` positions away from this one in [`Platform::ALL`] (backwards if `n` is negative), wrapping around in both directions.
+    pub const fn offset(self, n: isize) -> Self {
+        let index = self.index();
+        if Self::COUNT == 1 {
+            return (Self::FIRST);
+        }
+        let count = Self::COUNT as isize;
+        let index = (index as isize + n % count).rem_euclid(count);
+        (Self::from_index(index as usize).unwrap())
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the signed number of positions from this [`Platform`] variant to `other` in [`Platform::ALL`], such that `self.offset(self.distance_to(other))` equals `other`.
+    pub const fn distance_to(self, other: Self) -> isize {
+        let (index, other_index) = (self.index(), other.index());
+        (other_index as isize - index as isize)
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the position of the [`Platform`] variant in [`Platform::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Linux => 0usize,
+            Self::Windows => 1usize,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Returns the [`Platform`] variant at the given position in [`Platform::ALL`], if any.
+    pub const fn from_index(index: usize) -> ::std::option::Option<Self> {
+        match index {
+            0usize => ::std::option::Option::Some(Self::Linux),
+            1usize => ::std::option::Option::Some(Self::Windows),
+            _ => ::std::option::Option::None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns a string representation of the [`Platform`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Linux => "Linux",
+            Self::Windows => "Windows",
+        }
+    }
+    #[inline]
+    #[must_use]
+    /**Returns an abbreviated string representation of the [`Platform`] variant.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, it applies rename strategies on the string representation of the
+variant, following a priority-based fallback approach:
+
+1. [`InnerRenameStrategy`] (_highest priority_) - uses the abbreviated
+   string produced by the rename strategy from the `#[variants(rename_abbr(...))]`
+   attribute, if one has been specified for the variant;
+1. [`OuterRenameStrategy`] (_fallback_) - uses the abbreviated string produced
+   by the rename strategy from the `#[variants(rename_abbr(...))]` attribute, if
+   one has been specified for the type;
+1. **No renaming** (_default_) - abbreviates the full length string representation
+   of the variant as is, without applying any renaming strategy.
+
+Likewise, the renaming follows a priority-based fallback approach to
+determine the full length string representation before applying the
+abbreviation:
+
+1. **Variant-level attribute** (_highest priority_) - uses the string
+   produced by the rename strategy from the `#[variants(rename(...))]`
+   attribute, if one has been specified for the type;
+1. **Type-level attribute** (_fallback_) - uses the string produced by the
+   rename strategy from the `#[variants(rename(...))]` attribute, if one has
+   been specified for the type;
+1. **No renaming** (_default_) - converts the variant identifier to a string
+   if neither the type-level nor the variant-level rename attribute has been
+   specified.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn as_str_abbr(self) -> &'static str {
+        match self {
+            Self::Linux => "Lin",
+            Self::Windows => "Win",
+        }
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) [`Platform`] variants.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants() -> PlatformIter {
+        PlatformIter {
+            front: 0,
+            back: Self::COUNT,
+        }
+    }
+    ///Iterates over the [`Platform`] variants of [`Platform::ALL`] within the given range, yielding no variants if its start follows its end.
+    pub fn range<R>(range: R) -> PlatformIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        PlatformIter {
+            front,
+            back: ::std::cmp::max(front, back),
+        }
+    }
+    ///Iterates over the [`Platform`] variants of [`Platform::ALL`] within the given range, wrapping around to [`Platform::FIRST`] if its start follows its end.
+    pub fn range_wrapping<R>(range: R) -> PlatformIter
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let (front, back) = Self::range_indexes(&range);
+        let wraps = back < front
+            || (back == front && #[allow(non_exhaustive_omitted_patterns)]
+                match range.start_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                } && #[allow(non_exhaustive_omitted_patterns)]
+                match range.end_bound() {
+                    ::std::ops::Bound::Included(_) => true,
+                    _ => false,
+                });
+        PlatformIter {
+            front,
+            back: if wraps { back + Self::COUNT } else { back },
+        }
+    }
+    /**Returns the indexes of the first variant within the given range and of the variant
+following the last one.*/
+    fn range_indexes<R>(range: &R) -> (usize, usize)
+    where
+        R: ::std::ops::RangeBounds<Self>,
+    {
+        let front = match range.start_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index(),
+            ::std::ops::Bound::Excluded(variant) => variant.index() + 1,
+            ::std::ops::Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            ::std::ops::Bound::Included(variant) => variant.index() + 1,
+            ::std::ops::Bound::Excluded(variant) => variant.index(),
+            ::std::ops::Bound::Unbounded => Self::COUNT,
+        };
+        ((front, back))
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) string representations of [`Platform`]
+variants.
+
+See [`Platform::as_str`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str() -> PlatformAsStrIter {
+        PlatformAsStrIter(Self::iter_variants())
+    }
+    /**Iterates over _iterable_ (i.e. non-skipped) abbreviated string representations of
+[`Platform`] variants.
+
+See [`Platform::as_str_abbr`] for further details about yielded values.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from iteration.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn iter_variants_as_str_abbr() -> PlatformAsStrAbbrIter {
+        PlatformAsStrAbbrIter(Self::iter_variants())
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Platform`]
+variants.
+
+See [`Platform::as_str`] for further details about the string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str() -> &'static str {
+        "\"Linux\", \"Windows\""
+    }
+    /**Returns a list of quoted (double-quotes) and comma separated abbreviated string
+representations of _listed_ (i.e. not marked with `skip`, `skip(list)` or `other`) [`Platform`]
+variants.
+
+See [`Platform::as_str_abbr`] for further details about the abbreviated string representations.
+
+# Notes
+
+This method is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, with no variant excluded from the listing.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+    pub const fn variants_list_str_abbr() -> &'static str {
+        "\"Lin\", \"Win\""
+    }
+}
+#[automatically_derived]
+impl Platform {
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Platform`] value is the [`Platform::Linux`] variant.
+    pub const fn is_linux(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Linux => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether the [`Platform`] value is the [`Platform::Windows`] variant.
+    pub const fn is_windows(self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Windows => true,
+            _ => false,
+        }
+    }
+    #[inline]
+    #[must_use]
+    ///Checks whether two [`Platform`] values are the same variant, in `const` contexts too.
+    pub const fn const_eq(self, other: Self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match (self, other) {
+            (Self::Linux, Self::Linux) | (Self::Windows, Self::Windows) => true,
+            _ => false,
+        }
+    }
+}
+///An iterator over _iterable_ (i.e. non-skipped) [`Platform`] variants, returned by [`Platform::iter_variants`].
+pub struct PlatformIter {
+    front: usize,
+    back: usize,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for PlatformIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "PlatformIter",
+            "front",
+            &self.front,
+            "back",
+            &&self.back,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PlatformIter {
+    #[inline]
+    fn clone(&self) -> PlatformIter {
+        PlatformIter {
+            front: ::core::clone::Clone::clone(&self.front),
+            back: ::core::clone::Clone::clone(&self.back),
+        }
+    }
+}
+impl ::std::iter::Iterator for PlatformIter {
+    type Item = Platform;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Platform::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Platform::from_index(
+            if index < Platform::COUNT { index } else { index - Platform::COUNT },
+        )
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        let len = self.back - self.front;
+        (len, ::std::option::Option::Some(len))
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.front = ::std::cmp::min(self.front.saturating_add(n), self.back);
+        self.next()
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PlatformIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        if self.front == self.back {
+            return ::std::option::Option::None;
+        }
+        if Platform::COUNT == 0 {
+            return ::std::option::Option::None;
+        }
+        self.back -= 1;
+        Platform::from_index(
+            if self.back < Platform::COUNT {
+                self.back
+            } else {
+                self.back - Platform::COUNT
+            },
+        )
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.back = ::std::cmp::max(self.back.saturating_sub(n), self.front);
+        self.next_back()
+    }
+}
+impl ::std::iter::ExactSizeIterator for PlatformIter {}
+impl ::std::iter::FusedIterator for PlatformIter {}
+///An iterator over string representations of _iterable_ (i.e. non-skipped) [`Platform`] variants, returned by [`Platform::iter_variants_as_str`].
+pub struct PlatformAsStrIter(PlatformIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PlatformAsStrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PlatformAsStrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PlatformAsStrIter {
+    #[inline]
+    fn clone(&self) -> PlatformAsStrIter {
+        PlatformAsStrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PlatformAsStrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Platform::as_str)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Platform::as_str)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PlatformAsStrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Platform::as_str)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Platform::as_str)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PlatformAsStrIter {}
+impl ::std::iter::FusedIterator for PlatformAsStrIter {}
+///An iterator over abbreviated string representations of _iterable_ (i.e. non-skipped) [`Platform`] variants, returned by [`Platform::iter_variants_as_str_abbr`].
+pub struct PlatformAsStrAbbrIter(PlatformIter);
+#[automatically_derived]
+impl ::core::fmt::Debug for PlatformAsStrAbbrIter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "PlatformAsStrAbbrIter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PlatformAsStrAbbrIter {
+    #[inline]
+    fn clone(&self) -> PlatformAsStrAbbrIter {
+        PlatformAsStrAbbrIter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::std::iter::Iterator for PlatformAsStrAbbrIter {
+    type Item = &'static str;
+    fn next(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next().map(Platform::as_str_abbr)
+    }
+    fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+        self.0.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth(n).map(Platform::as_str_abbr)
+    }
+}
+impl ::std::iter::DoubleEndedIterator for PlatformAsStrAbbrIter {
+    fn next_back(&mut self) -> ::std::option::Option<Self::Item> {
+        self.0.next_back().map(Platform::as_str_abbr)
+    }
+    fn nth_back(&mut self, n: usize) -> ::std::option::Option<Self::Item> {
+        self.0.nth_back(n).map(Platform::as_str_abbr)
+    }
+}
+impl ::std::iter::ExactSizeIterator for PlatformAsStrAbbrIter {}
+impl ::std::iter::FusedIterator for PlatformAsStrAbbrIter {}
+impl ::serde::ser::Serialize for Platform {
+    ///Serializes the [`Platform`] variant as its string representation, with no variant excluded from serialization.
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+/**Either a known [`Platform`] variant or an unknown string value, preserved as is.
+
+# Notes
+
+This type is generated by the [`Variants`] derive macro from the [`beerec-variants`] crate, since the enum type is marked with the `#[variants(or_unknown)]` attribute.
+
+[`beerec-variants`]: https://docs.rs/beerec-variants
+[`Variants`]: https://docs.rs/beerec-variants/latest/beerec_variants/derive.Variants.html*/
+pub enum PlatformOrUnknown {
+    /// A known variant.
+    Known(Platform),
+    /// An unknown string value.
+    Unknown(::std::boxed::Box<str>),
+}
+#[automatically_derived]
+impl ::core::clone::Clone for PlatformOrUnknown {
+    #[inline]
+    fn clone(&self) -> PlatformOrUnknown {
+        match self {
+            PlatformOrUnknown::Known(__self_0) => {
+                PlatformOrUnknown::Known(::core::clone::Clone::clone(__self_0))
+            }
+            PlatformOrUnknown::Unknown(__self_0) => {
+                PlatformOrUnknown::Unknown(::core::clone::Clone::clone(__self_0))
+            }
+        }
+    }
+}
+#[automatically_derived]
+impl PlatformOrUnknown {
+    /// Returns the string representation of the known variant, or the unknown
+    /// string value.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Known(variant) => variant.as_str(),
+            Self::Unknown(value) => value,
+        }
+    }
+    /// Returns the known variant, if any.
+    #[inline]
+    #[must_use]
+    pub fn known(&self) -> ::std::option::Option<Platform> {
+        match self {
+            Self::Known(variant) => ::std::option::Option::Some(*variant),
+            Self::Unknown(_) => ::std::option::Option::None,
+        }
+    }
+    /// Returns the unknown string value, if any.
+    #[inline]
+    #[must_use]
+    pub fn unknown(&self) -> ::std::option::Option<&str> {
+        match self {
+            Self::Known(_) => ::std::option::Option::None,
+            Self::Unknown(value) => ::std::option::Option::Some(value),
+        }
+    }
+}
+impl ::std::fmt::Debug for PlatformOrUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match self {
+            Self::Known(variant) => {
+                f.debug_tuple("Known")
+                    .field(&format_args!("{0}", variant.as_str()))
+                    .finish()
+            }
+            Self::Unknown(value) => f.debug_tuple("Unknown").field(value).finish(),
+        }
+    }
+}
+impl ::std::cmp::PartialEq for PlatformOrUnknown {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Known(variant), Self::Known(other)) => {
+                ::std::mem::discriminant(variant) == ::std::mem::discriminant(other)
+            }
+            (Self::Unknown(value), Self::Unknown(other)) => value == other,
+            _ => false,
+        }
+    }
+}
+impl ::std::cmp::Eq for PlatformOrUnknown {}
+impl ::std::hash::Hash for PlatformOrUnknown {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Known(variant) => {
+                ::std::hash::Hash::hash(&::std::mem::discriminant(variant), state)
+            }
+            Self::Unknown(value) => ::std::hash::Hash::hash(value, state),
+        }
+    }
+}
+impl ::std::convert::From<Platform> for PlatformOrUnknown {
+    fn from(variant: Platform) -> Self {
+        Self::Known(variant)
+    }
+}
+impl ::std::fmt::Display for PlatformOrUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Formatter::write_str(f, self.as_str())
+    }
+}
+impl ::std::str::FromStr for PlatformOrUnknown {
+    type Err = ::std::convert::Infallible;
+    fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+        let value = input;
+        ::std::result::Result::Ok(
+            match value {
+                "Linux" | "Lin" => Self::Known(Platform::Linux),
+                "Windows" | "Win" => Self::Known(Platform::Windows),
+                _ => Self::Unknown(::std::boxed::Box::from(input)),
+            },
+        )
+    }
+}
+impl ::serde::ser::Serialize for PlatformOrUnknown {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::ser::Serializer,
+    {
+        match self {
+            Self::Known(variant) => {
+                ::serde::ser::Serialize::serialize(variant, serializer)
+            }
+            Self::Unknown(value) => serializer.serialize_str(value),
+        }
+    }
+}
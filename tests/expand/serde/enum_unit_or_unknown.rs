@@ -0,0 +1,16 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(or_unknown, deserialize, serialize)]
+pub enum Channel {
+    Stable,
+    Beta,
+    Nightly,
+}
+
+#[derive(Variants)]
+#[variants(or_unknown, serialize)]
+pub enum Platform {
+    Linux,
+    Windows,
+}
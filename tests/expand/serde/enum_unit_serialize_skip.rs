@@ -0,0 +1,10 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(serialize)]
+pub enum Status {
+    Active,
+    Inactive,
+    #[variants(skip(serialize))]
+    Unknown,
+}
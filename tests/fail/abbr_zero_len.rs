@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(abbr(len = 0))]
+pub enum Enum {
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Color {
+    #[variants(alias("Rosso", removed))]
+    Red,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str)]
+pub enum Planet {
+    March,
+    Mars,
+    #[variants(rename_abbr = "Mars")]
+    Mercury,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str(case_insensitive))]
+pub enum Month {
+    May,
+    #[variants(rename_abbr = "MAY")]
+    Mayday,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Priority {
+    #[variants(default)]
+    Low,
+    #[variants(default)]
+    Medium,
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+    #[variants(default, skip)]
+    Unset,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(derive(Debug, Default))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+}
+
+fn main() {}
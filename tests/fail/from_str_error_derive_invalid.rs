@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str(error_derive(Clone, Default)))]
+pub enum Currency {
+    Euro,
+    Dollar,
+    Pound,
+}
+
+fn main() {}
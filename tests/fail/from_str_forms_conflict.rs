@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str(full, abbr))]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Weekday {
+    #[variants(group = "Weekend")]
+    Saturday,
+    #[variants(group = "weekend", group = "weekend")]
+    Sunday,
+    #[variants(group = "first")]
+    Monday,
+}
+
+fn main() {}
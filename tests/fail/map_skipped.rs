@@ -0,0 +1,14 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(map)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    #[variants(skip)]
+    Holiday,
+    #[variants(skip(iter))]
+    Leave,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use beerec_variants::Variants;
+
+#[derive(PartialEq, Eq, Variants)]
+#[variants(ord(rank))]
+pub enum Severity {
+    #[variants(rank = 2)]
+    Info,
+    #[variants(rank = 2)]
+    Warning,
+    Error,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(from_str)]
+pub enum Event {
+    Created,
+    Deleted,
+    #[variants(other)]
+    Unknown,
+    #[variants(other)]
+    Unsupported,
+}
+
+fn main() {}
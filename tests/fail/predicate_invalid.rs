@@ -0,0 +1,30 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(predicate_prefix = "Is")]
+pub enum Protocol {
+    Http,
+}
+
+#[derive(Variants)]
+pub enum Server {
+    HttpServer,
+    HTTPServer,
+}
+
+#[derive(Variants)]
+pub enum Weekday {
+    #[variants(group = "weekend")]
+    Saturday,
+    #[variants(group = "weekend")]
+    Weekend,
+}
+
+#[derive(Variants)]
+#[variants(predicate_prefix = "const_")]
+pub enum Operator {
+    Add,
+    Eq,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use beerec_variants::Variants;
+
+#[derive(PartialEq, Eq, Variants)]
+#[variants(ord)]
+pub enum Severity {
+    Info,
+    #[variants(rank = 1)]
+    Error,
+}
+
+fn main() {}
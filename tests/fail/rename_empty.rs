@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+pub enum Enum {
+    #[variants(rename = "")]
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(rename(pascal_case))]
+pub enum Enum {
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(repr)]
+pub enum Status {
+    Pending,
+    Active,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(map, deserialize, serialize)]
+pub enum Weekday {
+    Monday,
+    #[variants(skip(parse))]
+    Tuesday,
+    #[variants(skip(serialize))]
+    Wednesday,
+}
+
+fn main() {}
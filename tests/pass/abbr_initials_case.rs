@@ -0,0 +1,21 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(rename(uppercase), abbr(initials))]
+pub enum Direction {
+    NorthEast,
+    SouthWest,
+    #[variants(rename(lowercase))]
+    NorthWest,
+    #[variants(rename_abbr(lowercase))]
+    SouthEast,
+}
+
+fn main() {
+    assert_eq!(Direction::NorthEast.as_str(), "NORTHEAST");
+    assert_eq!(Direction::NorthEast.as_str_abbr(), "NE");
+    assert_eq!(Direction::SouthWest.as_str_abbr(), "SW");
+    assert_eq!(Direction::NorthWest.as_str(), "northwest");
+    assert_eq!(Direction::NorthWest.as_str_abbr(), "nw");
+    assert_eq!(Direction::SouthEast.as_str_abbr(), "se");
+}
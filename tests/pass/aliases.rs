@@ -0,0 +1,26 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(display, from_str(full))]
+pub enum Color {
+    #[variants(alias = "grey", alias("gray", deprecated))]
+    Gray,
+    #[variants(rename = "navy", alias = "dark_blue")]
+    Navy,
+}
+
+fn main() {
+    for variant in Color::iter_variants() {
+        assert_eq!(Ok(variant), variant.as_str().parse::<Color>());
+    }
+
+    assert_eq!(Ok(Color::Gray), "grey".parse());
+    assert_eq!(Ok(Color::Gray), "gray".parse());
+    assert_eq!(Ok(Color::Navy), "dark_blue".parse());
+
+    // Aliases don't change the string representation.
+    assert_eq!("Gray", Color::Gray.as_str());
+    assert_eq!("navy", Color::Navy.to_string());
+
+    assert!("Navy".parse::<Color>().is_err());
+}
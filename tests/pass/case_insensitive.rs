@@ -0,0 +1,37 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(from_str(case_insensitive, trim))]
+pub enum Weekday {
+    #[variants(alias = "start")]
+    Monday,
+    Tuesday,
+    #[variants(rename = "HUMP_DAY")]
+    Wednesday,
+}
+
+fn main() {
+    for variant in Weekday::iter_variants() {
+        assert_eq!(
+            Ok(variant),
+            variant.as_str().to_lowercase().parse::<Weekday>()
+        );
+        assert_eq!(
+            Ok(variant),
+            variant.as_str_abbr().to_uppercase().parse::<Weekday>()
+        );
+    }
+
+    assert_eq!(Ok(Weekday::Monday), "  MONDAY\t".parse());
+    assert_eq!(Ok(Weekday::Monday), "Start".parse());
+    assert_eq!(Ok(Weekday::Tuesday), "tUe".parse());
+    assert_eq!(Ok(Weekday::Wednesday), "hump_day".parse());
+    assert_eq!(
+        Some(Weekday::Wednesday),
+        Weekday::from_str_full("Hump_Day").ok()
+    );
+    assert_eq!(Some(Weekday::Tuesday), Weekday::from_str_abbr(" TUE ").ok());
+
+    let error = "Wednesday".parse::<Weekday>().unwrap_err();
+    assert_eq!(error.input(), "Wednesday");
+}
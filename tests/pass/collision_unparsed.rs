@@ -0,0 +1,27 @@
+use beerec_variants::Variants;
+
+// Colliding representations are allowed when no parsing implementation is generated.
+#[derive(Variants)]
+#[variants(display)]
+pub enum Planet {
+    March,
+    Mars,
+}
+
+// Variants excluded from parsing are not checked for collisions.
+#[derive(Debug, PartialEq, Variants)]
+#[variants(from_str)]
+pub enum Month {
+    March,
+    May,
+    #[variants(skip(parse))]
+    Mars,
+}
+
+fn main() {
+    assert_eq!(Planet::March.as_str_abbr(), Planet::Mars.as_str_abbr());
+    assert_eq!(Planet::Mars.to_string(), "Mars");
+
+    assert_eq!("Mar".parse::<Month>(), Ok(Month::March));
+    assert!("Mars".parse::<Month>().is_err());
+}
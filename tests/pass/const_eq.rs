@@ -0,0 +1,19 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+}
+
+const SAME: bool = Weekday::Monday.const_eq(Weekday::Monday);
+const DIFFERENT: bool = Weekday::Monday.const_eq(Weekday::Tuesday);
+
+fn main() {
+    assert!(SAME);
+    assert!(!DIFFERENT);
+
+    // The `PartialEq` trait method is not shadowed by the generated one.
+    assert!(Weekday::Monday.eq(&Weekday::Monday));
+    assert!(Weekday::Monday.ne(&Weekday::Tuesday));
+}
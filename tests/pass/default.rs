@@ -0,0 +1,17 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+pub enum Priority {
+    Low,
+    #[variants(default)]
+    Medium,
+    High,
+}
+
+const DEFAULT: Priority = Priority::DEFAULT;
+
+fn main() {
+    assert_eq!(DEFAULT, Priority::Medium);
+    assert_eq!(Priority::default(), Priority::Medium);
+    assert_eq!(Priority::default().as_str(), "Medium");
+}
@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+
+use beerec_variants::Variants;
+
+#[derive(Debug, Clone, Copy, PartialEq, Variants)]
+#[variants(derived(Debug, Clone, Copy, PartialEq), derive(Eq, Hash))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+}
+
+#[derive(Variants)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[variants(derive(Eq))]
+pub enum Month {
+    January,
+    February,
+}
+
+fn main() {
+    let day = Weekday::Monday;
+    let copy = day;
+
+    assert_eq!(day, copy);
+    assert_eq!(Weekday::Tuesday, day.next());
+    assert_eq!(Weekday::Tuesday, Weekday::Monday.next());
+    assert_eq!(2, Weekday::iter_variants().collect::<HashSet<_>>().len());
+
+    assert_eq!(Month::February, Month::January.next());
+    assert_eq!("January", Month::January.as_str());
+}
@@ -0,0 +1,33 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(from_str)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+    #[variants(skip(iter, list, parse))]
+    Holiday,
+}
+
+fn main() {
+    assert_eq!(Weekday::from_str_fuzzy("Mondya", 0.5), Some(Weekday::Monday));
+    assert_eq!(Weekday::from_str_fuzzy("tuesday", 1.0), Some(Weekday::Tuesday));
+    assert_eq!(Weekday::from_str_fuzzy("Thur", 0.7), Some(Weekday::Thursday));
+    assert_eq!(Weekday::from_str_fuzzy("Mondya", 0.9), None);
+    assert_eq!(Weekday::from_str_fuzzy("Holiday", 0.9), None);
+    assert_eq!(Weekday::from_str_fuzzy("", 0.5), None);
+
+    let error = "Wendesday".parse::<Weekday>().unwrap_err();
+    assert_eq!(error.input(), "Wendesday");
+    assert_eq!(error.suggestion(), Some("Wednesday"));
+    assert_eq!(error.to_string(), "unknown weekday `Wendesday`, did you mean `Wednesday`?");
+
+    let error = "Holiday".parse::<Weekday>().unwrap_err();
+    assert_eq!(error.suggestion(), None);
+    assert!(error.to_string().starts_with("unknown weekday `Holiday`, expected one of \"Monday\""));
+}
@@ -0,0 +1,50 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+pub enum Weekday {
+    #[variants(group = "workday")]
+    Monday,
+    #[variants(group = "workday", group = "midweek")]
+    Wednesday,
+    #[variants(group = "workday")]
+    Friday,
+    #[variants(group = "weekend")]
+    Saturday,
+    #[variants(group = "weekend", skip)]
+    Sunday,
+}
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(predicate_prefix = "is_state_")]
+pub enum State {
+    #[variants(group = "active")]
+    Running,
+    #[variants(group = "active")]
+    Paused,
+    Stopped,
+}
+
+impl State {
+    // Hand-written method, not colliding with the prefixed group predicate.
+    pub const fn is_active(self) -> bool {
+        true
+    }
+}
+
+fn main() {
+    assert!(Weekday::Monday.is_workday());
+    assert!(Weekday::Wednesday.is_midweek());
+    assert!(!Weekday::Friday.is_midweek());
+    assert!(Weekday::Sunday.is_weekend());
+    assert_eq!([Weekday::Saturday], Weekday::WEEKEND);
+    assert_eq!(
+        vec![Weekday::Monday, Weekday::Wednesday, Weekday::Friday],
+        Weekday::iter_workday().collect::<Vec<_>>(),
+    );
+
+    assert!(State::Running.is_state_active());
+    assert!(State::Paused.is_state_active());
+    assert!(!State::Stopped.is_state_active());
+    assert!(State::Stopped.is_active());
+    assert_eq!([State::Running, State::Paused], State::ACTIVE);
+}
@@ -0,0 +1,38 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(map)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+fn main() {
+    use Weekday::*;
+
+    let mut hours = WeekdayMap::from_fn(|weekday| if weekday.index() < 5 { 8 } else { 0 });
+    assert_eq!(hours[Friday], 8);
+    assert_eq!(hours[Sunday], 0);
+
+    hours[Saturday] = 4;
+    *hours.get_mut(Monday) += 1;
+    assert_eq!(hours.get(Monday), &9);
+    assert_eq!(hours.values().sum::<u32>(), 45);
+
+    for (weekday, value) in hours.iter_mut() {
+        if weekday == Tuesday {
+            *value = 0;
+        }
+    }
+
+    assert_eq!(hours.into_array(), [9, 0, 8, 8, 8, 4, 0]);
+
+    let scaled = WeekdayMap::from_array([1, 2, 3, 4, 5, 6, 7]).map(|_, value| value * 10);
+    assert_eq!(scaled.iter().last(), Some((Sunday, &70)));
+    assert_eq!(WeekdayMap::<u8>::default().values().copied().max(), Some(0));
+}
@@ -0,0 +1,21 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(markers)]
+pub enum Crate {
+    Local,
+    Remote,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Variants)]
+#[variants(markers)]
+pub enum Self_ {
+    Owned,
+}
+
+fn main() {
+    assert_eq!(<crate_markers::Remote as CrateVariant>::VALUE, Crate::Remote);
+    assert_eq!(<crate_markers::Local as CrateVariant>::NAME, "Local");
+    assert_eq!(<self_markers::Owned as Self_Variant>::VALUE, Self_::Owned);
+}
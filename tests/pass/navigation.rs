@@ -0,0 +1,39 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+fn main() {
+    use Weekday::*;
+
+    assert_eq!(Sunday.next(), Monday);
+    assert_eq!(Monday.prev(), Sunday);
+    assert_eq!(Sunday.saturating_next(), Sunday);
+    assert_eq!(Monday.saturating_prev(), Monday);
+
+    assert_eq!(Friday.offset(3), Monday);
+    assert_eq!(Tuesday.offset(-3), Saturday);
+    assert_eq!(Monday.offset(15), Tuesday);
+    assert_eq!(Monday.offset(-15), Sunday);
+    assert_eq!(Wednesday.offset(0), Wednesday);
+    assert_eq!(Wednesday.offset(isize::MAX), Wednesday.offset(isize::MAX % 7));
+    assert_eq!(Wednesday.offset(isize::MIN), Wednesday.offset(isize::MIN % 7));
+
+    assert_eq!(Monday.distance_to(Sunday), 6);
+    assert_eq!(Sunday.distance_to(Monday), -6);
+    assert_eq!(Thursday.distance_to(Thursday), 0);
+
+    for from in Weekday::iter_variants() {
+        for to in Weekday::iter_variants() {
+            assert_eq!(from.offset(from.distance_to(to)), to);
+        }
+    }
+}
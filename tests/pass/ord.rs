@@ -0,0 +1,46 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Eq, Variants)]
+#[variants(ord(name))]
+pub enum Fruit {
+    Pear,
+    #[variants(rename = "apple")]
+    Apple,
+    Banana,
+}
+
+#[derive(Debug, PartialEq, Eq, Variants)]
+#[variants(ord(rank))]
+pub enum Severity {
+    #[variants(rank = 3)]
+    Info,
+    #[variants(rank = 2)]
+    Warning,
+    #[variants(rank = 1)]
+    Error,
+    #[variants(rank = 0, skip)]
+    Unset,
+}
+
+fn main() {
+    // Ordered by string representation, i.e. "Banana" < "Pear" < "apple".
+    assert!(Fruit::Banana < Fruit::Pear);
+    assert!(Fruit::Pear < Fruit::Apple);
+    assert_eq!(Fruit::MIN, Fruit::Banana);
+    assert_eq!(Fruit::MAX, Fruit::Apple);
+    assert_eq!(
+        Fruit::iter_variants_sorted().collect::<Vec<_>>(),
+        [Fruit::Banana, Fruit::Pear, Fruit::Apple],
+    );
+
+    assert!(Severity::Error < Severity::Warning);
+    assert!(Severity::Warning < Severity::Info);
+    assert!(Severity::Unset < Severity::Error);
+    assert_eq!(Severity::MIN, Severity::Error);
+    assert_eq!(Severity::MAX, Severity::Info);
+    assert_eq!(
+        Severity::iter_variants_sorted().collect::<Vec<_>>(),
+        [Severity::Error, Severity::Warning, Severity::Info],
+    );
+    assert_eq!([Severity::Info, Severity::Unset, Severity::Error].iter().max(), Some(&Severity::Info));
+}
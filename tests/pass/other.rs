@@ -0,0 +1,30 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(from_str)]
+pub enum Event {
+    Created,
+    Deleted,
+    #[variants(other)]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(from_str)]
+pub enum Action {
+    Start,
+    Stop,
+}
+
+fn main() {
+    assert_eq!("Created".parse::<Event>(), Ok(Event::Created));
+    assert_eq!("Renamed".parse::<Event>(), Ok(Event::Unknown));
+
+    // The catch-all variant is not a parseable value, hence it's not listed.
+    assert_eq!(Event::variants_list_str(), r#""Created", "Deleted""#);
+    assert_eq!(Event::variants_list_str_abbr(), r#""Cre", "Del""#);
+    assert_eq!(Event::iter_variants().count(), 3);
+
+    let error = "Pause".parse::<Action>().unwrap_err().to_string();
+    assert!(error.contains(r#""Start", "Stop""#), "{error}");
+}
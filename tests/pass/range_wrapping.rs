@@ -0,0 +1,45 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+fn main() {
+    use Weekday::*;
+
+    assert_eq!(Weekday::range(Friday..=Monday).count(), 0);
+    assert_eq!(Weekday::range(Tuesday..Thursday).collect::<Vec<_>>(), [Tuesday, Wednesday]);
+
+    assert_eq!(
+        Weekday::range_wrapping(Friday..=Monday).collect::<Vec<_>>(),
+        [Friday, Saturday, Sunday, Monday],
+    );
+    assert_eq!(
+        Weekday::range_wrapping(Saturday..Tuesday).collect::<Vec<_>>(),
+        [Saturday, Sunday, Monday],
+    );
+    assert_eq!(
+        Weekday::range_wrapping(Tuesday..Thursday).collect::<Vec<_>>(),
+        [Tuesday, Wednesday],
+    );
+    assert_eq!(
+        Weekday::range_wrapping(Friday..=Monday).rev().collect::<Vec<_>>(),
+        [Monday, Sunday, Saturday, Friday],
+    );
+
+    // An inclusive range ending on the variant preceding its start covers the whole cycle.
+    assert_eq!(
+        Weekday::range_wrapping(Wednesday..=Tuesday).collect::<Vec<_>>(),
+        [Wednesday, Thursday, Friday, Saturday, Sunday, Monday, Tuesday],
+    );
+    assert_eq!(Weekday::range_wrapping(Wednesday..Wednesday).count(), 0);
+    assert_eq!(Weekday::range_wrapping(Wednesday..=Wednesday).count(), 1);
+    assert_eq!(Weekday::range_wrapping(Friday..=Monday).len(), 4);
+}
@@ -0,0 +1,35 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Eq, Variants)]
+#[variants(repr)]
+#[repr(i16)]
+pub enum Status {
+    Pending = -1,
+    Active,
+    #[variants(skip)]
+    Archived = 300,
+    Closed,
+}
+
+fn main() {
+    assert_eq!(-1, Status::Pending.to_repr());
+    assert_eq!(0, Status::Active.to_repr());
+    assert_eq!(300, i16::from(Status::Archived));
+    assert_eq!(301, Status::Closed.to_repr());
+
+    for variant in Status::iter_variants() {
+        assert_eq!(Some(variant), Status::from_repr(variant.to_repr()));
+    }
+
+    // Skipped variants are converted as well.
+    assert_eq!(Some(Status::Archived), Status::from_repr(300));
+    assert_eq!(None, Status::from_repr(1));
+
+    assert_eq!(Ok(Status::Pending), Status::try_from(-1_i8));
+    assert_eq!(Ok(Status::Closed), Status::try_from(301_u64));
+    assert!(Status::try_from(u64::MAX).is_err());
+    assert!(Status::try_from(-1_i64 << 40).is_err());
+
+    let error = Status::try_from(2_u8).unwrap_err();
+    assert_eq!("unknown status representation `2`", error.to_string());
+}
@@ -0,0 +1,28 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(deserialize(fallback))]
+pub enum Priority {
+    Low,
+    #[variants(default)]
+    Medium,
+    High,
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Task {
+    priority: Priority,
+}
+
+fn main() {
+    assert_eq!(
+        toml::from_str::<Task>("priority = \"High\"\n").unwrap(),
+        Task { priority: Priority::High },
+    );
+
+    // Unknown values fall back to the default variant.
+    assert_eq!(
+        toml::from_str::<Task>("priority = \"Urgent\"\n").unwrap(),
+        Task { priority: Priority::Medium },
+    );
+}
@@ -0,0 +1,28 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(deserialize)]
+pub enum Event {
+    Created,
+    Deleted,
+    #[variants(other)]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Record {
+    event: Event,
+}
+
+fn main() {
+    assert_eq!(
+        toml::from_str::<Record>("event = \"Deleted\"\n").unwrap(),
+        Record { event: Event::Deleted },
+    );
+
+    // Unknown values are deserialized as the catch-all variant.
+    assert_eq!(
+        toml::from_str::<Record>("event = \"Renamed\"\n").unwrap(),
+        Record { event: Event::Unknown },
+    );
+}
@@ -0,0 +1,34 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(map, deserialize(case_insensitive), serialize)]
+pub enum Weekday {
+    #[variants(rename = "mon", alias = "first")]
+    Monday,
+    Tuesday,
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct Record {
+    hours: WeekdayMap<u32>,
+}
+
+fn main() {
+    let record = Record {
+        hours: WeekdayMap::from_array([8, 4]),
+    };
+
+    // Keys go through the enum `Serialize` implementation.
+    let serialized = toml::to_string(&record).unwrap();
+    assert_eq!(serialized, "[hours]\nmon = 8\nTuesday = 4\n");
+    assert_eq!(toml::from_str::<Record>(&serialized).unwrap(), record);
+
+    // Keys go through the enum `Deserialize` implementation.
+    assert_eq!(
+        toml::from_str::<Record>("[hours]\nFIRST = 8\ntuesday = 4\n").unwrap(),
+        record,
+    );
+
+    assert!(toml::from_str::<Record>("[hours]\nmon = 8\n").is_err());
+    assert!(toml::from_str::<Record>("[hours]\nmon = 8\nfirst = 1\nTuesday = 4\n").is_err());
+}
@@ -0,0 +1,34 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(or_unknown, deserialize(case_insensitive), serialize)]
+pub enum Status {
+    Active,
+    Inactive,
+    #[variants(skip(serialize))]
+    Hidden,
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct Record {
+    status: StatusOrUnknown,
+}
+
+fn main() {
+    // Deserialization follows the parsing options of the enum type.
+    assert_eq!(
+        toml::from_str::<Record>("status = \"ACTIVE\"\n").unwrap(),
+        Record { status: StatusOrUnknown::Known(Status::Active) },
+    );
+
+    let record = toml::from_str::<Record>("status = \"Archived\"\n").unwrap();
+    assert_eq!(record.status, StatusOrUnknown::Unknown(Box::from("Archived")));
+    assert_eq!(toml::to_string(&record).unwrap(), "status = \"Archived\"\n");
+
+    // Serialization of known variants goes through the enum type.
+    let record = Record { status: StatusOrUnknown::Known(Status::Inactive) };
+    assert_eq!(toml::to_string(&record).unwrap(), "status = \"Inactive\"\n");
+
+    let record = Record { status: StatusOrUnknown::Known(Status::Hidden) };
+    assert!(toml::to_string(&record).is_err());
+}
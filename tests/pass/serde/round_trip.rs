@@ -0,0 +1,46 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(deserialize(case_insensitive, trim), serialize)]
+pub enum Level {
+    #[variants(alias = "warning")]
+    Warn,
+    #[variants(rename = "err", alias("failure", deprecated))]
+    Error,
+    #[variants(default)]
+    Info,
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct Record {
+    level: Level,
+}
+
+fn main() {
+    for level in Level::iter_variants() {
+        let record = Record { level };
+        let serialized = toml::to_string(&record).unwrap();
+
+        assert_eq!(
+            serialized,
+            format!("level = \"{}\"\n", record.level.as_str())
+        );
+        assert_eq!(toml::from_str::<Record>(&serialized).unwrap(), record);
+    }
+
+    // Aliases and abbreviations are accepted regardless of their ASCII case.
+    for (input, level) in [
+        ("WARNING", Level::Warn),
+        (" Failure ", Level::Error),
+        ("ERR", Level::Error),
+        ("inf", Level::Info),
+    ] {
+        assert_eq!(
+            toml::from_str::<Record>(&format!("level = \"{input}\"\n")).unwrap(),
+            Record { level },
+        );
+    }
+
+    assert_eq!(Level::Info, Level::DEFAULT);
+    assert!(toml::from_str::<Record>("level = \"Debug\"\n").is_err());
+}
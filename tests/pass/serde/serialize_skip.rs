@@ -0,0 +1,25 @@
+use beerec_variants::Variants;
+
+#[derive(Variants)]
+#[variants(serialize)]
+pub enum Status {
+    Active,
+    Inactive,
+    #[variants(skip(serialize))]
+    Unknown,
+}
+
+#[derive(serde::Serialize)]
+struct Record {
+    status: Status,
+}
+
+fn main() {
+    assert_eq!(
+        toml::to_string(&Record { status: Status::Active }).unwrap(),
+        "status = \"Active\"\n",
+    );
+
+    let error = toml::to_string(&Record { status: Status::Unknown }).unwrap_err();
+    assert!(error.to_string().contains("the status `Unknown` cannot be serialized"));
+}
@@ -0,0 +1,45 @@
+use beerec_variants::Variants;
+
+#[derive(Debug, PartialEq, Variants)]
+#[variants(set, from_str)]
+pub enum Permission {
+    Read,
+    Write,
+    Execute,
+    #[variants(skip)]
+    Admin,
+}
+
+fn main() {
+    use Permission::*;
+
+    let read_write = PermissionSet::from_variants(&[Read, Write]);
+    let write_execute: PermissionSet = [Write, Execute].into_iter().collect();
+
+    assert_eq!(read_write.len(), 2);
+    assert!(read_write.contains(Read) && !read_write.contains(Execute));
+    assert_eq!((read_write | write_execute), PermissionSet::ALL);
+    assert_eq!((read_write & write_execute).iter().collect::<Vec<_>>(), [Write]);
+    assert_eq!((read_write - write_execute).iter().collect::<Vec<_>>(), [Read]);
+    assert_eq!((read_write ^ write_execute).iter().collect::<Vec<_>>(), [Read, Execute]);
+    assert_eq!(!read_write, PermissionSet::from(Execute));
+    assert_eq!(PermissionSet::EMPTY.complement(), PermissionSet::ALL);
+    assert!(PermissionSet::from(Read).is_subset(read_write));
+    assert!(read_write.is_superset(PermissionSet::EMPTY));
+    assert!(PermissionSet::from(Read).is_disjoint(write_execute));
+
+    // Skipped variants are never contained in the set.
+    assert_eq!(PermissionSet::from(Admin), PermissionSet::EMPTY);
+    assert_eq!(PermissionSet::ALL.len(), 3);
+
+    let mut set = PermissionSet::empty();
+    assert!(set.insert(Execute));
+    assert!(!set.insert(Execute));
+    assert!(!set.insert(Admin));
+    assert!(set.remove(Execute));
+    assert!(set.is_empty());
+
+    assert_eq!("Read, Execute".parse::<PermissionSet>().unwrap(), PermissionSet::from_variants(&[Read, Execute]));
+    assert_eq!("".parse::<PermissionSet>().unwrap(), PermissionSet::EMPTY);
+    assert!("Read, Delete".parse::<PermissionSet>().is_err());
+}